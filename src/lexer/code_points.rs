@@ -29,6 +29,8 @@ pub(super) const LF: char = '\u{000a}';
 pub(super) const CR: char = '\u{000d}';
 pub(super) const LS: char = '\u{2028}';
 pub(super) const PS: char = '\u{2029}';
+/// 字节顺序标记 (BOM)，与 <ZWNBSP> 为同一字符
+pub(super) const BOM: char = '\u{feff}';
 
 /// 判断当前字符是否为行终止符 (Line Terminators)
 ///
@@ -38,18 +40,32 @@ pub(super) const PS: char = '\u{2029}';
 /// 返回当前字段是否是 Line Terminators
 #[inline(always)]
 pub(super) const fn is_line_terminator(chr: char) -> bool {
-    match chr {
-        '\u{000a}' | '\u{000d}' | '\u{2028}' | '\u{2029}' => true,
-        _ => false,
-    }
+    matches!(chr, '\u{000a}' | '\u{000d}' | '\u{2028}' | '\u{2029}')
 }
 
 /// 判断当前字符是否为 ID Start
 ///
+/// 启用 `unicode-ident` feature 时按 Unicode 的 XID_Start 属性判断，覆盖全部平面
+///
 /// # Arguments
 /// `chr` - 字符
 /// # Returns
 /// 返回当前字段是否是 ID Start
+#[cfg(feature = "unicode-ident")]
+#[inline(always)]
+pub(super) fn is_id_start(chr: char) -> bool {
+    unicode_ident::is_xid_start(chr)
+}
+
+/// 判断当前字符是否为 ID Start
+///
+/// 未启用 `unicode-ident` feature 时仅识别基本多文种平面中的常见范围
+///
+/// # Arguments
+/// `chr` - 字符
+/// # Returns
+/// 返回当前字段是否是 ID Start
+#[cfg(not(feature = "unicode-ident"))]
 #[inline(always)]
 pub(super) const fn is_id_start(chr: char) -> bool {
     matches!(chr, 
@@ -69,7 +85,6 @@ pub(super) const fn is_id_start(chr: char) -> bool {
         | '\u{3001}'..='\u{d7ff}'
         | '\u{f900}'..='\u{fdff}'
         | '\u{fe70}'..='\u{fefe}'
-        | '\u{ff10}'..='\u{ff19}'
         | '\u{ff21}'..='\u{ff3a}'
         | '\u{ff41}'..='\u{ff5a}'
         | '\u{ff65}'..='\u{ffdc}')
@@ -77,10 +92,27 @@ pub(super) const fn is_id_start(chr: char) -> bool {
 
 /// 判断当前字符是否为 ID Continue
 ///
+/// 启用 `unicode-ident` feature 时按 Unicode 的 XID_Continue 属性判断，覆盖全部平面
+///
 /// # Arguments
 /// `chr` - 字符
 /// # Returns
 /// 返回当前字段是否是 ID Continue
+#[cfg(feature = "unicode-ident")]
+#[inline(always)]
+pub(super) fn is_id_continue(chr: char) -> bool {
+    unicode_ident::is_xid_continue(chr)
+}
+
+/// 判断当前字符是否为 ID Continue
+///
+/// 未启用 `unicode-ident` feature 时仅识别基本多文种平面中的常见范围
+///
+/// # Arguments
+/// `chr` - 字符
+/// # Returns
+/// 返回当前字段是否是 ID Continue
+#[cfg(not(feature = "unicode-ident"))]
 pub(super) const fn is_id_continue(chr: char) -> bool {
     matches!(chr,
         | '\u{0030}'..='\u{0039}'
@@ -108,3 +140,23 @@ pub(super) const fn is_id_continue(chr: char) -> bool {
         | '\u{ff41}'..='\u{ff5a}'
         | '\u{ff65}'..='\u{ffdc}')
 }
+
+/// 判断当前字符是否为 IdentifierPartChar
+///
+/// IdentifierPartChar ::
+///     UnicodeIDContinue
+///     `$`
+///     <ZWNJ>
+///     <ZWJ>
+///
+/// # Arguments
+/// `chr` - 字符
+/// # Returns
+/// 返回当前字段是否是 IdentifierPartChar
+#[inline(always)]
+pub(super) fn is_identifier_part(chr: char) -> bool {
+    is_id_start(chr)
+        || is_id_continue(chr)
+        || matches!(chr as u32, 0x200c | 0x200d)
+        || matches!(chr, '$' | '_')
+}
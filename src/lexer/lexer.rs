@@ -1,22 +1,105 @@
-use super::{code_points, lexer_error, reader, token::Token};
+use std::ops::{Deref, DerefMut};
+
+use super::{
+    code_points, lexer_error,
+    lexer_options::LexerOptions,
+    reader,
+    span::{Position, Span},
+    token::{CommentKind, Token},
+};
 
 type LexerResult = Result<Token, lexer_error::LexerError>;
 type LexerResultOnlyErr = Result<(), lexer_error::LexerError>;
 
+/// 词法分析器使用的源码读取器，可以借用外部的读取器，也可以持有内部构建的读取器
+enum LexerReader<'s> {
+    Borrowed(&'s mut dyn reader::SourceReader),
+    Inline(reader::InlineSourceReader<'s>),
+}
+
+impl<'s> Deref for LexerReader<'s> {
+    type Target = dyn reader::SourceReader + 's;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            LexerReader::Borrowed(reader) => *reader,
+            LexerReader::Inline(reader) => reader,
+        }
+    }
+}
+
+impl DerefMut for LexerReader<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            LexerReader::Borrowed(reader) => *reader,
+            LexerReader::Inline(reader) => reader,
+        }
+    }
+}
+
+/// 尚未闭合的括号，用于判定 `/` 是正则表达式还是除法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OpenBracket {
+    /// `(`，`after_control` 表示其前面是 `if`、`while`、`for` 或 `with`
+    Paren { after_control: bool },
+    /// `[`
+    Square,
+    /// 作为语句块、函数声明或类声明主体的 `{`
+    Block,
+    /// 作为函数表达式或类表达式主体的 `{`
+    ExpressionBody,
+    /// 作为对象字面量的 `{`
+    Object,
+}
+
 /// 词法分析器
 ///
 /// 用于将 EMCAScript 源码拆解分析成为一组 Token
 pub(crate) struct Lexer<'s> {
-    reader: &'s mut dyn reader::SourceReader,
+    reader: LexerReader<'s>,
+    options: LexerOptions,
 
     line_number: usize,
     line_off: usize,
+    /// 已扫描过的每一行的起始字节偏移量，第一行从 0 开始
+    line_starts: Vec<usize>,
+    /// 已扫描过的多字节字符的起始字节偏移量，以及其字节数与所占列数之差
+    wide_chars: Vec<(usize, usize)>,
+    /// 越过起始处的 BOM 之后源码的起始字节偏移量，Hashbang 注释只能出现在此处
+    source_start: usize,
+    operations: usize,
 
     tokenbuf: String,
+    /// 扫描 template 时记录的原始字符，不在 template 中时为 None
+    rawbuf: Option<String>,
 
     tok: Token,
+    tok_start: Position,
+    span: Span,
+    newline_before: bool,
+    /// 根据上一个非空白、非注释的 Token 判定的 `/` 是否作为正则表达式的开始
+    regex_allowed: bool,
+    /// 根据上一个非空白、非注释的 Token 判定的 `{` 是否作为语句块的开始
+    block_allowed: bool,
+    /// 上一个非空白、非注释的 Token 是否为 `if`、`while`、`for` 或 `with`
+    after_control: bool,
+    /// 上一个非空白、非注释的 Token 是否为 `default`，`export default` 之后的函数与类为声明
+    after_default: bool,
+    /// 上一个非空白、非注释的 Token 是否为 `.` 或 `?.`，此时随后的保留字为属性名
+    after_dot: bool,
+    brackets: Vec<OpenBracket>,
+    /// 尚未扫描到主体的函数表达式与类表达式，记录其所在的括号层数
+    pending_bodies: Vec<usize>,
+    peeked: Option<(Token, Span, bool)>,
+    /// 最近一次扫描到的注释类型，其内容保存在 comment_buf 中
+    last_comment: Option<CommentKind>,
+    /// 最近一次扫描到的注释内容，由 tokenbuf 交换得到，无需复制
+    comment_buf: String,
 
     template_expression: Vec<u8>,
+
+    /// 作为迭代器使用时，是否已产出 EOF 或报错
+    exhausted: bool,
 }
 
 impl<'s> Lexer<'s> {
@@ -27,28 +110,125 @@ impl<'s> Lexer<'s> {
     /// # Returns
     /// 返回一个 EMCAScript 词法分析器
     pub(crate) fn new(reader: &'s mut dyn reader::SourceReader) -> Self {
+        Self::with_options(reader, LexerOptions::default())
+    }
+
+    /// 构建一个直接读取字符串源码的词法分析器
+    ///
+    /// 内部构建 InlineSourceReader，无需调用方另行持有读取器
+    ///
+    /// # Arguments
+    /// `source` - EMCAScript 源码
+    /// # Returns
+    /// 返回一个 EMCAScript 词法分析器
+    pub(crate) fn from_source(source: &'s str) -> Self {
+        Self::build(
+            LexerReader::Inline(reader::InlineSourceReader::new(source)),
+            LexerOptions::default(),
+        )
+    }
+
+    /// 使用指定选项构建一个词法分析器
+    ///
+    /// # Arguments
+    /// `reader` - EMCAScript 源码读取器
+    /// `options` - 词法分析器选项
+    /// # Returns
+    /// 返回一个 EMCAScript 词法分析器
+    pub(crate) fn with_options(
+        reader: &'s mut dyn reader::SourceReader,
+        options: LexerOptions,
+    ) -> Self {
+        Self::build(LexerReader::Borrowed(reader), options)
+    }
+
+    fn build(reader: LexerReader<'s>, options: LexerOptions) -> Self {
+        let tokenbuf_capacity = options.tokenbuf_capacity;
         let mut result = Self {
             reader,
+            options,
 
             line_number: 1,
-            line_off: 1,
+            line_off: 0,
+            line_starts: vec![0],
+            wide_chars: Vec::new(),
+            source_start: 0,
+            operations: 0,
 
-            tokenbuf: String::new(),
+            tokenbuf: String::with_capacity(tokenbuf_capacity),
+            rawbuf: None,
 
             tok: Token::EOF,
+            tok_start: Position::default(),
+            span: Span::default(),
+            newline_before: false,
+            regex_allowed: true,
+            block_allowed: true,
+            after_control: false,
+            after_default: false,
+            after_dot: false,
+            brackets: Vec::new(),
+            pending_bodies: Vec::new(),
+            peeked: None,
+            last_comment: None,
+            comment_buf: String::new(),
 
             template_expression: Vec::new(),
+
+            exhausted: false,
         };
         result.next(1);
+        // 源码起始处的 BOM 不属于源码内容，越过它以保证随后的 `#!` 仍能被识别为 Hashbang 注释
+        if matches!(result.reader.current(), Some(code_points::BOM)) {
+            result.next(1);
+            result.line_off = 1;
+            // BOM 不占列
+            result.wide_chars = vec![(0, code_points::BOM.len_utf8())];
+        }
+        result.source_start = result.reader.offset();
 
         result
     }
 
     /// 将源码游标向下移动，并更新对应游标指向的字符
+    ///
+    /// 正在记录 template 的 raw 值时，被越过的字符同时追加到 rawbuf 中；
+    /// 被越过的多字节字符记录在 wide_chars 中，供 offset_to_position 计算列号
     fn next(&mut self, off: usize) {
-        self.reader.next(off as isize);
+        for _ in 0..off {
+            if let Some(chr) = self.reader.current() {
+                if let Some(raw) = self.rawbuf.as_mut() {
+                    raw.push(chr);
+                }
+                if chr.len_utf8() > 1 {
+                    self.wide_chars
+                        .push((self.reader.offset(), chr.len_utf8() - 1));
+                }
+            }
+            self.reader.next(1);
+        }
 
         self.line_off += off;
+        self.operations += off;
+    }
+
+    /// 检查是否超出了操作预算
+    ///
+    /// 除扫描每个 Token 前检查外，注释、字符串、template 等逐字符扫描的循环中同样检查，
+    /// 避免单个超长的 Token 越过预算
+    ///
+    /// # Returns
+    /// 若已消费的字符数超出预算，则返回报错
+    #[inline(always)]
+    fn check_budget(&self) -> LexerResultOnlyErr {
+        match self.options.max_operations {
+            Some(max) if self.operations > max => Err(lexer_error::LexerError::new(
+                lexer_error::LexerErrorKind::BudgetExceeded,
+                self.line_number,
+                self.line_off,
+            )),
+            _ => Ok(()),
+        }
     }
 
     /// 保存字符到 token buffer
@@ -91,6 +271,11 @@ impl<'s> Lexer<'s> {
         self.tokenbuf.clear()
     }
 
+    /// 获取 token buffer 当前已分配的容量
+    pub(crate) fn tokenbuf_capacity(&self) -> usize {
+        self.tokenbuf.capacity()
+    }
+
     /// 将 token buffer 中的字符串获取出来
     #[inline(always)]
     fn get_tokenbuf(&self) -> String {
@@ -134,6 +319,7 @@ impl<'s> Lexer<'s> {
         if let Some(blocks) = self.template_expression.pop() {
             if blocks != 0 {
                 return Err(lexer_error::LexerError::new(
+                    lexer_error::LexerErrorKind::InvalidTemplate,
                     self.line_number,
                     self.line_off,
                 ));
@@ -141,6 +327,7 @@ impl<'s> Lexer<'s> {
             Ok(())
         } else {
             Err(lexer_error::LexerError::new(
+                lexer_error::LexerErrorKind::InvalidTemplate,
                 self.line_number,
                 self.line_off,
             ))
@@ -159,6 +346,7 @@ impl<'s> Lexer<'s> {
             Ok(())
         } else {
             Err(lexer_error::LexerError::new(
+                lexer_error::LexerErrorKind::InvalidTemplate,
                 self.line_number,
                 self.line_off,
             ))
@@ -174,6 +362,7 @@ impl<'s> Lexer<'s> {
         if let Some(blocks) = self.template_expression.last_mut() {
             if *blocks == 0 {
                 return Err(lexer_error::LexerError::new(
+                    lexer_error::LexerErrorKind::InvalidTemplate,
                     self.line_number,
                     self.line_off,
                 ));
@@ -184,6 +373,7 @@ impl<'s> Lexer<'s> {
             Ok(())
         } else {
             Err(lexer_error::LexerError::new(
+                lexer_error::LexerErrorKind::InvalidTemplate,
                 self.line_number,
                 self.line_off,
             ))
@@ -205,27 +395,26 @@ impl<'s> Lexer<'s> {
     ///     <PS>
     ///     <CR> <LF>
     fn newline(&mut self) {
-        if matches!(self.reader.current(), None) {
+        if self.reader.current().is_none() {
             return;
         }
         if matches!(self.reader.current(), Some(chr) if !code_points::is_line_terminator(chr)) {
             return;
         }
 
-        self.line_number += 1;
-        self.line_off = 1;
-
         // 如果是 <CR>, 则进一步判断下一个字符是否是 <LF>
         // 若凑成 <CR><LF>，即命中 LineTerminatorSequence 词法规则，则消费掉后续的 <LF>
-        if !matches!(self.reader.current(), Some(code_points::CR)) {
-            self.next(1);
-            return;
-        }
-        if !matches!(self.reader.lookahead(), Some(code_points::LF)) {
+        if matches!(self.reader.current(), Some(code_points::CR))
+            && matches!(self.reader.lookahead(), Some(code_points::LF))
+        {
+            self.next(2);
+        } else {
             self.next(1);
-            return;
         }
-        self.next(2);
+
+        self.line_number += 1;
+        self.line_off = 1;
+        self.line_starts.push(self.reader.offset());
     }
 
     /// 解析注释
@@ -235,20 +424,30 @@ impl<'s> Lexer<'s> {
     ///     SingleLineComment
     ///
     /// # Returns
-    /// 返回注释 Token （此处应直接摒弃）
-    fn parse_comment(&mut self) -> LexerResult {
-        match self.reader.lookahead() {
-            Some('/') => self.parse_singleline_comment()?,
-            Some('*') => self.parse_multiline_comment()?,
+    /// 返回注释类型，注释内容保存在 comment_buf 中，由调用方按需构造注释 Token
+    fn parse_comment(&mut self) -> Result<CommentKind, lexer_error::LexerError> {
+        let kind = match self.reader.lookahead() {
+            Some('/') => {
+                self.parse_singleline_comment()?;
+                CommentKind::Line
+            }
+            Some('*') => {
+                self.parse_multiline_comment()?;
+                CommentKind::Block
+            }
             _ => {
                 return Err(lexer_error::LexerError::new(
+                    lexer_error::LexerErrorKind::InvalidToken,
                     self.line_number,
                     self.line_off,
                 ));
             }
-        }
+        };
+        std::mem::swap(&mut self.tokenbuf, &mut self.comment_buf);
+        self.tokenbuf.clear();
+        self.last_comment = Some(kind);
 
-        Ok(Token::Comment(self.get_tokenbuf()))
+        Ok(kind)
     }
 
     /// 解析 Hashbang 注释
@@ -288,9 +487,11 @@ impl<'s> Lexer<'s> {
         self.next(2);
 
         loop {
+            self.check_budget()?;
             match self.reader.current() {
                 None => {
                     return Err(lexer_error::LexerError::new(
+                        lexer_error::LexerErrorKind::UnterminatedComment,
                         self.line_number,
                         self.line_off,
                     ))
@@ -302,6 +503,7 @@ impl<'s> Lexer<'s> {
                 Some(chr) if code_points::is_line_terminator(chr) => {
                     self.save('\n');
                     self.newline();
+                    self.newline_before = true;
                 }
                 Some(chr) => self.savenext(chr),
             }
@@ -327,6 +529,7 @@ impl<'s> Lexer<'s> {
         self.next(2);
 
         loop {
+            self.check_budget()?;
             match self.reader.current() {
                 None => break,
                 Some(chr) if code_points::is_line_terminator(chr) => {
@@ -358,9 +561,44 @@ impl<'s> Lexer<'s> {
     ///
     /// NumbericLiteralSeparator ::
     ///     `_`
+    ///
+    /// `\u` Hex4Digits 表示高代理项 (0xD800–0xDBFF) 且之后紧跟表示低代理项 (0xDC00–0xDFFF) 的
+    /// `\u` Hex4Digits 时，二者组合为一个完整的码点；单独出现的代理项视为不合法的码点。
+    /// `\u{...}` 不允许表示代理项
     fn parse_unicode_escape_sequence(&mut self) -> LexerResultOnlyErr {
+        let mut val = self.parse_unicode_escape_value()?;
+        if (0xd800..=0xdbff).contains(&val)
+            && matches!(self.reader.current(), Some('\\'))
+            && matches!(self.reader.lookahead(), Some('u'))
+        {
+            self.next(1);
+            let low = self.parse_unicode_escape_value()?;
+            if (0xdc00..=0xdfff).contains(&low) {
+                val = 0x10000 + ((val - 0xd800) << 10) + (low - 0xdc00);
+            }
+        }
+
+        if let Some(chr) = char::from_u32(val) {
+            self.save(chr);
+
+            Ok(())
+        } else {
+            Err(lexer_error::LexerError::new(
+                lexer_error::LexerErrorKind::InvalidUnicodeCodePoint,
+                self.line_number,
+                self.line_off,
+            ))
+        }
+    }
+
+    /// 解析 UnicodeEscapeSequence 表示的码点
+    ///
+    /// # Returns
+    /// 返回转义序列表示的码点，可能为代理项
+    fn parse_unicode_escape_value(&mut self) -> Result<u32, lexer_error::LexerError> {
         if !matches!(self.reader.current(), Some('u')) {
             return Err(lexer_error::LexerError::new(
+                lexer_error::LexerErrorKind::InvalidEscape,
                 self.line_number,
                 self.line_off,
             ));
@@ -374,9 +612,21 @@ impl<'s> Lexer<'s> {
             let mut has_digit = false;
             let mut last_digit = false;
             loop {
+                self.check_budget()?;
                 match self.reader.current() {
-                    Some('}') if has_digit => break,
-                    Some(chr) if chr.is_digit(16) => {
+                    // `\u{...}` 必须表示一个 Unicode 标量值，不能为代理项
+                    Some('}') if has_digit && (0xd800..=0xdfff).contains(&val) => {
+                        return Err(lexer_error::LexerError::new(
+                            lexer_error::LexerErrorKind::InvalidUnicodeCodePoint,
+                            self.line_number,
+                            self.line_off,
+                        ))
+                    }
+                    Some('}') if has_digit => {
+                        self.next(1);
+                        break;
+                    }
+                    Some(chr) if chr.is_ascii_hexdigit() => {
                         has_digit = true;
                         last_digit = true;
 
@@ -385,6 +635,7 @@ impl<'s> Lexer<'s> {
                             val |= digit;
                         } else {
                             return Err(lexer_error::LexerError::new(
+                                lexer_error::LexerErrorKind::InvalidEscape,
                                 self.line_number,
                                 self.line_off,
                             ));
@@ -392,6 +643,7 @@ impl<'s> Lexer<'s> {
 
                         if val > 0x10ffff {
                             return Err(lexer_error::LexerError::new(
+                                lexer_error::LexerErrorKind::InvalidUnicodeCodePoint,
                                 self.line_number,
                                 self.line_off,
                             ));
@@ -406,6 +658,7 @@ impl<'s> Lexer<'s> {
                     }
                     _ => {
                         return Err(lexer_error::LexerError::new(
+                            lexer_error::LexerErrorKind::InvalidEscape,
                             self.line_number,
                             self.line_off,
                         ))
@@ -415,12 +668,13 @@ impl<'s> Lexer<'s> {
         } else {
             for _ in 0..4 {
                 match self.reader.current() {
-                    Some(chr) if chr.is_digit(16) => {
+                    Some(chr) if chr.is_ascii_hexdigit() => {
                         if let Some(digit) = chr.to_digit(16) {
                             val <<= 4;
                             val |= digit;
                         } else {
                             return Err(lexer_error::LexerError::new(
+                                lexer_error::LexerErrorKind::InvalidEscape,
                                 self.line_number,
                                 self.line_off,
                             ));
@@ -430,6 +684,7 @@ impl<'s> Lexer<'s> {
                     }
                     _ => {
                         return Err(lexer_error::LexerError::new(
+                            lexer_error::LexerErrorKind::InvalidEscape,
                             self.line_number,
                             self.line_off,
                         ))
@@ -438,16 +693,7 @@ impl<'s> Lexer<'s> {
             }
         }
 
-        if let Some(chr) = char::from_u32(val) {
-            self.save(chr);
-
-            Ok(())
-        } else {
-            Err(lexer_error::LexerError::new(
-                self.line_number,
-                self.line_off,
-            ))
-        }
+        Ok(val)
     }
 
     /// 解析 IdentifierName
@@ -479,20 +725,21 @@ impl<'s> Lexer<'s> {
     /// 返回解析过程是否成功
     fn parse_identifier_name_part(&mut self) -> LexerResultOnlyErr {
         loop {
+            self.check_budget()?;
             match self.reader.current() {
-                Some(chr)
-                    if code_points::is_id_start(chr)
-                        || code_points::is_id_continue(chr)
-                        || matches!(chr as u32, 0x200c | 0x200d)
-                        || matches!(chr, '$' | '_') =>
-                {
-                    self.savenext(chr)
-                }
-                Some('\\') => {
+                Some(chr) if self.is_identifier_part(chr) => self.savenext(chr),
+                Some('\\') if self.options.allow_identifier_escapes => {
                     self.next(1);
 
                     self.parse_unicode_escape_sequence()?;
                 }
+                Some('\\') => {
+                    return Err(lexer_error::LexerError::new(
+                        lexer_error::LexerErrorKind::InvalidEscape,
+                        self.line_number,
+                        self.line_off,
+                    ))
+                }
                 _ => break,
             }
         }
@@ -500,6 +747,22 @@ impl<'s> Lexer<'s> {
         Ok(())
     }
 
+    /// 判断字符是否可以作为 IdentifierName 的首字符，包括 LexerOptions 中额外指定的字符
+    #[inline(always)]
+    fn is_identifier_start(&self, chr: char) -> bool {
+        matches!(chr, '$' | '_')
+            || code_points::is_id_start(chr)
+            || self.options.extra_identifier_starts.contains(&chr)
+    }
+
+    /// 判断字符是否可以出现在 IdentifierName 中，包括 LexerOptions 中额外指定的字符
+    #[inline(always)]
+    fn is_identifier_part(&self, chr: char) -> bool {
+        code_points::is_identifier_part(chr)
+            || self.options.extra_identifier_starts.contains(&chr)
+            || self.options.extra_identifier_parts.contains(&chr)
+    }
+
     /// 解析 IdentifierName，若解析出的结果为关键字，则转换为对应的关键字 Token
     ///
     /// # Returns
@@ -558,12 +821,12 @@ impl<'s> Lexer<'s> {
     ///     `#` IdentifierName
     ///
     /// # Returns
-    /// 返回 PrivateIdentifier Token
+    /// 返回 PrivateIdentifier Token，其文本包含开头的 `#`，如 `#foo`
     fn parse_private_identifier(&mut self) -> LexerResult {
         self.savenext('#');
         self.parse_identifier_name_part()?;
 
-        Ok(Token::IdentifierName(self.get_tokenbuf()))
+        Ok(Token::PrivateIdentifier(self.get_tokenbuf()))
     }
 
     /// 解析数字
@@ -623,6 +886,7 @@ impl<'s> Lexer<'s> {
         }
 
         let mut has_digit = false;
+        let mut has_exp = false;
         let mut only_dec = false;
         let mut may_allow_exp = false;
         let mut allow_exp = false;
@@ -663,8 +927,21 @@ impl<'s> Lexer<'s> {
         };
 
         loop {
+            self.check_budget()?;
+            // NumericLiteralSeparator 只能出现在两个同进制的数字之间，
+            // 不能紧跟前缀、小数点、指数标记，也不能出现在旧式八进制数字中
+            let prev = self.tokenbuf.chars().next_back();
             match self.reader.current() {
-                Some('n') if only_dec => {
+                Some('n')
+                    if only_dec
+                        || (has_digit
+                            && matches!(
+                                number_type,
+                                NumberType::MustBinary
+                                    | NumberType::MustOctal
+                                    | NumberType::MustHex
+                            )) =>
+                {
                     self.savecurrent(1);
                     break;
                 }
@@ -672,6 +949,7 @@ impl<'s> Lexer<'s> {
                     if allow_exp && matches!(self.reader.lookahead(), Some('+' | '-')) =>
                 {
                     allow_exp = false;
+                    has_exp = true;
                     only_dec = false;
                     has_digit = false;
                     allow_dot = false;
@@ -681,6 +959,7 @@ impl<'s> Lexer<'s> {
                 }
                 Some('e' | 'E') if allow_exp => {
                     allow_exp = false;
+                    has_exp = true;
                     only_dec = false;
                     has_digit = false;
                     allow_dot = false;
@@ -695,19 +974,16 @@ impl<'s> Lexer<'s> {
                     self.savecurrent(1);
                 }
                 Some('_')
-                    if has_digit
+                    if matches!(prev, Some(chr) if chr.is_ascii_hexdigit())
                         && matches!(number_type, NumberType::MustHex)
-                        && matches!(self.reader.lookahead(), Some(chr) if chr.is_digit(16)) =>
+                        && matches!(self.reader.lookahead(), Some(chr) if chr.is_ascii_hexdigit()) =>
                 {
                     self.savecurrent(2);
                 }
                 Some('_')
-                    if has_digit
-                        && matches!(
-                            number_type,
-                            NumberType::MustDecimal | NumberType::MaybeOctal
-                        )
-                        && matches!(self.reader.lookahead(), Some(chr) if chr.is_digit(10)) =>
+                    if matches!(prev, Some(chr) if chr.is_ascii_digit())
+                        && matches!(number_type, NumberType::MustDecimal)
+                        && matches!(self.reader.lookahead(), Some(chr) if chr.is_ascii_digit()) =>
                 {
                     if may_allow_exp {
                         may_allow_exp = false;
@@ -716,21 +992,23 @@ impl<'s> Lexer<'s> {
                     self.savecurrent(2);
                 }
                 Some('_')
-                    if has_digit
+                    if matches!(prev, Some(chr) if chr.is_digit(8))
                         && matches!(number_type, NumberType::MustOctal)
                         && matches!(self.reader.lookahead(), Some(chr) if chr.is_digit(8)) =>
                 {
                     self.savecurrent(2);
                 }
                 Some('_')
-                    if has_digit
+                    if matches!(prev, Some(chr) if chr.is_digit(2))
                         && matches!(number_type, NumberType::MustBinary)
                         && matches!(self.reader.lookahead(), Some(chr) if chr.is_digit(2)) =>
                 {
                     self.savecurrent(2);
                 }
+                // `0` 之后紧跟数字时不能作为 BigInt，如 `017n`、`08n`
                 Some('0'..='7') if matches!(number_type, NumberType::MaybeOctal) => {
                     has_digit = true;
+                    only_dec = false;
                     if may_allow_exp {
                         may_allow_exp = false;
                         allow_exp = true;
@@ -740,17 +1018,22 @@ impl<'s> Lexer<'s> {
                 Some('8'..='9') if matches!(number_type, NumberType::MaybeOctal) => {
                     number_type = NumberType::MustDecimal;
                     has_digit = true;
+                    only_dec = false;
                     if may_allow_exp {
                         may_allow_exp = false;
                         allow_exp = true;
                     }
                     self.savecurrent(1);
                 }
-                Some(chr) if matches!(number_type, NumberType::MustHex) && chr.is_digit(16) => {
+                Some(chr)
+                    if matches!(number_type, NumberType::MustHex) && chr.is_ascii_hexdigit() =>
+                {
                     has_digit = true;
                     self.savenext(chr);
                 }
-                Some(chr) if matches!(number_type, NumberType::MustDecimal) && chr.is_digit(10) => {
+                Some(chr)
+                    if matches!(number_type, NumberType::MustDecimal) && chr.is_ascii_digit() =>
+                {
                     has_digit = true;
                     if may_allow_exp {
                         may_allow_exp = false;
@@ -770,6 +1053,28 @@ impl<'s> Lexer<'s> {
             }
         }
 
+        // 带前缀的数字与指数部分至少需要一位数字，且数字之后不能紧跟 IdentifierStart 或 DecimalDigit，
+        // IdentifierStart 包括以 `\` 开头的 Unicode 转义序列
+        let missing_digits = !has_digit
+            && (has_exp
+                || matches!(
+                    number_type,
+                    NumberType::MustBinary | NumberType::MustOctal | NumberType::MustHex
+                ));
+        // 严格模式下不允许 LegacyOctalIntegerLiteral 与 NonOctalDecimalIntegerLiteral
+        let legacy = self.options.strict
+            && matches!(self.tokenbuf.as_bytes(), [b'0', digit, ..] if digit.is_ascii_digit());
+        if missing_digits
+            || legacy
+            || matches!(self.reader.current(), Some(chr) if chr.is_ascii_digit() || chr == '\\' || self.is_identifier_start(chr))
+        {
+            return Err(lexer_error::LexerError::new(
+                lexer_error::LexerErrorKind::InvalidNumericLiteral,
+                self.line_number,
+                self.line_off,
+            ));
+        }
+
         Ok(Token::Number(self.get_tokenbuf()))
     }
 
@@ -788,6 +1093,25 @@ impl<'s> Lexer<'s> {
             Some('\\') => {
                 self.next(1);
                 match self.reader.current() {
+                    // 严格模式与 template 中不允许 LegacyOctalEscapeSequence 与 NonOctalDecimalEscapeSequence，
+                    // template 中出现时其 cooked 值为 None（正在记录 raw 值即表示位于 template 中）
+                    Some('1'..='9') if self.options.strict || self.rawbuf.is_some() => {
+                        return Err(lexer_error::LexerError::new(
+                            lexer_error::LexerErrorKind::InvalidEscape,
+                            self.line_number,
+                            self.line_off,
+                        ))
+                    }
+                    Some('0')
+                        if (self.options.strict || self.rawbuf.is_some())
+                            && matches!(self.reader.lookahead(), Some(chr) if chr.is_ascii_digit()) =>
+                    {
+                        return Err(lexer_error::LexerError::new(
+                            lexer_error::LexerErrorKind::InvalidEscape,
+                            self.line_number,
+                            self.line_off,
+                        ))
+                    }
                     Some('\'') => self.savecurrent(1),
                     Some('\"') => self.savecurrent(1),
                     Some('\\') => self.savecurrent(1),
@@ -797,7 +1121,7 @@ impl<'s> Lexer<'s> {
                     Some('r') => self.savenext('\r'),
                     Some('t') => self.savenext('\t'),
                     Some('v') => self.savenext('\x0b'),
-                    Some('0') if !matches!(self.reader.lookahead(), Some(chr) if chr.is_digit(10)) =>
+                    Some('0') if !matches!(self.reader.lookahead(), Some(chr) if chr.is_ascii_digit()) =>
                     {
                         self.savenext('\0');
                     }
@@ -830,6 +1154,7 @@ impl<'s> Lexer<'s> {
                             self.save(chr);
                         } else {
                             return Err(lexer_error::LexerError::new(
+                                lexer_error::LexerErrorKind::InvalidEscape,
                                 self.line_number,
                                 self.line_off,
                             ));
@@ -844,6 +1169,7 @@ impl<'s> Lexer<'s> {
                                 val |= digit;
                             } else {
                                 return Err(lexer_error::LexerError::new(
+                                    lexer_error::LexerErrorKind::InvalidEscape,
                                     self.line_number,
                                     self.line_off,
                                 ));
@@ -854,6 +1180,7 @@ impl<'s> Lexer<'s> {
                             self.save(chr);
                         } else {
                             return Err(lexer_error::LexerError::new(
+                                lexer_error::LexerErrorKind::InvalidEscape,
                                 self.line_number,
                                 self.line_off,
                             ));
@@ -864,11 +1191,12 @@ impl<'s> Lexer<'s> {
                             .reader
                             .current()
                             .and_then(|x| x.to_digit(8))
-                            .and_then(|x| char::from_u32(x))
+                            .and_then(char::from_u32)
                         {
                             self.savenext(chr);
                         } else {
                             return Err(lexer_error::LexerError::new(
+                                lexer_error::LexerErrorKind::InvalidEscape,
                                 self.line_number,
                                 self.line_off,
                             ));
@@ -885,6 +1213,7 @@ impl<'s> Lexer<'s> {
                                 val |= digit;
                             } else {
                                 return Err(lexer_error::LexerError::new(
+                                    lexer_error::LexerErrorKind::InvalidEscape,
                                     self.line_number,
                                     self.line_off,
                                 ));
@@ -895,14 +1224,18 @@ impl<'s> Lexer<'s> {
                             self.save(chr);
                         } else {
                             return Err(lexer_error::LexerError::new(
+                                lexer_error::LexerErrorKind::InvalidEscape,
                                 self.line_number,
                                 self.line_off,
                             ));
                         }
                     }
                     Some('u') => self.parse_unicode_escape_sequence()?,
+                    // NonEscapeCharacter，如 `\``、`\$`，转义结果为字符本身
+                    Some(chr) if !chr.is_ascii_digit() => self.savenext(chr),
                     _ => {
                         return Err(lexer_error::LexerError::new(
+                            lexer_error::LexerErrorKind::InvalidEscape,
                             self.line_number,
                             self.line_off,
                         ))
@@ -912,6 +1245,7 @@ impl<'s> Lexer<'s> {
             Some(chr) => self.savenext(chr),
             _ => {
                 return Err(lexer_error::LexerError::new(
+                    lexer_error::LexerErrorKind::UnterminatedString,
                     self.line_number,
                     self.line_off,
                 ))
@@ -950,12 +1284,22 @@ impl<'s> Lexer<'s> {
         self.next(1);
 
         loop {
-            if self.reader.current().eq(&quota) {
-                self.next(1);
-                break;
+            self.check_budget()?;
+            match self.reader.current() {
+                chr if chr == quota => {
+                    self.next(1);
+                    break;
+                }
+                // 源码在字符串结尾的引号之前结束，包括以 `\` 结束的情况
+                None | Some('\\') if self.reader.lookahead().is_none() => {
+                    return Err(lexer_error::LexerError::new(
+                        lexer_error::LexerErrorKind::UnterminatedString,
+                        self.line_number,
+                        self.line_off,
+                    ))
+                }
+                _ => self.parse_string_content()?,
             }
-
-            self.parse_string_content()?;
         }
 
         Ok(Token::Str(self.get_tokenbuf()))
@@ -986,30 +1330,101 @@ impl<'s> Lexer<'s> {
         let is_head = matches!(self.reader.current(), Some('`'));
         self.next(1);
 
+        self.rawbuf = Some(String::new());
+        let result = self.parse_template_characters(is_head);
+        self.rawbuf = None;
+
+        result
+    }
+
+    /// 解析 template 的 TemplateCharacters 及其之后的 `` ` `` 或 `${`
+    ///
+    /// 在得到 cooked 值的同时记录原始字符作为 raw 值。
+    /// 转义序列不合法时 cooked 值不可用（带标签的 template 允许这种情况），继续扫描直至 template 结束
+    ///
+    /// # Arguments
+    /// `is_head` - 是否以 `` ` `` 开始
+    /// # Returns
+    /// 返回 template 对应的 Token
+    fn parse_template_characters(&mut self, is_head: bool) -> LexerResult {
+        let mut cooked_valid = true;
+
         loop {
+            self.check_budget()?;
             match self.reader.current() {
                 Some('`') => {
+                    let (cooked, raw) = self.take_template_value(cooked_valid);
                     self.next(1);
                     break Ok(if is_head {
-                        Token::Str(self.get_tokenbuf())
+                        Token::NoSubstitutionTemplate { cooked, raw }
                     } else {
-                        Token::TemplateTail(self.get_tokenbuf())
+                        Token::TemplateTail { cooked, raw }
                     });
                 }
                 Some('$') if matches!(self.reader.lookahead(), Some('{')) => {
+                    let (cooked, raw) = self.take_template_value(cooked_valid);
                     self.next(2);
                     self.template_enter_expression();
                     break Ok(if is_head {
-                        Token::TemplateHead(self.get_tokenbuf())
+                        Token::TemplateHead { cooked, raw }
                     } else {
-                        Token::TemplateMiddle(self.get_tokenbuf())
+                        Token::TemplateMiddle { cooked, raw }
                     });
                 }
-                _ => self.parse_string_content()?,
+                // 模板中的 <CR><LF> 与 <CR> 在 cooked 值中统一规范化为 <LF>
+                Some(code_points::CR | code_points::LF) => {
+                    self.save('\n');
+                    self.newline();
+                }
+                Some(chr @ (code_points::LS | code_points::PS)) => {
+                    self.save(chr);
+                    self.newline();
+                }
+                // 源码在 template 结尾的 `` ` `` 之前结束，包括以 `\` 结束的情况
+                None | Some('\\') if self.reader.lookahead().is_none() => {
+                    return Err(lexer_error::LexerError::new(
+                        lexer_error::LexerErrorKind::UnterminatedTemplate,
+                        self.line_number,
+                        self.line_off,
+                    ))
+                }
+                _ => match self.parse_string_content() {
+                    Ok(()) => {}
+                    Err(err)
+                        if matches!(
+                            err.kind(),
+                            lexer_error::LexerErrorKind::InvalidEscape
+                                | lexer_error::LexerErrorKind::InvalidUnicodeCodePoint
+                        ) =>
+                    {
+                        cooked_valid = false;
+                    }
+                    Err(err) => return Err(err),
+                },
             }
         }
     }
 
+    /// 取出 template 当前部分的 cooked 值与 raw 值
+    ///
+    /// raw 值中的 <CR><LF> 与 <CR> 同样规范化为 <LF>
+    ///
+    /// # Arguments
+    /// `cooked_valid` - 转义序列是否均合法
+    /// # Returns
+    /// 返回 cooked 值（不可用时为 None）与 raw 值
+    fn take_template_value(&mut self, cooked_valid: bool) -> (Option<String>, String) {
+        let raw = self
+            .rawbuf
+            .take()
+            .unwrap_or_default()
+            .replace("\r\n", "\n")
+            .replace('\r', "\n");
+        let cooked = cooked_valid.then(|| self.get_tokenbuf());
+
+        (cooked, raw)
+    }
+
     /// 解析正则表达式
     ///
     /// RegularExpressionLiteral ::
@@ -1045,6 +1460,7 @@ impl<'s> Lexer<'s> {
 
         let mut class_depth = 0;
         loop {
+            self.check_budget()?;
             if matches!(self.reader.current(), Some('/')) {
                 self.next(1);
                 break;
@@ -1056,12 +1472,14 @@ impl<'s> Lexer<'s> {
                 }
                 Some('\\') if matches!(self.reader.lookahead(), Some(chr) if code_points::is_line_terminator(chr)) => {
                     return Err(lexer_error::LexerError::new(
+                        lexer_error::LexerErrorKind::UnterminatedRegex,
                         self.line_number,
                         self.line_off,
                     ))
                 }
-                Some('\\') if matches!(self.reader.lookahead(), None) => {
+                Some('\\') if self.reader.lookahead().is_none() => {
                     return Err(lexer_error::LexerError::new(
+                        lexer_error::LexerErrorKind::UnterminatedRegex,
                         self.line_number,
                         self.line_off,
                     ))
@@ -1072,6 +1490,7 @@ impl<'s> Lexer<'s> {
                 }
                 Some(']') if class_depth <= 0 => {
                     return Err(lexer_error::LexerError::new(
+                        lexer_error::LexerErrorKind::InvalidRegex,
                         self.line_number,
                         self.line_off,
                     ))
@@ -1082,6 +1501,7 @@ impl<'s> Lexer<'s> {
                 }
                 Some(chr) if code_points::is_line_terminator(chr) => {
                     return Err(lexer_error::LexerError::new(
+                        lexer_error::LexerErrorKind::UnterminatedRegex,
                         self.line_number,
                         self.line_off,
                     ))
@@ -1089,6 +1509,7 @@ impl<'s> Lexer<'s> {
                 Some(chr) => self.savenext(chr),
                 _ => {
                     return Err(lexer_error::LexerError::new(
+                        lexer_error::LexerErrorKind::UnterminatedRegex,
                         self.line_number,
                         self.line_off,
                     ))
@@ -1098,12 +1519,60 @@ impl<'s> Lexer<'s> {
 
         if class_depth != 0 {
             return Err(lexer_error::LexerError::new(
+                lexer_error::LexerErrorKind::InvalidRegex,
+                self.line_number,
+                self.line_off,
+            ));
+        }
+        let pattern = self.get_tokenbuf();
+
+        self.clear();
+        while let Some(chr) = self.reader.current() {
+            self.check_budget()?;
+            if !self.is_identifier_part(chr) {
+                break;
+            }
+            self.savenext(chr);
+        }
+
+        let flags = self.get_tokenbuf();
+        self.validate_regular_flags(&flags)?;
+
+        Ok(Token::Regex { pattern, flags })
+    }
+
+    /// 校验正则表达式的 flags
+    ///
+    /// flags 只能由 `d` `g` `i` `m` `s` `u` `v` `y` 组成，不允许重复，
+    /// 且 `u` 与 `v` 不能同时出现
+    ///
+    /// # Arguments
+    /// `flags` - 正则表达式的 flags
+    /// # Returns
+    /// flags 不合法时返回报错
+    fn validate_regular_flags(&self, flags: &str) -> LexerResultOnlyErr {
+        let mut seen = Vec::with_capacity(flags.len());
+        for flag in flags.chars() {
+            if !matches!(flag, 'd' | 'g' | 'i' | 'm' | 's' | 'u' | 'v' | 'y')
+                || seen.contains(&flag)
+            {
+                return Err(lexer_error::LexerError::new(
+                    lexer_error::LexerErrorKind::InvalidRegex,
+                    self.line_number,
+                    self.line_off,
+                ));
+            }
+            seen.push(flag);
+        }
+        if seen.contains(&'u') && seen.contains(&'v') {
+            return Err(lexer_error::LexerError::new(
+                lexer_error::LexerErrorKind::InvalidRegex,
                 self.line_number,
                 self.line_off,
             ));
         }
 
-        Ok(Token::Regular(self.get_tokenbuf()))
+        Ok(())
     }
 
     /// 获取下一个 Token
@@ -1111,28 +1580,332 @@ impl<'s> Lexer<'s> {
     /// # Returns
     /// 如果获取下一个 token 失败，则返回报错
     pub(crate) fn next_token(&mut self) -> LexerResultOnlyErr {
-        self.tok = self.scan()?;
+        if let Some((tok, span, newline_before)) = self.peeked.take() {
+            self.tok = tok;
+            self.span = span;
+            self.newline_before = newline_before;
+
+            return Ok(());
+        }
+        let (tok, span) = self.scan()?;
+        self.tok = tok;
+        self.span = span;
+        self.check_budget()?;
 
         Ok(())
     }
 
+    /// 预读下一个 Token，不改变当前 Token
+    ///
+    /// 预读的结果会被缓存，随后的 next_token 直接使用该结果。
+    /// 预读与逐个读取时 Token 均按源码顺序扫描，正则表达式与除法的判定不受影响
+    ///
+    /// # Returns
+    /// 返回下一个 Token
+    pub(crate) fn peek_token(&mut self) -> Result<&Token, lexer_error::LexerError> {
+        let peeked = match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => {
+                let newline_before = self.newline_before;
+                let (tok, span) = self.scan()?;
+                let peeked = (tok, span, self.newline_before);
+                self.newline_before = newline_before;
+
+                peeked
+            }
+        };
+
+        Ok(&self.peeked.insert(peeked).0)
+    }
+
     /// 获取当前 Token
     #[inline(always)]
     pub(crate) const fn current(&self) -> &Token {
         &self.tok
     }
 
+    /// 当前 Token 是否为 EOF
+    ///
+    /// 到达源码末尾后，再次调用 next_token 会稳定地返回 EOF，
+    /// 因此以 `while !lexer.is_eof()` 驱动的循环不会越界
+    #[inline(always)]
+    pub(crate) const fn is_eof(&self) -> bool {
+        matches!(self.tok, Token::EOF)
+    }
+
+    /// 获取当前 Token 在源码中的位置
+    #[inline(always)]
+    pub(crate) const fn span(&self) -> Span {
+        self.span
+    }
+
+    /// 获取源码游标当前所在的行号与列号
+    ///
+    /// 游标位于最近一次扫描到的 Token 之后，预读 Token 时游标位于预读的 Token 之后
+    ///
+    /// # Returns
+    /// 返回 `(行号, 列号)`，均从 1 开始
+    #[inline(always)]
+    pub(crate) const fn position(&self) -> (usize, usize) {
+        (self.line_number, self.line_off)
+    }
+
+    /// 获取已扫描过的每一行的起始字节偏移量
+    ///
+    /// 第 n 行（从 1 开始）的起始偏移量位于下标 n - 1 处，
+    /// 表中只包含游标已经越过的行，可用于生成 source map 或转换 LSP 位置
+    #[inline(always)]
+    pub(crate) fn line_starts(&self) -> &[usize] {
+        &self.line_starts
+    }
+
+    /// 将字节偏移量转换为行号与列号
+    ///
+    /// 在 line_starts 中二分查找偏移量所在的行，
+    /// 偏移量超出已扫描的范围时视为位于最后一个已扫描的行中。
+    /// 列号与 Position 一致按字符计算，行首至偏移量之间的多字节字符由 wide_chars 修正
+    ///
+    /// # Arguments
+    /// `offset` - 源码中的字节偏移量
+    /// # Returns
+    /// 返回 `(行号, 列号)`，均从 1 开始，列号为距离行首的字符数加 1
+    pub(crate) fn offset_to_position(&self, offset: usize) -> (usize, usize) {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+
+        let first = self
+            .wide_chars
+            .partition_point(|&(start, _)| start < line_start);
+        let last = self
+            .wide_chars
+            .partition_point(|&(start, _)| start < offset);
+        let extra: usize = self.wide_chars[first..last]
+            .iter()
+            .map(|&(_, extra)| extra)
+            .sum();
+
+        (line, offset - line_start - extra + 1)
+    }
+
+    /// 获取源码游标当前指向的位置
+    #[inline(always)]
+    fn cursor_position(&self) -> Position {
+        Position::new(self.reader.offset(), self.line_number, self.line_off)
+    }
+
+    /// 获取最近一次扫描到的注释
+    ///
+    /// 用于提取 `/* @flow */`、`//# sourceMappingURL=` 等注释中的编译指示，
+    /// 无需重新扫描源码。Hashbang 注释不计入
+    ///
+    /// # Returns
+    /// 返回注释类型及去除 `//`、`/*`、`*/` 后的注释内容，尚未扫描到注释时返回 None
+    pub(crate) fn last_comment(&self) -> Option<(CommentKind, &str)> {
+        self.last_comment
+            .map(|kind| (kind, self.comment_buf.as_str()))
+    }
+
+    /// 当前 Token 之前是否出现过 LineTerminator
+    ///
+    /// 多行注释内部包含换行时，该注释 Token 本身也会被标记，
+    /// 供语法分析器处理自动分号插入 (ASI) 时使用
+    ///
+    /// # Returns
+    /// 返回当前 Token 与上一个 Token 之间是否存在换行
+    #[inline(always)]
+    pub(crate) const fn has_newline_before(&self) -> bool {
+        self.newline_before
+    }
+
+    /// 从 EMCAScript 源码的当前游标起进行扫描，获取下一个 Token 及其位置
+    ///
+    /// # Returns
+    /// 返回下一个 Token 及其在源码中的位置
+    fn scan(&mut self) -> Result<(Token, Span), lexer_error::LexerError> {
+        let result = self.scan_token();
+        // 读取失败时源码提前结束，此时产生的 EOF 或报错均应视为读取失败
+        if matches!(result, Ok(Token::EOF) | Err(_))
+            && self.reader.current().is_none()
+            && self.reader.error().is_some()
+        {
+            return Err(lexer_error::LexerError::new(
+                lexer_error::LexerErrorKind::ReadError,
+                self.line_number,
+                self.line_off,
+            ));
+        }
+
+        let tok = match result {
+            Ok(tok) => tok,
+            Err(err)
+                if self.options.recover
+                    && err.kind() != lexer_error::LexerErrorKind::BudgetExceeded =>
+            {
+                self.recover()
+            }
+            Err(err) => return Err(err),
+        };
+
+        if !tok.is_trivia() {
+            self.update_regex_context(&tok);
+        }
+
+        Ok((tok, Span::new(self.tok_start, self.cursor_position())))
+    }
+
+    /// 根据扫描到的非空白、非注释的 Token，判定随后的 `/` 是正则表达式还是除法
+    ///
+    /// 字面量、标识符、`this`、`super` 等结束一个表达式的 Token 之后为除法，
+    /// `return`、`typeof`、`case` 等关键字与运算符之后为正则表达式。
+    /// 闭合括号的判定取决于与之匹配的开括号：
+    /// `if (...)` 等控制语句的条件与语句块的 `}` 之后为正则表达式，
+    /// 其他 `)`、`]`、对象字面量以及函数表达式、类表达式主体的 `}` 之后为除法，
+    /// `export default` 之后的函数与类视为声明。
+    /// `.` 之后的保留字为属性名，同样结束一个表达式
+    ///
+    /// # Arguments
+    /// `tok` - 扫描到的 Token
+    fn update_regex_context(&mut self, tok: &Token) {
+        let after_dot = std::mem::replace(
+            &mut self.after_dot,
+            matches!(tok, Token::Operator('.') | Token::Chain),
+        );
+        if after_dot && tok.keyword().is_some() {
+            self.regex_allowed = false;
+            self.block_allowed = false;
+            self.after_control = false;
+            self.after_default = false;
+            return;
+        }
+
+        let (regex_allowed, block_allowed) = match tok {
+            // 不在语句起始处且不在 `default` 之后的 `function` 与 `class` 为表达式
+            Token::Function | Token::Class => {
+                let declaration = self.block_allowed || self.after_default;
+                if !declaration {
+                    self.pending_bodies.push(self.brackets.len());
+                }
+                // 匿名类声明的 `{` 紧随 `class` 之后，为类体
+                (true, declaration)
+            }
+            Token::Operator('(') => {
+                self.brackets.push(OpenBracket::Paren {
+                    after_control: self.after_control,
+                });
+                (true, false)
+            }
+            Token::Operator('[') => {
+                self.brackets.push(OpenBracket::Square);
+                (true, false)
+            }
+            Token::Operator('{') => {
+                // 与函数表达式或类表达式位于同一括号层数的 `{` 为其主体；
+                // 其余情况下，结束表达式的 Token 之后不能出现对象字面量，此时 `{` 为函数体或类体
+                if self.pending_bodies.last() == Some(&self.brackets.len()) {
+                    self.pending_bodies.pop();
+                    self.brackets.push(OpenBracket::ExpressionBody);
+                    (true, true)
+                } else if self.regex_allowed && !self.block_allowed {
+                    self.brackets.push(OpenBracket::Object);
+                    (true, false)
+                } else {
+                    self.brackets.push(OpenBracket::Block);
+                    (true, true)
+                }
+            }
+            Token::Operator(')') => match self.brackets.pop() {
+                Some(OpenBracket::Paren {
+                    after_control: true,
+                }) => (true, true),
+                _ => (false, false),
+            },
+            Token::Operator(']') => {
+                self.brackets.pop();
+                (false, false)
+            }
+            Token::Operator('}') => match self.brackets.pop() {
+                Some(OpenBracket::Object | OpenBracket::ExpressionBody) => (false, false),
+                _ => (true, true),
+            },
+            Token::Operator(';') | Token::ArrowFunction => (true, true),
+            // `case x:` 与标签之后为语句，对象字面量与条件表达式中的 `:` 之后为表达式
+            Token::Operator(':') => (
+                true,
+                matches!(self.brackets.last(), None | Some(OpenBracket::Block)),
+            ),
+            Token::Else | Token::Do | Token::Try | Token::Finally => (true, true),
+            Token::Number(..)
+            | Token::IdentifierName(..)
+            | Token::PrivateIdentifier(..)
+            | Token::Str(..)
+            | Token::NoSubstitutionTemplate { .. }
+            | Token::TemplateTail { .. }
+            | Token::This
+            | Token::Super
+            | Token::Null
+            | Token::True
+            | Token::False
+            | Token::Incr
+            | Token::Decr => (false, false),
+            _ => (true, false),
+        };
+
+        self.regex_allowed = regex_allowed;
+        self.block_allowed = block_allowed;
+        self.after_control = matches!(tok, Token::If | Token::While | Token::For | Token::With);
+        self.after_default = matches!(tok, Token::Default);
+    }
+
+    /// 容错模式下从词法错误中恢复
+    ///
+    /// 出错的字符已被消费时，下一次扫描从游标处继续进行；
+    /// 若游标仍停留在当前 Token 的起始处，则越过该字符，保证扫描能够向前推进
+    ///
+    /// # Returns
+    /// 返回覆盖从当前 Token 起始处到恢复位置的 `Token::Error`
+    fn recover(&mut self) -> Token {
+        if self.reader.offset() == self.tok_start.offset {
+            match self.reader.current() {
+                Some(chr) if code_points::is_line_terminator(chr) => self.newline(),
+                Some(_) => self.next(1),
+                // 源码在 template 的表达式部分中结束时，丢弃未闭合的 template，避免重复报错
+                None => self.template_expression.clear(),
+            }
+        }
+        self.tokenbuf.clear();
+
+        Token::Error(Span::new(self.tok_start, self.cursor_position()))
+    }
+
     /// 从 EMCAScript 源码的当前游标起进行扫描，获取下一个 Token
     ///
     /// # Returns
     /// 返回下一个 Token
-    fn scan(&mut self) -> LexerResult {
+    fn scan_token(&mut self) -> LexerResult {
         self.tokenbuf.clear();
+        self.newline_before = false;
 
         loop {
+            self.check_budget()?;
+            self.tok_start = self.cursor_position();
+
             match self.reader.current() {
-                Some('#') if matches!(self.reader.lookahead(), Some('!')) => {
-                    return self.parse_hashbang_comment(); // `#!`
+                // 额外指定的标识符首字符优先于其他规则
+                Some(chr) if self.options.extra_identifier_starts.contains(&chr) => {
+                    return self.parse_identifier_name();
+                }
+
+                // Hashbang 注释只能是源码的起始字符，其他位置的 `#!` 按普通字符处理
+                Some('#')
+                    if matches!(self.reader.lookahead(), Some('!'))
+                        && self.tok_start.offset == self.source_start =>
+                {
+                    let tok = self.parse_hashbang_comment()?; // `#!`
+                    if !self.options.skip_comments {
+                        return Ok(tok);
+                    }
+                    self.tokenbuf.clear();
+                    continue;
                 }
                 Some('#')
                     if matches!(self.reader.lookahead(), Some('$' | '_'))
@@ -1143,18 +1916,18 @@ impl<'s> Lexer<'s> {
 
                 // 注释
                 Some('/') if matches!(self.reader.lookahead(), Some('*' | '/')) => {
-                    return self.parse_comment()
+                    let kind = self.parse_comment()?;
+                    if !self.options.skip_comments {
+                        let text = self.comment_buf.clone();
+                        return Ok(match kind {
+                            CommentKind::Line => Token::LineComment(text),
+                            CommentKind::Block => Token::BlockComment(text),
+                        });
+                    }
+                    continue;
                 }
                 // 正则表达式
-                Some('/')
-                    if !matches!(
-                        self.current(),
-                        Token::Number(..)
-                            | Token::IdentifierName(..)
-                            | Token::Str(..)
-                            | Token::Operator(')' | ']')
-                    ) =>
-                {
+                Some('/') if self.regex_allowed => {
                     return self.parse_regular();
                 }
                 // 除法运算符
@@ -1309,8 +2082,9 @@ impl<'s> Lexer<'s> {
                 }
                 Some('?') if matches!(self.reader.lookahead(), Some('.')) => {
                     self.next(2);
-                    if matches!(self.reader.current(), Some(chr) if chr.is_digit(10)) {
+                    if matches!(self.reader.current(), Some(chr) if chr.is_ascii_digit()) {
                         return Err(lexer_error::LexerError::new(
+                            lexer_error::LexerErrorKind::InvalidToken,
                             self.line_number,
                             self.line_off,
                         ));
@@ -1348,29 +2122,89 @@ impl<'s> Lexer<'s> {
 
                 // 换行
                 Some(chr) if code_points::is_line_terminator(chr) => {
+                    let crlf = chr == code_points::CR
+                        && matches!(self.reader.lookahead(), Some(code_points::LF));
                     self.newline();
-                    return Ok(Token::LineTerminator);
+                    self.newline_before = true;
+                    if self.options.preserve_trivia {
+                        let text = if crlf {
+                            "\r\n".to_string()
+                        } else {
+                            chr.to_string()
+                        };
+                        return Ok(Token::Newline(text));
+                    }
+                    continue;
                 }
 
                 // White Space
+                Some(chr) if code_points::is_whitespace(chr) && self.options.preserve_trivia => {
+                    while let Some(chr) = self.reader.current() {
+                        self.check_budget()?;
+                        if !code_points::is_whitespace(chr) || code_points::is_line_terminator(chr)
+                        {
+                            break;
+                        }
+                        self.savenext(chr);
+                    }
+                    return Ok(Token::Whitespace(self.get_tokenbuf()));
+                }
                 Some(chr) if code_points::is_whitespace(chr) => {
                     self.next(1);
                     continue;
                 }
 
                 // IdentifierName || ReservedWord
-                Some(chr) if matches!(chr, '$' | '_') || code_points::is_id_start(chr) => {
+                Some(chr) if self.is_identifier_start(chr) => {
                     return self.parse_identifier_name(); // IdentifierName
                 }
+                // 以 Unicode 转义序列开头的 IdentifierName
+                Some('\\') if matches!(self.reader.lookahead(), Some('u')) => {
+                    return self.parse_identifier_name();
+                }
 
                 Some('0'..='9') => return self.parse_number(),
 
                 // 单字符操作符
                 Some(chr) => return Ok(self.operatornext(chr)),
 
+                // 源码在 template 的表达式部分中结束
+                None if self.template_in_expression() => {
+                    return Err(lexer_error::LexerError::new(
+                        lexer_error::LexerErrorKind::UnterminatedTemplate,
+                        self.line_number,
+                        self.line_off,
+                    ))
+                }
+
                 // 结束
                 None => return Ok(Token::EOF),
             }
         }
     }
 }
+
+/// 将词法分析器作为 Token 迭代器使用
+///
+/// 依次产出各个 Token，`Token::EOF` 仅产出一次，随后返回 None；
+/// 词法错误以 Err 产出，之后同样返回 None
+impl Iterator for Lexer<'_> {
+    type Item = Result<Token, lexer_error::LexerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        match self.next_token() {
+            Ok(()) => {
+                self.exhausted = matches!(self.tok, Token::EOF);
+                Some(Ok(self.tok.clone()))
+            }
+            Err(err) => {
+                self.exhausted = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
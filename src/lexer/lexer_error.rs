@@ -1,13 +1,97 @@
-pub(crate) struct LexerError {
+use std::{error, fmt};
+
+/// 词法分析错误类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LexerErrorKind {
+    /// 无法识别的 Token
+    InvalidToken,
+    /// 字符串缺少结尾的引号
+    UnterminatedString,
+    /// 多行注释缺少结尾的 `*/`
+    UnterminatedComment,
+    /// 正则表达式缺少结尾的 `/`
+    UnterminatedRegex,
+    /// 正则表达式的字符类或 flags 不合法
+    InvalidRegex,
+    /// 不合法的转义序列
+    InvalidEscape,
+    /// Unicode 转义序列表示的码点超出范围或不是合法的字符
+    InvalidUnicodeCodePoint,
+    /// 不合法的数字字面量
+    InvalidNumericLiteral,
+    /// 不合法的 template，如表达式部分中的 `}` 不匹配
+    InvalidTemplate,
+    /// template 缺少结尾的 `` ` ``，或源码在其表达式部分中结束
+    UnterminatedTemplate,
+    /// 超出了词法分析的操作预算
+    BudgetExceeded,
+    /// 读取源码失败，如 IO 报错或源码不是合法的 UTF-8
+    ReadError,
+}
+
+impl fmt::Display for LexerErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LexerErrorKind::InvalidToken => "lexical error",
+            LexerErrorKind::UnterminatedString => "unterminated string literal",
+            LexerErrorKind::UnterminatedComment => "unterminated comment",
+            LexerErrorKind::UnterminatedRegex => "unterminated regular expression",
+            LexerErrorKind::InvalidRegex => "invalid regular expression",
+            LexerErrorKind::InvalidEscape => "invalid escape sequence",
+            LexerErrorKind::InvalidUnicodeCodePoint => "invalid Unicode code point",
+            LexerErrorKind::InvalidNumericLiteral => "invalid numeric literal",
+            LexerErrorKind::InvalidTemplate => "invalid template literal",
+            LexerErrorKind::UnterminatedTemplate => "unterminated template literal",
+            LexerErrorKind::BudgetExceeded => "lexer operation budget exceeded",
+            LexerErrorKind::ReadError => "failed to read source",
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct LexerError {
+    kind: LexerErrorKind,
     line_number: usize,
     line_off: usize,
 }
 
 impl LexerError {
-    pub(super) fn new(line_number: usize, line_off: usize) -> Self {
+    pub(super) fn new(kind: LexerErrorKind, line_number: usize, line_off: usize) -> Self {
         LexerError {
+            kind,
             line_number,
             line_off,
         }
     }
+
+    /// 获取错误类型
+    #[inline(always)]
+    pub const fn kind(&self) -> LexerErrorKind {
+        self.kind
+    }
+
+    /// 获取出错位置的行号，从 1 开始
+    #[inline(always)]
+    pub const fn line(&self) -> usize {
+        self.line_number
+    }
+
+    /// 获取出错位置在行内的列号，从 1 开始
+    #[inline(always)]
+    pub const fn column(&self) -> usize {
+        self.line_off
+    }
 }
+
+impl fmt::Display for LexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.kind, self.line_number, self.line_off
+        )
+    }
+}
+
+impl error::Error for LexerError {}
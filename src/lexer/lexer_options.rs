@@ -0,0 +1,71 @@
+/// 词法分析器选项
+#[derive(Debug, Clone)]
+pub(crate) struct LexerOptions {
+    /// 是否允许 IdentifierName 中出现 Unicode 转义序列，如 `\u{61}bc`
+    ///
+    /// 该选项不影响字符串与 template 中的转义序列
+    pub(crate) allow_identifier_escapes: bool,
+
+    /// 词法分析允许消费的最大字符数，None 表示不限制
+    ///
+    /// 用于限制对不可信源码进行词法分析时的工作量，
+    /// 超出预算时返回 `LexerErrorKind::BudgetExceeded` 错误
+    pub(crate) max_operations: Option<usize>,
+
+    /// Token 缓冲区的初始容量
+    ///
+    /// 对于包含较长标识符或字符串的源码，预先分配缓冲区可以减少重新分配的次数
+    pub(crate) tokenbuf_capacity: usize,
+
+    /// 除标准规则外，额外允许作为 IdentifierName 首字符的字符
+    ///
+    /// 用于 JSX 等扩展了标识符文法的方言
+    pub(crate) extra_identifier_starts: Vec<char>,
+
+    /// 除标准规则外，额外允许出现在 IdentifierName 首字符之后的字符，
+    /// 如将 `-` 加入后 `data-x` 被识别为一个标识符
+    pub(crate) extra_identifier_parts: Vec<char>,
+
+    /// 是否启用容错模式
+    ///
+    /// 启用后遇到词法错误时不再中止扫描，而是产出一个携带出错片段位置的 `Token::Error`，
+    /// 越过出错的字符后继续扫描，便于一次性报告源码中的全部词法错误。
+    /// 超出操作预算的错误仍会中止扫描
+    pub(crate) recover: bool,
+
+    /// 是否跳过注释
+    ///
+    /// 启用后注释与 Hashbang 注释不再作为 Token 返回，扫描直接继续到下一个 Token。
+    /// 注释中的换行仍计入 `has_newline_before`，`last_comment` 仍会记录被跳过的注释
+    pub(crate) skip_comments: bool,
+
+    /// 是否保留空白与换行
+    ///
+    /// 启用后空白与换行不再被跳过，而是分别产出 `Token::Whitespace` 与 `Token::Newline`，
+    /// 配合注释 Token 即可按原样还原源码，供代码格式化等场景使用。
+    /// 此时 `has_newline_before` 仅在换行 Token 上为 true
+    pub(crate) preserve_trivia: bool,
+
+    /// 是否按严格模式进行词法分析
+    ///
+    /// 启用后旧式八进制数字（如 `010`）与以 `0` 开头的十进制数字（如 `08`）返回
+    /// `LexerErrorKind::InvalidNumericLiteral`，字符串中的旧式八进制转义序列（如 `\07`）
+    /// 与 `\8`、`\9` 返回 `LexerErrorKind::InvalidEscape`
+    pub(crate) strict: bool,
+}
+
+impl Default for LexerOptions {
+    fn default() -> Self {
+        Self {
+            allow_identifier_escapes: true,
+            max_operations: None,
+            tokenbuf_capacity: 0,
+            extra_identifier_starts: Vec::new(),
+            extra_identifier_parts: Vec::new(),
+            recover: false,
+            skip_comments: false,
+            preserve_trivia: false,
+            strict: false,
+        }
+    }
+}
@@ -1,6 +1,12 @@
-use crate::lexer::token::Token;
+use crate::lexer::token::{CommentKind, Token};
 
-use super::{lexer::Lexer, reader};
+use super::{
+    lexer::Lexer,
+    lexer_error::LexerErrorKind,
+    lexer_options::LexerOptions,
+    reader,
+    span::{Position, Span},
+};
 
 #[test]
 fn test_Lexer_parse_singleline_comment() {
@@ -11,13 +17,13 @@ fn test_Lexer_parse_singleline_comment() {
     let mut lexer = Lexer::new(&mut src);
 
     if lexer.next_token().is_ok() {
-        assert!(matches!(lexer.current(), Token::Comment(v) if v.eq(" hello world   ")))
+        assert!(matches!(lexer.current(), Token::LineComment(v) if v.eq(" hello world   ")))
     } else {
         panic!("next token failed")
     }
 
     if lexer.next_token().is_ok() {
-        assert!(matches!(lexer.current(), Token::Comment(v) if v.eq("// foobar")))
+        assert!(matches!(lexer.current(), Token::LineComment(v) if v.eq("// foobar")))
     } else {
         panic!("next token failed")
     }
@@ -34,7 +40,7 @@ fn test_Lexer_parse_multiline_comment() {
     let mut lexer = Lexer::new(&mut src);
 
     if lexer.next_token().is_ok() {
-        assert!(matches!(lexer.current(), Token::Comment(v) if v.eq("*\n* hello\n* world\n")))
+        assert!(matches!(lexer.current(), Token::BlockComment(v) if v.eq("*\n* hello\n* world\n")))
     } else {
         panic!("next token failed")
     }
@@ -52,6 +58,54 @@ fn test_Lexer_parse_hashbang_comment() {
     }
 }
 
+#[test]
+fn test_Lexer_hashbang_only_at_start() {
+    let verify = |source: &str| {
+        let mut src = reader::InlineSourceReader::new(source);
+        let mut lexer = Lexer::new(&mut src);
+        loop {
+            lexer.next_token().unwrap();
+            assert!(!matches!(lexer.current(), Token::HashbangComment(_)));
+            if lexer.is_eof() {
+                break;
+            }
+        }
+    };
+
+    verify("a\n#! x");
+    verify("\n#! x");
+    verify(" #! x");
+    verify("/* c */#! x");
+
+    let mut src = reader::InlineSourceReader::new("a\n#!x");
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Operator('#'));
+}
+
+#[test]
+fn test_Lexer_leading_bom() {
+    let mut src = reader::InlineSourceReader::new("\u{feff}#! x\na");
+    let mut lexer = Lexer::new(&mut src);
+
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::HashbangComment(" x".to_string()));
+    assert_eq!(
+        lexer.span(),
+        Span::new(Position::new(3, 1, 1), Position::new(7, 1, 5))
+    );
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::IdentifierName("a".to_string()));
+
+    // 只有源码起始处的 BOM 会被越过
+    let mut src = reader::InlineSourceReader::new("\u{feff}\u{feff}a");
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::IdentifierName("a".to_string()));
+    assert_eq!(lexer.span().start, Position::new(6, 1, 2));
+}
+
 #[test]
 fn test_Lexer_parse_identify_name() {
     let mut src = reader::InlineSourceReader::new(r#"$ _ h $hello _world foobar 张三"#);
@@ -77,6 +131,77 @@ fn test_Lexer_parse_identify_name() {
     verify("张三");
 }
 
+#[test]
+fn test_Lexer_unicode_identifier() {
+    let mut src = reader::InlineSourceReader::new("e\u{0301}t\u{0301} a\u{200d}b");
+    let tokens = Lexer::new(&mut src).collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::IdentifierName("e\u{0301}t\u{0301}".to_string()),
+            Token::IdentifierName("a\u{200d}b".to_string()),
+            Token::EOF,
+        ]
+    );
+
+    // 组合附加符号不能作为标识符的首字符
+    let mut src = reader::InlineSourceReader::new("\u{0301}a");
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Operator('\u{0301}'));
+}
+
+#[test]
+fn test_Lexer_fullwidth_digit_identifier() {
+    let mut src = reader::InlineSourceReader::new("a\u{ff10}\u{ff19}");
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(
+        lexer.current(),
+        &Token::IdentifierName("a\u{ff10}\u{ff19}".to_string())
+    );
+
+    let mut src = reader::InlineSourceReader::new("\u{ff10}abc");
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Operator('\u{ff10}'));
+}
+
+#[cfg(feature = "unicode-ident")]
+#[test]
+fn test_Lexer_astral_identifier() {
+    let mut src = reader::InlineSourceReader::new("\u{1d4b3} = \u{10400}\u{1d7ce}");
+    let tokens = Lexer::new(&mut src).collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::IdentifierName("\u{1d4b3}".to_string()),
+            Token::Operator('='),
+            Token::IdentifierName("\u{10400}\u{1d7ce}".to_string()),
+            Token::EOF,
+        ]
+    );
+}
+
+#[test]
+fn test_Lexer_parse_private_identifier() {
+    let mut src = reader::InlineSourceReader::new(r#"#foo foo this.#bar"#);
+    let mut lexer = Lexer::new(&mut src);
+
+    let tokens = lexer.by_ref().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::PrivateIdentifier("#foo".to_string()),
+            Token::IdentifierName("foo".to_string()),
+            Token::This,
+            Token::Operator('.'),
+            Token::PrivateIdentifier("#bar".to_string()),
+            Token::EOF,
+        ]
+    );
+}
+
 #[test]
 fn test_Lexer_parse_number() {
     let mut src = reader::InlineSourceReader::new(
@@ -137,23 +262,149 @@ fn test_Lexer_parse_string() {
 
 #[test]
 fn test_Lexer_parse_regular() {
-    let mut src = reader::InlineSourceReader::new(r#"/.*?/ /^.*?\/$/ /[\]]/ "#);
+    let mut src = reader::InlineSourceReader::new(r#"/.*?/ /^.*?\/$/ /[\]]/ /a.b/gi /x/gimsuy "#);
     let mut lexer = Lexer::new(&mut src);
 
-    let mut verify = |exp: &str| {
+    let mut verify = |pat: &str, flg: &str| {
         if lexer.next_token().is_ok() {
-            println!("verify token: {:?} {}", lexer.current(), exp);
-            assert!(matches!(lexer.current(), Token::Regular(v) if v.eq(exp)));
-            println!("verify token: {} success", exp);
+            println!("verify token: {:?} /{}/{}", lexer.current(), pat, flg);
+            assert!(matches!(
+                lexer.current(),
+                Token::Regex { pattern, flags } if pattern.eq(pat) && flags.eq(flg)
+            ));
+            println!("verify token: /{}/{} success", pat, flg);
         } else {
-            println!("verify token: {} failed", exp);
+            println!("verify token: /{}/{} failed", pat, flg);
             panic!("next token failed")
         }
     };
 
-    verify(".*?");
-    verify("^.*?\\/$");
-    verify("[\\]]");
+    verify(".*?", "");
+    verify("^.*?\\/$", "");
+    verify("[\\]]", "");
+    verify("a.b", "gi");
+    verify("x", "gimsuy");
+}
+
+#[test]
+fn test_Lexer_regex_or_division() {
+    // 返回源码中被识别为正则表达式的 pattern，以及除法运算符的个数
+    let scan = |source: &str| {
+        let mut src = reader::InlineSourceReader::new(source);
+        let mut lexer = Lexer::new(&mut src);
+        let mut patterns = Vec::new();
+        let mut divisions = 0;
+        loop {
+            lexer.next_token().unwrap();
+            match lexer.current() {
+                Token::EOF => break,
+                Token::Regex { pattern, .. } => patterns.push(pattern.clone()),
+                Token::Operator('/') => divisions += 1,
+                _ => {}
+            }
+        }
+        (patterns, divisions)
+    };
+
+    assert_eq!(scan("return /x/g"), (vec!["x".to_string()], 0));
+    assert_eq!(scan("typeof /x/"), (vec!["x".to_string()], 0));
+    assert_eq!(scan("case /x/:"), (vec!["x".to_string()], 0));
+    assert_eq!(scan("a / b / c"), (vec![], 2));
+    assert_eq!(scan("a.b / c / d"), (vec![], 2));
+    assert_eq!(scan("this / 2; super.x / 2"), (vec![], 2));
+    assert_eq!(scan("true / 2; null / 2"), (vec![], 2));
+    assert_eq!(scan("a++ / 2"), (vec![], 1));
+    assert_eq!(scan("(a) / 2; [a] / 2"), (vec![], 2));
+
+    // 语句块之后为正则表达式，对象字面量之后为除法
+    assert_eq!(scan("{}\n/re/g.test(a)"), (vec!["re".to_string()], 0));
+    assert_eq!(scan("} /re/"), (vec!["re".to_string()], 0));
+    assert_eq!(scan("if (a) {} /re/"), (vec!["re".to_string()], 0));
+    assert_eq!(
+        scan("function f() { return 1 } /re/"),
+        (vec!["re".to_string()], 0)
+    );
+    assert_eq!(scan("x = {} / 2"), (vec![], 1));
+    assert_eq!(scan("x = { a: { b: 1 } } / 2"), (vec![], 1));
+    assert_eq!(scan("f({}) / 2"), (vec![], 1));
+
+    // 函数表达式与类表达式的主体之后为除法，声明之后为正则表达式
+    assert_eq!(scan("x = function(){} / 2"), (vec![], 1));
+    assert_eq!(
+        scan("x = function f(a = {}) { if (a) {} } / 2"),
+        (vec![], 1)
+    );
+    assert_eq!(scan("x = class { m() {} } / 2"), (vec![], 1));
+    assert_eq!(scan("x = class extends B {} / 2"), (vec![], 1));
+    assert_eq!(
+        scan("f(function(){ /re/ }) / 2"),
+        (vec!["re".to_string()], 1)
+    );
+    assert_eq!(scan("class A {}\n/re/"), (vec!["re".to_string()], 0));
+    assert_eq!(
+        scan("{ function f() {} /re/ }"),
+        (vec!["re".to_string()], 0)
+    );
+    assert_eq!(
+        scan("export default function () {}\n/re/g"),
+        (vec!["re".to_string()], 0)
+    );
+    assert_eq!(
+        scan("export default class {}\n/re/g"),
+        (vec!["re".to_string()], 0)
+    );
+    assert_eq!(scan("export default {} / 2"), (vec![], 1));
+
+    // `.` 之后的保留字为属性名
+    assert_eq!(scan("a.default / 2"), (vec![], 1));
+    assert_eq!(scan("a?.return / b.if / 2"), (vec![], 2));
+
+    // 控制语句的条件之后为正则表达式
+    assert_eq!(scan("if (a) /re/.test(b)"), (vec!["re".to_string()], 0));
+    assert_eq!(scan("while (a) /re/"), (vec!["re".to_string()], 0));
+    assert_eq!(scan("f(a) / 2"), (vec![], 1));
+}
+
+#[test]
+fn test_Lexer_parse_regular_invalid_flags() {
+    for src in [r#"/x/gg"#, r#"/x/uv"#, r#"/x/gq"#] {
+        let mut src = reader::InlineSourceReader::new(src);
+        let mut lexer = Lexer::new(&mut src);
+
+        assert!(lexer.next_token().is_err());
+    }
+}
+
+#[test]
+fn test_Lexer_parse_regular_flags_end() {
+    let mut src = reader::InlineSourceReader::new(r#"(/x/g).test(/y/i)"#);
+    let mut lexer = Lexer::new(&mut src);
+
+    let mut tokens = Vec::new();
+    loop {
+        lexer.next_token().unwrap();
+        if matches!(lexer.current(), Token::EOF) {
+            break;
+        }
+        tokens.push(lexer.current().clone());
+    }
+
+    assert_eq!(tokens.len(), 8);
+    assert_eq!(
+        tokens[1],
+        Token::Regex {
+            pattern: "x".to_string(),
+            flags: "g".to_string(),
+        }
+    );
+    assert_eq!(tokens[2], Token::Operator(')'));
+    assert_eq!(
+        tokens[6],
+        Token::Regex {
+            pattern: "y".to_string(),
+            flags: "i".to_string(),
+        }
+    );
 }
 
 #[test]
@@ -171,13 +422,1075 @@ fn test_Lexer_parse_template() {
         println!("verify token: {:?} success", exp);
     };
 
-    verify(Token::TemplateHead("hello ".to_string()));
+    verify(Token::TemplateHead {
+        cooked: Some("hello ".to_string()),
+        raw: "hello ".to_string(),
+    });
     verify(Token::IdentifierName("world".to_string()));
-    verify(Token::TemplateMiddle("".to_string()));
-    verify(Token::TemplateHead("你".to_string()));
+    verify(Token::TemplateMiddle {
+        cooked: Some("".to_string()),
+        raw: "".to_string(),
+    });
+    verify(Token::TemplateHead {
+        cooked: Some("你".to_string()),
+        raw: "你".to_string(),
+    });
     verify(Token::IdentifierName("好".to_string()));
-    verify(Token::TemplateTail("".to_string()));
-    verify(Token::TemplateMiddle(" foo ".to_string()));
+    verify(Token::TemplateTail {
+        cooked: Some("".to_string()),
+        raw: "".to_string(),
+    });
+    verify(Token::TemplateMiddle {
+        cooked: Some(" foo ".to_string()),
+        raw: " foo ".to_string(),
+    });
     verify(Token::IdentifierName("bar".to_string()));
-    verify(Token::TemplateTail("".to_string()));
+    verify(Token::TemplateTail {
+        cooked: Some("".to_string()),
+        raw: "".to_string(),
+    });
+}
+
+#[test]
+fn test_Lexer_parse_identifier_escape() {
+    let mut src = reader::InlineSourceReader::new(r#"\u{61}bc a\u0062c "\u{61}""#);
+    let mut lexer = Lexer::new(&mut src);
+
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::IdentifierName("abc".to_string()));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::IdentifierName("abc".to_string()));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Str("a".to_string()));
+}
+
+#[test]
+fn test_Lexer_disallow_identifier_escape() {
+    let options = LexerOptions {
+        allow_identifier_escapes: false,
+        ..LexerOptions::default()
+    };
+
+    let mut src = reader::InlineSourceReader::new(r#"\u{61}bc"#);
+    let mut lexer = Lexer::with_options(&mut src, options.clone());
+    assert!(lexer.next_token().is_err());
+
+    let mut src = reader::InlineSourceReader::new(r#"a\u0062c"#);
+    let mut lexer = Lexer::with_options(&mut src, options.clone());
+    assert!(lexer.next_token().is_err());
+
+    let mut src = reader::InlineSourceReader::new(r#"abc "\u{61}""#);
+    let mut lexer = Lexer::with_options(&mut src, options);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::IdentifierName("abc".to_string()));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Str("a".to_string()));
+}
+
+#[test]
+fn test_Lexer_strict() {
+    let options = LexerOptions {
+        strict: true,
+        ..LexerOptions::default()
+    };
+
+    for source in ["010", "08", "00", r#""\07""#, r#""\1""#, r#""\00""#] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let mut lexer = Lexer::with_options(&mut src, options.clone());
+        assert!(lexer.next_token().is_err(), "{}", source);
+
+        // 非严格模式下仍然允许
+        let mut src = reader::InlineSourceReader::new(source);
+        let mut lexer = Lexer::new(&mut src);
+        assert!(lexer.next_token().is_ok(), "{}", source);
+    }
+
+    let mut src = reader::InlineSourceReader::new(r#""\8""#);
+    let mut lexer = Lexer::with_options(&mut src, options.clone());
+    assert!(lexer.next_token().is_err());
+
+    let mut src = reader::InlineSourceReader::new(r#"0 0.5 0n 0o10 10 "\0" "\x41""#);
+    let mut lexer = Lexer::with_options(&mut src, options.clone());
+    for exp in ["0", "0.5", "0n", "0o10", "10"] {
+        lexer.next_token().unwrap();
+        assert_eq!(lexer.current(), &Token::Number(exp.to_string()));
+    }
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Str("\0".to_string()));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Str("A".to_string()));
+
+    // template 中的八进制转义序列使 cooked 为 None
+    let mut src = reader::InlineSourceReader::new(r#"`\1`"#);
+    let mut lexer = Lexer::with_options(&mut src, options);
+    lexer.next_token().unwrap();
+    assert_eq!(
+        lexer.current(),
+        &Token::NoSubstitutionTemplate {
+            cooked: None,
+            raw: r#"\1"#.to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_Lexer_combined_options() {
+    let mut src = reader::InlineSourceReader::new("a /* c */ 08\nb");
+    let mut lexer = Lexer::with_options(
+        &mut src,
+        LexerOptions {
+            strict: true,
+            skip_comments: true,
+            preserve_trivia: true,
+            recover: true,
+            ..LexerOptions::default()
+        },
+    );
+
+    let mut tokens = Vec::new();
+    loop {
+        lexer.next_token().unwrap();
+        if lexer.is_eof() {
+            break;
+        }
+        tokens.push(lexer.current().clone());
+    }
+    assert_eq!(tokens.len(), 6);
+    assert_eq!(tokens[0], Token::IdentifierName("a".to_string()));
+    assert_eq!(tokens[1], Token::Whitespace(" ".to_string()));
+    assert_eq!(tokens[2], Token::Whitespace(" ".to_string()));
+    assert!(matches!(tokens[3], Token::Error(_)));
+    assert_eq!(tokens[4], Token::Newline("\n".to_string()));
+    assert_eq!(tokens[5], Token::IdentifierName("b".to_string()));
+}
+
+#[test]
+fn test_Lexer_max_operations() {
+    let source = "a + b;\n".repeat(1000);
+    let options = LexerOptions {
+        max_operations: Some(64),
+        ..LexerOptions::default()
+    };
+    let mut src = reader::InlineSourceReader::new(&source);
+    let mut lexer = Lexer::with_options(&mut src, options);
+
+    let err = loop {
+        match lexer.next_token() {
+            Ok(()) if matches!(lexer.current(), Token::EOF) => panic!("budget not exceeded"),
+            Ok(()) => continue,
+            Err(err) => break err,
+        }
+    };
+    assert_eq!(err.kind(), LexerErrorKind::BudgetExceeded);
+
+    let mut src = reader::InlineSourceReader::new("a + b;");
+    let mut lexer = Lexer::with_options(
+        &mut src,
+        LexerOptions {
+            max_operations: Some(64),
+            ..LexerOptions::default()
+        },
+    );
+    loop {
+        lexer.next_token().unwrap();
+        if matches!(lexer.current(), Token::EOF) {
+            break;
+        }
+    }
+}
+
+#[test]
+fn test_Lexer_max_operations_single_token() {
+    let body = "x".repeat(10000);
+    for source in [
+        format!("/*{}*/", body),
+        format!("//{}", body),
+        format!("\"{}\"", body),
+        format!("`{}`", body),
+        format!("/{}/", body),
+        format!("a{}", body),
+        format!("1{}", "0".repeat(10000)),
+    ] {
+        let mut src = reader::InlineSourceReader::new(&source);
+        let mut lexer = Lexer::with_options(
+            &mut src,
+            LexerOptions {
+                max_operations: Some(64),
+                ..LexerOptions::default()
+            },
+        );
+
+        let err = lexer.next_token().unwrap_err();
+        assert_eq!(
+            err.kind(),
+            LexerErrorKind::BudgetExceeded,
+            "{}",
+            &source[..4]
+        );
+        // 超出预算后立即中止，不会扫描完整个 Token
+        assert!(lexer.position().1 < 100, "{}", &source[..4]);
+    }
+}
+
+#[test]
+fn test_Lexer_tokenbuf_capacity() {
+    let mut src = reader::InlineSourceReader::new("a");
+    let lexer = Lexer::with_options(
+        &mut src,
+        LexerOptions {
+            tokenbuf_capacity: 256,
+            ..LexerOptions::default()
+        },
+    );
+    assert!(lexer.tokenbuf_capacity() >= 256);
+
+    let mut src = reader::InlineSourceReader::new("a");
+    let lexer = Lexer::new(&mut src);
+    assert_eq!(lexer.tokenbuf_capacity(), 0);
+}
+
+#[test]
+fn test_Lexer_extra_identifier_chars() {
+    let options = LexerOptions {
+        extra_identifier_starts: vec!['@'],
+        extra_identifier_parts: vec!['-'],
+        ..LexerOptions::default()
+    };
+    let mut src = reader::InlineSourceReader::new("data-x @click a - b");
+    let mut lexer = Lexer::with_options(&mut src, options);
+
+    let mut tokens = Vec::new();
+    loop {
+        lexer.next_token().unwrap();
+        if matches!(lexer.current(), Token::EOF) {
+            break;
+        }
+        tokens.push(lexer.current().clone());
+    }
+    assert_eq!(
+        tokens,
+        vec![
+            Token::IdentifierName("data-x".to_string()),
+            Token::IdentifierName("@click".to_string()),
+            Token::IdentifierName("a".to_string()),
+            Token::Operator('-'),
+            Token::IdentifierName("b".to_string()),
+        ]
+    );
+
+    let mut src = reader::InlineSourceReader::new("data-x");
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::IdentifierName("data".to_string()));
+}
+
+#[test]
+fn test_Lexer_last_comment() {
+    let mut src =
+        reader::InlineSourceReader::new("/* @flow */\nlet a = 1;\n//# sourceMappingURL=a.js.map\n");
+    let mut lexer = Lexer::new(&mut src);
+    assert_eq!(lexer.last_comment(), None);
+
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.last_comment(), Some((CommentKind::Block, " @flow ")));
+
+    let mut source_map = None;
+    loop {
+        lexer.next_token().unwrap();
+        if let Token::LineComment(..) = lexer.current() {
+            if let Some((CommentKind::Line, text)) = lexer.last_comment() {
+                source_map = text.strip_prefix("# sourceMappingURL=").map(str::to_string);
+            }
+        }
+        if matches!(lexer.current(), Token::EOF) {
+            break;
+        }
+    }
+    assert_eq!(source_map.as_deref(), Some("a.js.map"));
+}
+
+#[test]
+fn test_Lexer_skip_comments() {
+    let options = LexerOptions {
+        skip_comments: true,
+        ..LexerOptions::default()
+    };
+
+    let mut src = reader::InlineSourceReader::new("a /* x */ b");
+    let tokens = Lexer::with_options(&mut src, options.clone())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::IdentifierName("a".to_string()),
+            Token::IdentifierName("b".to_string()),
+            Token::EOF,
+        ]
+    );
+
+    // 被跳过的注释不影响正则表达式与除法的判定
+    let mut src = reader::InlineSourceReader::new(
+        "#!/usr/bin/env node
+a // x
+/* y */ / b",
+    );
+    let mut lexer = Lexer::with_options(&mut src, options);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::IdentifierName("a".to_string()));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Operator('/'));
+    assert!(lexer.has_newline_before());
+    assert_eq!(lexer.last_comment(), Some((CommentKind::Block, " y ")));
+
+    let mut src = reader::InlineSourceReader::new("a /* x */ b");
+    let tokens = Lexer::new(&mut src).collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(tokens[1], Token::BlockComment(" x ".to_string()));
+}
+
+#[test]
+fn test_Lexer_preserve_trivia() {
+    let source =
+        "#!/usr/bin/env node\r\nlet a\t=  /re/g; // c\n\n  /* d */ a / 2 + `x${ a }y`\u{2028}";
+    let mut src = reader::InlineSourceReader::new(source);
+    let mut lexer = Lexer::with_options(
+        &mut src,
+        LexerOptions {
+            preserve_trivia: true,
+            ..LexerOptions::default()
+        },
+    );
+
+    let mut tokens = Vec::new();
+    let mut restored = String::new();
+    loop {
+        lexer.next_token().unwrap();
+        let span = lexer.span();
+        restored.push_str(&source[span.start.offset..span.end.offset]);
+        if let Token::Whitespace(text) | Token::Newline(text) = lexer.current() {
+            assert_eq!(text, &source[span.start.offset..span.end.offset]);
+        }
+        tokens.push(lexer.current().clone());
+        if matches!(lexer.current(), Token::EOF) {
+            break;
+        }
+    }
+    assert_eq!(restored, source);
+
+    assert_eq!(tokens[1], Token::Newline("\r\n".to_string()));
+    assert_eq!(tokens[3], Token::Whitespace(" ".to_string()));
+    assert_eq!(tokens[5], Token::Whitespace("\t".to_string()));
+    assert_eq!(tokens[7], Token::Whitespace("  ".to_string()));
+    assert_eq!(
+        tokens[8],
+        Token::Regex {
+            pattern: "re".to_string(),
+            flags: "g".to_string()
+        }
+    );
+    // 空白与注释之后的 `/` 仍按上一个有效 Token 判定为除法
+    assert!(tokens.contains(&Token::Operator('/')));
+    assert_eq!(
+        tokens[tokens.len() - 2],
+        Token::Newline("\u{2028}".to_string())
+    );
+}
+
+#[test]
+fn test_Lexer_no_substitution_template() {
+    let mut src = reader::InlineSourceReader::new(r#"`abc` "abc" `a\n` / 2 `${b}` / c"#);
+    let mut lexer = Lexer::new(&mut src);
+
+    lexer.next_token().unwrap();
+    assert_eq!(
+        lexer.current(),
+        &Token::NoSubstitutionTemplate {
+            cooked: Some("abc".to_string()),
+            raw: "abc".to_string(),
+        }
+    );
+    assert!(!matches!(lexer.current(), Token::Str(_)));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Str("abc".to_string()));
+
+    lexer.next_token().unwrap();
+    assert_eq!(
+        lexer.current(),
+        &Token::NoSubstitutionTemplate {
+            cooked: Some("a\n".to_string()),
+            raw: "a\\n".to_string(),
+        }
+    );
+    // template 之后的 `/` 与字符串之后一样作为除法运算符
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Operator('/'));
+    lexer.next_token().unwrap();
+
+    for _ in 0..3 {
+        lexer.next_token().unwrap();
+    }
+    assert!(matches!(lexer.current(), Token::TemplateTail { .. }));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Operator('/'));
+}
+
+#[test]
+fn test_Lexer_template_octal_escape() {
+    // 非严格模式下 template 中的八进制转义序列同样使 cooked 为 None
+    for raw in [r#"\1"#, r#"\7"#, r#"\8"#, r#"\9"#, r#"\01"#, r#"a\123b"#] {
+        let source = format!("`{}`", raw);
+        let mut src = reader::InlineSourceReader::new(&source);
+        let mut lexer = Lexer::new(&mut src);
+        lexer.next_token().unwrap();
+        assert_eq!(
+            lexer.current(),
+            &Token::NoSubstitutionTemplate {
+                cooked: None,
+                raw: raw.to_string(),
+            },
+            "{}",
+            source
+        );
+    }
+
+    let mut src = reader::InlineSourceReader::new(r#"`\0${a}\1`"#);
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(
+        lexer.current(),
+        &Token::TemplateHead {
+            cooked: Some("\0".to_string()),
+            raw: r#"\0"#.to_string(),
+        }
+    );
+    lexer.next_token().unwrap();
+    lexer.next_token().unwrap();
+    assert_eq!(
+        lexer.current(),
+        &Token::TemplateTail {
+            cooked: None,
+            raw: r#"\1"#.to_string(),
+        }
+    );
+
+    // 字符串中的八进制转义序列不受影响
+    let mut src = reader::InlineSourceReader::new(r#""\1""#);
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Str("\u{1}".to_string()));
+}
+
+#[test]
+fn test_Lexer_template_line_terminators() {
+    let mut src = reader::InlineSourceReader::new("`a\r\nb\rc\nd` `e${f}\r\n`");
+    let mut lexer = Lexer::new(&mut src);
+
+    lexer.next_token().unwrap();
+    assert_eq!(
+        lexer.current(),
+        &Token::NoSubstitutionTemplate {
+            cooked: Some("a\nb\nc\nd".to_string()),
+            raw: "a\nb\nc\nd".to_string(),
+        }
+    );
+
+    lexer.next_token().unwrap();
+    assert_eq!(
+        lexer.current(),
+        &Token::TemplateHead {
+            cooked: Some("e".to_string()),
+            raw: "e".to_string(),
+        }
+    );
+    lexer.next_token().unwrap();
+    lexer.next_token().unwrap();
+    assert_eq!(
+        lexer.current(),
+        &Token::TemplateTail {
+            cooked: Some("\n".to_string()),
+            raw: "\n".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_Lexer_iterator() {
+    let mut src = reader::InlineSourceReader::new("a + 1");
+    let lexer = Lexer::new(&mut src);
+    assert_eq!(
+        lexer.collect::<Result<Vec<_>, _>>().unwrap(),
+        vec![
+            Token::IdentifierName("a".to_string()),
+            Token::Operator('+'),
+            Token::Number("1".to_string()),
+            Token::EOF,
+        ]
+    );
+
+    let mut src = reader::InlineSourceReader::new("");
+    let mut lexer = Lexer::new(&mut src);
+    assert_eq!(lexer.next().unwrap().unwrap(), Token::EOF);
+    assert!(lexer.next().is_none());
+    assert!(lexer.next().is_none());
+
+    let mut src = reader::InlineSourceReader::new("a; /x/gg b");
+    let mut count = 0;
+    let mut failed = false;
+    for tok in Lexer::new(&mut src) {
+        count += 1;
+        failed = tok.is_err();
+    }
+    assert_eq!(count, 3);
+    assert!(failed);
+}
+
+#[test]
+fn test_LexerError_display() {
+    let mut src = reader::InlineSourceReader::new("let a;\nlet b = /x/gg;");
+    let err = Lexer::new(&mut src)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_err();
+
+    assert_eq!(err.line(), 2);
+    assert_eq!(err.kind(), LexerErrorKind::InvalidRegex);
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "invalid regular expression at line 2, column {}",
+            err.column()
+        )
+    );
+
+    let err: Box<dyn std::error::Error> = Box::new(err);
+    assert!(err.to_string().starts_with("invalid regular expression"));
+}
+
+#[test]
+fn test_LexerError_kind() {
+    let verify = |source: &str, kind: LexerErrorKind| {
+        let mut src = reader::InlineSourceReader::new(source);
+        let err = Lexer::new(&mut src)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(err.kind(), kind, "{}", source);
+    };
+
+    verify("'abc", LexerErrorKind::UnterminatedString);
+    verify("a /* b", LexerErrorKind::UnterminatedComment);
+    verify("a = /b", LexerErrorKind::UnterminatedRegex);
+    verify("a = /b\n/", LexerErrorKind::UnterminatedRegex);
+    verify("a = /b/gg", LexerErrorKind::InvalidRegex);
+    verify("'\\x0g'", LexerErrorKind::InvalidEscape);
+    verify("'\\u{110000}'", LexerErrorKind::InvalidUnicodeCodePoint);
+    verify("'\\uD800'", LexerErrorKind::InvalidUnicodeCodePoint);
+    verify("3in", LexerErrorKind::InvalidNumericLiteral);
+    verify("0x", LexerErrorKind::InvalidNumericLiteral);
+    verify("1_", LexerErrorKind::InvalidNumericLiteral);
+}
+
+#[test]
+fn test_Lexer_exponent_digits() {
+    for source in ["1e", "1e+", "1e-", "1.5E", ".5e+", "1e+;"] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let err = Lexer::new(&mut src)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            LexerErrorKind::InvalidNumericLiteral,
+            "{}",
+            source
+        );
+    }
+
+    for source in ["1e5", "1e+5", "1e-5", "1.5E1_0", ".5e+0"] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let mut lexer = Lexer::new(&mut src);
+        lexer.next_token().unwrap();
+        assert_eq!(lexer.current(), &Token::Number(source.to_string()));
+    }
+}
+
+#[test]
+fn test_Lexer_number_followed_by_identifier() {
+    for source in [
+        "3in", "123abc", "1$", "1.5e3x", "0x1fg", "0b12", "0o78", "1n2", "3\\u0061",
+    ] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let err = Lexer::new(&mut src)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            LexerErrorKind::InvalidNumericLiteral,
+            "{}",
+            source
+        );
+    }
+
+    let tokenize = |source: &str| {
+        let mut src = reader::InlineSourceReader::new(source);
+        Lexer::new(&mut src).collect::<Result<Vec<_>, _>>().unwrap()
+    };
+    assert_eq!(
+        tokenize("3 .toString"),
+        vec![
+            Token::Number("3".to_string()),
+            Token::Operator('.'),
+            Token::IdentifierName("toString".to_string()),
+            Token::EOF,
+        ]
+    );
+    assert_eq!(
+        tokenize("3..toString"),
+        vec![
+            Token::Number("3.".to_string()),
+            Token::Operator('.'),
+            Token::IdentifierName("toString".to_string()),
+            Token::EOF,
+        ]
+    );
+    assert_eq!(
+        tokenize("3n+1"),
+        vec![
+            Token::Number("3n".to_string()),
+            Token::Operator('+'),
+            Token::Number("1".to_string()),
+            Token::EOF,
+        ]
+    );
+}
+
+#[test]
+fn test_Lexer_from_source() {
+    // 词法分析器只借用源码，可以在构建后直接返回
+    fn lex(source: &str) -> Lexer<'_> {
+        Lexer::from_source(source)
+    }
+
+    let tokens = lex("1 + 2").collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Number("1".to_string()),
+            Token::Operator('+'),
+            Token::Number("2".to_string()),
+            Token::EOF,
+        ]
+    );
+}
+
+#[test]
+fn test_Lexer_peek_token() {
+    let mut src = reader::InlineSourceReader::new("[1, 2]");
+    let mut lexer = Lexer::new(&mut src);
+
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Operator('['));
+    assert_eq!(lexer.peek_token().unwrap(), &Token::Number("1".to_string()));
+    assert_eq!(lexer.peek_token().unwrap(), &Token::Number("1".to_string()));
+    assert_eq!(lexer.current(), &Token::Operator('['));
+    assert_eq!(
+        lexer.span(),
+        Span::new(Position::new(0, 1, 1), Position::new(1, 1, 2))
+    );
+
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Number("1".to_string()));
+    assert_eq!(
+        lexer.span(),
+        Span::new(Position::new(1, 1, 2), Position::new(2, 1, 3))
+    );
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Operator(','));
+    assert_eq!(lexer.peek_token().unwrap(), &Token::Number("2".to_string()));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.peek_token().unwrap(), &Token::Operator(']'));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Operator(']'));
+    assert_eq!(lexer.peek_token().unwrap(), &Token::EOF);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::EOF);
+
+    // 预读时仍以当前 Token 判定 `/` 是除法还是正则表达式
+    let mut src = reader::InlineSourceReader::new("a / b\n(/b/g)");
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.peek_token().unwrap(), &Token::Operator('/'));
+    lexer.next_token().unwrap();
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.peek_token().unwrap(), &Token::Operator('('));
+    assert!(!lexer.has_newline_before());
+    lexer.next_token().unwrap();
+    assert!(lexer.has_newline_before());
+    assert_eq!(
+        lexer.peek_token().unwrap(),
+        &Token::Regex {
+            pattern: "b".to_string(),
+            flags: "g".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_Lexer_position() {
+    let mut src = reader::InlineSourceReader::new("ab\n\n  cd\r\ne");
+    let mut lexer = Lexer::new(&mut src);
+    assert_eq!(lexer.position(), (1, 1));
+
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.position(), (1, 3));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.position(), (3, 5));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.position(), (4, 2));
+}
+
+#[test]
+fn test_Lexer_is_eof() {
+    let mut src = reader::InlineSourceReader::new("a // b\n");
+    let mut lexer = Lexer::new(&mut src);
+
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::IdentifierName("a".to_string()));
+    assert!(!lexer.is_eof());
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::LineComment(" b".to_string()));
+    assert!(!lexer.is_eof());
+
+    lexer.next_token().unwrap();
+    assert!(lexer.is_eof());
+    let span = lexer.span();
+    for _ in 0..4 {
+        lexer.next_token().unwrap();
+        assert_eq!(lexer.current(), &Token::EOF);
+        assert!(lexer.is_eof());
+        assert_eq!(lexer.span(), span);
+    }
+    assert_eq!(lexer.peek_token().unwrap(), &Token::EOF);
+}
+
+#[test]
+fn test_Lexer_line_starts() {
+    let source = "let a;\r\n\n/* x\u{2028}y */ `b\nc`\rd";
+    let mut src = reader::InlineSourceReader::new(source);
+    let mut lexer = Lexer::new(&mut src);
+    assert_eq!(lexer.line_starts(), &[0]);
+
+    loop {
+        lexer.next_token().unwrap();
+        if lexer.is_eof() {
+            break;
+        }
+    }
+    assert_eq!(lexer.line_starts(), &[0, 8, 9, 16, 24, 27]);
+
+    assert_eq!(lexer.offset_to_position(0), (1, 1));
+    assert_eq!(lexer.offset_to_position(4), (1, 5));
+    assert_eq!(lexer.offset_to_position(6), (1, 7));
+    assert_eq!(lexer.offset_to_position(8), (2, 1));
+    assert_eq!(lexer.offset_to_position(9), (3, 1));
+    assert_eq!(lexer.offset_to_position(16), (4, 1));
+    assert_eq!(lexer.offset_to_position(22), (4, 7));
+    assert_eq!(lexer.offset_to_position(27), (6, 1));
+    assert_eq!(lexer.offset_to_position(source.len()), (6, 2));
+}
+
+#[test]
+fn test_Lexer_offset_to_position_non_ascii() {
+    let source = "\u{FEFF}名字 = 'é';\nlet 𝒳 = 名字";
+    let mut src = reader::InlineSourceReader::new(source);
+    let mut lexer = Lexer::new(&mut src);
+
+    let mut spans = Vec::new();
+    loop {
+        lexer.next_token().unwrap();
+        if lexer.is_eof() {
+            break;
+        }
+        spans.push(lexer.span());
+    }
+
+    // 列号按字符计算，与 Token 的 Position 一致
+    for span in spans {
+        for pos in [span.start, span.end] {
+            assert_eq!(
+                lexer.offset_to_position(pos.offset),
+                (pos.line, pos.column),
+                "{:?}",
+                pos
+            );
+        }
+    }
+    assert_eq!(lexer.offset_to_position(source.find('=').unwrap()), (1, 4));
+    assert_eq!(lexer.offset_to_position(source.rfind('=').unwrap()), (2, 7));
+    assert_eq!(lexer.offset_to_position(source.len()), (2, 11));
+}
+
+#[test]
+fn test_Lexer_span() {
+    let mut src = reader::InlineSourceReader::new("a /* x\r\ny */ `b\n${c}\u{2028}d`\r\n  名字");
+    let mut lexer = Lexer::new(&mut src);
+
+    let mut verify = |start: (usize, usize, usize), end: (usize, usize, usize)| {
+        lexer.next_token().unwrap();
+        assert_eq!(
+            lexer.span(),
+            Span::new(
+                Position::new(start.0, start.1, start.2),
+                Position::new(end.0, end.1, end.2)
+            )
+        );
+    };
+
+    verify((0, 1, 1), (1, 1, 2)); // a
+    verify((2, 1, 3), (12, 2, 5)); // /* x\r\ny */
+    verify((13, 2, 6), (18, 3, 3)); // `b\n${
+    verify((18, 3, 3), (19, 3, 4)); // c
+    verify((19, 3, 4), (25, 4, 3)); // }\u{2028}d`
+    verify((29, 5, 3), (35, 5, 5)); // 名字
+}
+
+#[test]
+fn test_Lexer_recover() {
+    let source = "a; /x/gg b\n\\u{zz} c /* d";
+    let options = LexerOptions {
+        recover: true,
+        ..LexerOptions::default()
+    };
+    let mut src = reader::InlineSourceReader::new(source);
+    let tokens = Lexer::with_options(&mut src, options)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    let error = |start: (usize, usize, usize), end: (usize, usize, usize)| {
+        Token::Error(Span::new(
+            Position::new(start.0, start.1, start.2),
+            Position::new(end.0, end.1, end.2),
+        ))
+    };
+    assert_eq!(
+        tokens,
+        vec![
+            Token::IdentifierName("a".to_string()),
+            Token::Operator(';'),
+            error((3, 1, 4), (8, 1, 9)), // /x/gg
+            Token::IdentifierName("b".to_string()),
+            error((11, 2, 1), (14, 2, 4)), // \u{
+            Token::IdentifierName("zz".to_string()),
+            Token::Operator('}'),
+            Token::IdentifierName("c".to_string()),
+            error((20, 2, 10), (24, 2, 14)), // /* d
+            Token::EOF,
+        ]
+    );
+
+    let mut src = reader::InlineSourceReader::new(source);
+    assert!(Lexer::new(&mut src).collect::<Result<Vec<_>, _>>().is_err());
+
+    let mut src = reader::InlineSourceReader::new("`a${b");
+    let tokens = Lexer::with_options(
+        &mut src,
+        LexerOptions {
+            recover: true,
+            ..LexerOptions::default()
+        },
+    )
+    .collect::<Result<Vec<_>, _>>()
+    .unwrap();
+    assert_eq!(tokens.len(), 4);
+    assert!(matches!(tokens[2], Token::Error(..)));
+    assert_eq!(tokens[3], Token::EOF);
+}
+
+#[test]
+fn test_Lexer_unterminated_string() {
+    for source in ["\"abc", "'abc", "\"", "\"abc\\", "\"abc\\\""] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let mut lexer = Lexer::new(&mut src);
+        let err = lexer.next_token().unwrap_err();
+        assert_eq!(err.kind(), LexerErrorKind::UnterminatedString, "{}", source);
+    }
+}
+
+#[test]
+fn test_Lexer_unterminated_template() {
+    for source in ["`hello", "`a${b", "`a${b}c", "`a${ {b} ", "`hello\\"] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let err = Lexer::new(&mut src)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            LexerErrorKind::UnterminatedTemplate,
+            "{}",
+            source
+        );
+    }
+}
+
+#[test]
+fn test_Lexer_template_raw() {
+    let mut src = reader::InlineSourceReader::new(r"`a\n\u0041${b}\x${c}\u{110000}\``");
+    let lexer = Lexer::new(&mut src);
+    let tokens = lexer.collect::<Result<Vec<_>, _>>().unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            Token::TemplateHead {
+                cooked: Some("a\nA".to_string()),
+                raw: r"a\n\u0041".to_string(),
+            },
+            Token::IdentifierName("b".to_string()),
+            Token::TemplateMiddle {
+                cooked: None,
+                raw: r"\x".to_string(),
+            },
+            Token::IdentifierName("c".to_string()),
+            Token::TemplateTail {
+                cooked: None,
+                raw: r"\u{110000}\`".to_string(),
+            },
+            Token::EOF,
+        ]
+    );
+
+    let mut src = reader::InlineSourceReader::new("`\\`\r\n`");
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(
+        lexer.current(),
+        &Token::NoSubstitutionTemplate {
+            cooked: Some("`\n".to_string()),
+            raw: "\\`\n".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_Lexer_surrogate_pair_escape() {
+    let mut src = reader::InlineSourceReader::new(r#""\uD83D\uDE00" '\uD83D\uDE00!'"#);
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Str("\u{1F600}".to_string()));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Str("\u{1F600}!".to_string()));
+
+    for source in [
+        r#""\uD83D""#,
+        r#""\uD83Dx""#,
+        r#""\uD83D\u0041""#,
+        r#""\uDE00""#,
+    ] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let err = Lexer::new(&mut src).next_token().unwrap_err();
+        assert_eq!(
+            err.kind(),
+            LexerErrorKind::InvalidUnicodeCodePoint,
+            "{}",
+            source
+        );
+    }
+}
+
+#[test]
+fn test_Lexer_code_point_escape() {
+    let mut src = reader::InlineSourceReader::new(r#""\u{1F600}" "\u{10FFFF}""#);
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Str("\u{1F600}".to_string()));
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::Str("\u{10FFFF}".to_string()));
+
+    for source in [
+        r#""\u{D800}""#,
+        r#""\u{DFFF}""#,
+        r#""\u{D83D}\u{DE00}""#,
+        r#""\u{110000}""#,
+    ] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let err = Lexer::new(&mut src).next_token().unwrap_err();
+        assert_eq!(
+            err.kind(),
+            LexerErrorKind::InvalidUnicodeCodePoint,
+            "{}",
+            source
+        );
+    }
+}
+
+#[test]
+fn test_Lexer_parse_number_prefix() {
+    for source in ["0x", "0X", "0b", "0B", "0o", "0O", "0x;", "0bn", "0o8"] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let err = Lexer::new(&mut src).next_token().unwrap_err();
+        assert_eq!(
+            err.kind(),
+            LexerErrorKind::InvalidNumericLiteral,
+            "{}",
+            source
+        );
+    }
+
+    for source in ["0x0", "0b1", "0o7", "0xfn"] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let mut lexer = Lexer::new(&mut src);
+        lexer.next_token().unwrap();
+        assert_eq!(lexer.current(), &Token::Number(source.to_string()));
+    }
+}
+
+#[test]
+fn test_Lexer_bigint_leading_zero() {
+    // `0` 之后紧跟数字时不能带有 `n` 后缀
+    for source in ["0123n", "08n", "00n", "019n"] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let err = Lexer::new(&mut src).next_token().unwrap_err();
+        assert_eq!(
+            err.kind(),
+            LexerErrorKind::InvalidNumericLiteral,
+            "{}",
+            source
+        );
+    }
+
+    for source in ["0n", "10n", "0o17n", "0x0n"] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let mut lexer = Lexer::new(&mut src);
+        lexer.next_token().unwrap();
+        assert_eq!(lexer.current(), &Token::Number(source.to_string()));
+    }
+}
+
+#[test]
+fn test_Lexer_numeric_separator() {
+    for source in [
+        "100_", "1__0", "0x_1", "0b_1", "0o_1", "1_.5", "1._5", "1.5_", "1e_5", "1e+_5", "1_e5",
+        "1_n", "0_1", "07_1",
+    ] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let err = Lexer::new(&mut src).next_token().unwrap_err();
+        assert_eq!(
+            err.kind(),
+            LexerErrorKind::InvalidNumericLiteral,
+            "{}",
+            source
+        );
+    }
+
+    for source in [
+        "1_000", "0xe_f", "0b1_0", "0o7_7", "1.5_5", "1e1_0", ".5_5", "1_0n",
+    ] {
+        let mut src = reader::InlineSourceReader::new(source);
+        let mut lexer = Lexer::new(&mut src);
+        lexer.next_token().unwrap();
+        assert_eq!(lexer.current(), &Token::Number(source.to_string()));
+    }
+
+    // 以 `_` 开头的是标识符而非数字
+    let mut src = reader::InlineSourceReader::new("_100");
+    let mut lexer = Lexer::new(&mut src);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::IdentifierName("_100".to_string()));
 }
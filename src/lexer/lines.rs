@@ -0,0 +1,35 @@
+use super::code_points;
+
+/// 按 LineTerminatorSequence 将源码拆分为逻辑行
+///
+/// 换行符判定与词法分析器一致：`<LF>`、`<CR>`、`<CR><LF>`、`<LS>`、`<PS>`
+/// 均视为一个换行，因此返回结果的下标加一即为词法分析器中的行号。
+/// 返回的各行不包含换行符
+///
+/// # Arguments
+/// `source` - EMCAScript 源码
+/// # Returns
+/// 返回各行的内容，源码以换行结尾时最后一行为空字符串
+pub(crate) fn split_lines(source: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+
+    let mut chars = source.char_indices().peekable();
+    while let Some((idx, chr)) = chars.next() {
+        if !code_points::is_line_terminator(chr) {
+            continue;
+        }
+        lines.push(&source[start..idx]);
+
+        start = idx + chr.len_utf8();
+        if chr == code_points::CR {
+            if let Some(&(next, code_points::LF)) = chars.peek() {
+                chars.next();
+                start = next + code_points::LF.len_utf8();
+            }
+        }
+    }
+    lines.push(&source[start..]);
+
+    lines
+}
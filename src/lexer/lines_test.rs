@@ -0,0 +1,12 @@
+use super::lines::split_lines;
+
+#[test]
+fn test_split_lines() {
+    assert_eq!(
+        split_lines("a\r\nb\rc\u{2028}d\ne\u{2029}f"),
+        vec!["a", "b", "c", "d", "e", "f"]
+    );
+    assert_eq!(split_lines("a\r\r\nb\n"), vec!["a", "", "b", ""]);
+    assert_eq!(split_lines(""), vec![""]);
+    assert_eq!(split_lines("名字\u{2028}"), vec!["名字", ""]);
+}
@@ -1,14 +1,36 @@
 mod code_points;
+#[allow(clippy::module_inception)]
 mod lexer;
 mod lexer_error;
+mod lexer_options;
+mod lines;
 mod reader;
+mod span;
+mod style;
 mod token;
+mod tokenize;
 
 pub(crate) use lexer::Lexer;
-pub(crate) use lexer_error::LexerError;
+pub use lexer_error::{LexerError, LexerErrorKind};
+pub(crate) use lexer_options::LexerOptions;
 pub(crate) use reader::{InlineSourceReader, SourceReader};
-pub(crate) use token::Token;
+pub use span::{Position, Span};
+pub use token::Token;
+pub(crate) use tokenize::tokenize;
 
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod lexer_test;
+
+#[cfg(test)]
+mod lines_test;
+
+#[cfg(test)]
+mod style_test;
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod token_test;
+
+#[cfg(test)]
+mod tokenize_test;
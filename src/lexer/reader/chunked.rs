@@ -0,0 +1,77 @@
+use std::str::Chars;
+
+use super::{reader, stream::StreamCursor};
+
+/// 读取分块存储的 EMCAScript 源码
+///
+/// 编辑器通常以 rope 等分块结构存储文档，该 SourceReader 直接按块读取源码，
+/// 无需将整个文档拷贝为连续的字符串。块与块之间的边界对词法分析器透明
+pub(crate) struct ChunkedSourceReader<'s, I>
+where
+    I: Iterator<Item = &'s str>,
+{
+    chunks: I,
+    chunk_chars: Chars<'s>,
+    consumed: usize,
+
+    cursor: StreamCursor,
+}
+
+impl<'s, I> ChunkedSourceReader<'s, I>
+where
+    I: Iterator<Item = &'s str>,
+{
+    /// 构造一个读取分块源码的 SourceReader
+    ///
+    /// # Arguments
+    /// `chunks` - 按顺序产生源码各个块的迭代器
+    /// # Returns
+    /// SourceReader 的一个实现
+    pub(crate) fn new(chunks: I) -> Self {
+        ChunkedSourceReader {
+            chunks,
+            chunk_chars: "".chars(),
+            consumed: 0,
+
+            cursor: StreamCursor::default(),
+        }
+    }
+
+    /// 读取下一个字符，当前块读取完毕时切换到下一个非空块
+    fn next_char(&mut self) -> Option<char> {
+        loop {
+            if let Some(chr) = self.chunk_chars.next() {
+                self.consumed += chr.len_utf8();
+                return Some(chr);
+            }
+            self.chunk_chars = self.chunks.next()?.chars();
+        }
+    }
+}
+
+impl<'s, I> reader::SourceReader for ChunkedSourceReader<'s, I>
+where
+    I: Iterator<Item = &'s str>,
+{
+    #[inline(always)]
+    fn next(&mut self, off: isize) {
+        let mut cursor = self.cursor;
+        cursor.next(off, || self.next_char());
+        self.cursor = cursor;
+    }
+
+    #[inline(always)]
+    fn current(&self) -> Option<char> {
+        self.cursor.current()
+    }
+
+    #[inline(always)]
+    fn lookahead(&self) -> Option<char> {
+        self.cursor.lookahead()
+    }
+
+    #[inline(always)]
+    fn offset(&self) -> usize {
+        self.cursor.offset(self.consumed)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::lexer::{tokenize, Lexer, Token};
+
+use super::chunked::ChunkedSourceReader;
+
+#[test]
+fn test_ChunkedSourceReader_tokenize() {
+    let source = "let 名字 = \"你好\" + `a${b}c`; // 注释\nfoo.bar(1.5e3)";
+    let expected = tokenize(source).unwrap();
+
+    let boundaries = source
+        .char_indices()
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+    for step in [1, 2, 3, 5, 7] {
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        for end in boundaries.iter().copied().step_by(step).skip(1) {
+            chunks.push(&source[start..end]);
+            start = end;
+        }
+        chunks.push(&source[start..]);
+        chunks.insert(1, "");
+
+        let mut reader = ChunkedSourceReader::new(chunks.into_iter());
+        let mut lexer = Lexer::new(&mut reader);
+        let mut tokens = Vec::new();
+        loop {
+            lexer.next_token().unwrap();
+            tokens.push(lexer.current().clone());
+            if matches!(lexer.current(), Token::EOF) {
+                break;
+            }
+        }
+
+        assert_eq!(tokens, expected, "step {}", step);
+    }
+}
@@ -0,0 +1,97 @@
+use std::{fs, io, path::Path};
+
+use super::{inline::ByteCursor, reader};
+
+/// 读取文件中的 EMCAScript 源码
+///
+/// 根据文件开头的 BOM 检测编码：UTF-8 BOM 会被跳过，UTF-16 LE/BE 编码的内容
+/// 会被转码为 UTF-8；不带 BOM 的文件按 UTF-8 读取。
+/// 解码后的源码完整保存在内存中，因此与 InlineSourceReader 一样支持向前与向后移动
+pub(crate) struct FileSourceReader {
+    source: String,
+    cursor: ByteCursor,
+}
+
+impl FileSourceReader {
+    /// 打开文件并构造一个读取其中 EMCAScript 源码的 SourceReader
+    ///
+    /// # Arguments
+    /// `path` - 文件路径
+    /// # Returns
+    /// SourceReader 的一个实现，文件读取失败或编码不合法时返回报错
+    pub(crate) fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_bytes(fs::read(path)?)
+    }
+
+    /// 从文件内容构造一个 SourceReader
+    ///
+    /// # Arguments
+    /// `bytes` - 文件的原始内容
+    /// # Returns
+    /// SourceReader 的一个实现，编码不合法时返回 `io::ErrorKind::InvalidData` 报错
+    pub(crate) fn from_bytes(bytes: Vec<u8>) -> io::Result<Self> {
+        Ok(FileSourceReader {
+            source: decode(bytes)?,
+            cursor: ByteCursor::default(),
+        })
+    }
+}
+
+/// 根据 BOM 将文件内容解码为 UTF-8 字符串
+fn decode(bytes: Vec<u8>) -> io::Result<String> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+
+    match bytes.as_slice() {
+        [0xef, 0xbb, 0xbf, ..] => {
+            String::from_utf8(bytes[3..].to_vec()).map_err(|_| invalid("invalid UTF-8 source"))
+        }
+        [0xff, 0xfe, rest @ ..] => {
+            decode_utf16(rest, u16::from_le_bytes).ok_or_else(|| invalid("invalid UTF-16LE source"))
+        }
+        [0xfe, 0xff, rest @ ..] => {
+            decode_utf16(rest, u16::from_be_bytes).ok_or_else(|| invalid("invalid UTF-16BE source"))
+        }
+        _ => String::from_utf8(bytes).map_err(|_| invalid("invalid UTF-8 source")),
+    }
+}
+
+/// 将 UTF-16 编码的内容转码为 UTF-8 字符串
+///
+/// # Arguments
+/// `bytes` - 去除 BOM 后的内容
+/// `to_unit` - 将两个字节组合为一个 UTF-16 码元
+/// # Returns
+/// 返回转码后的字符串，字节数为奇数或存在孤立的代理项时返回 None
+fn decode_utf16(bytes: &[u8], to_unit: fn([u8; 2]) -> u16) -> Option<String> {
+    let pairs = bytes.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+    let units = pairs.map(|pair| to_unit([pair[0], pair[1]]));
+
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .ok()
+}
+
+impl reader::SourceReader for FileSourceReader {
+    #[inline(always)]
+    fn next(&mut self, off: isize) {
+        self.cursor.next(&self.source, off);
+    }
+
+    #[inline(always)]
+    fn current(&self) -> Option<char> {
+        self.cursor.current()
+    }
+
+    #[inline(always)]
+    fn lookahead(&self) -> Option<char> {
+        self.cursor.lookahead()
+    }
+
+    #[inline(always)]
+    fn offset(&self) -> usize {
+        self.cursor.offset()
+    }
+}
@@ -0,0 +1,98 @@
+use std::io;
+
+use crate::lexer::{tokenize, Lexer, Token};
+
+use super::{file::FileSourceReader, reader::SourceReader};
+
+fn lex(reader: &mut FileSourceReader) -> Vec<Token> {
+    let mut lexer = Lexer::new(reader);
+    let mut tokens = Vec::new();
+    loop {
+        lexer.next_token().unwrap();
+        tokens.push(lexer.current().clone());
+        if matches!(lexer.current(), Token::EOF) {
+            return tokens;
+        }
+    }
+}
+
+#[test]
+fn test_FileSourceReader_encoding() {
+    let source = "let 名字 = \"😀\";";
+    let expected = tokenize(source).unwrap();
+
+    let mut utf16le = vec![0xff, 0xfe];
+    utf16le.extend(source.encode_utf16().flat_map(u16::to_le_bytes));
+    let mut reader = FileSourceReader::from_bytes(utf16le).unwrap();
+    assert_eq!(lex(&mut reader), expected);
+
+    let mut utf16be = vec![0xfe, 0xff];
+    utf16be.extend(source.encode_utf16().flat_map(u16::to_be_bytes));
+    let mut reader = FileSourceReader::from_bytes(utf16be).unwrap();
+    assert_eq!(lex(&mut reader), expected);
+
+    let mut utf8 = vec![0xef, 0xbb, 0xbf];
+    utf8.extend(source.bytes());
+    let mut reader = FileSourceReader::from_bytes(utf8).unwrap();
+    assert_eq!(lex(&mut reader), expected);
+
+    let err = FileSourceReader::from_bytes(vec![0xff, 0xfe, 0x00, 0xd8])
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let err = FileSourceReader::from_bytes(vec![0xff, 0xfe, 0x61])
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let err = FileSourceReader::from_bytes(vec![0x61, 0xff])
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_FileSourceReader_open() {
+    let path = std::env::temp_dir().join(format!("qjs-rs-file-test-{}.js", std::process::id()));
+    std::fs::write(&path, "a + 1").unwrap();
+
+    let mut reader = FileSourceReader::open(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(lex(&mut reader), tokenize("a + 1").unwrap());
+
+    assert!(FileSourceReader::open(&path).is_err());
+
+    let path = std::env::temp_dir().join(format!(
+        "qjs-rs-file-test-invalid-{}.js",
+        std::process::id()
+    ));
+    std::fs::write(&path, b"let a = \"\xc3\";").unwrap();
+    let err = FileSourceReader::open(&path).err().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_FileSourceReader_seek_backward() {
+    let mut reader = FileSourceReader::from_bytes("a张三b".as_bytes().to_vec()).unwrap();
+    reader.next(3);
+    assert_eq!(reader.current(), Some('三'));
+    assert_eq!(reader.lookahead(), Some('b'));
+    assert_eq!(reader.offset(), 4);
+
+    reader.next(-2);
+    assert_eq!(reader.current(), Some('a'));
+    assert_eq!(reader.lookahead(), Some('张'));
+    assert_eq!(reader.offset(), 0);
+
+    reader.next(0);
+    assert_eq!(reader.current(), Some('a'));
+    assert_eq!(reader.lookahead(), Some('张'));
+
+    // 越过源码开头时回到尚未读取任何字符的状态
+    reader.next(-10);
+    assert_eq!(reader.current(), None);
+    assert_eq!(reader.lookahead(), Some('a'));
+    reader.next(4);
+    assert_eq!(reader.current(), Some('b'));
+    assert_eq!(reader.offset(), 7);
+}
@@ -1,15 +1,93 @@
-use std::str::Chars;
-
 use super::reader;
 
-/// 读取在 Rust 代码内写 EMCAScript 源码
-pub(crate) struct InlineSourceReader<'s> {
-    source_chars: Chars<'s>,
+/// 以字节偏移量记录在完整源码上的游标，支持向前与向后移动
+///
+/// 游标本身不持有源码，由持有完整源码的 InlineSourceReader 与 FileSourceReader 共用
+#[derive(Debug, Default)]
+pub(super) struct ByteCursor {
+    /// 当前字符的字节偏移量，None 表示游标位于源码开头之前（尚未读取任何字符）；
+    /// 等于源码长度时表示已到达源码末尾
+    cursor: Option<usize>,
 
     current_chr: Option<char>,
     lookahead_chr: Option<char>,
 }
 
+impl ByteCursor {
+    /// 移动游标，`off` 为负数时向前回退
+    ///
+    /// 回退越过源码开头时，游标停留在尚未读取任何字符的状态：
+    /// 此时 current 为 None，lookahead 为源码的第一个字符
+    ///
+    /// # Arguments
+    /// `source` - 游标所在的源码
+    /// `off` - 游标移动偏移量
+    pub(super) fn next(&mut self, source: &str, off: isize) {
+        for _ in 0..off.unsigned_abs() {
+            self.cursor = if off > 0 {
+                Self::forward(source, self.cursor)
+            } else {
+                Self::backward(source, self.cursor)
+            };
+        }
+
+        let lookahead = match self.cursor {
+            None => 0,
+            Some(offset) => offset + char_at(source, offset).map_or(0, char::len_utf8),
+        };
+        self.current_chr = self.cursor.and_then(|offset| char_at(source, offset));
+        self.lookahead_chr = char_at(source, lookahead);
+    }
+
+    /// 向后移动一个字符后的字节偏移量，到达源码末尾后不再移动
+    fn forward(source: &str, cursor: Option<usize>) -> Option<usize> {
+        match cursor {
+            None => Some(0),
+            Some(offset) => Some(offset + char_at(source, offset).map_or(0, char::len_utf8)),
+        }
+    }
+
+    /// 向前移动一个字符后的字节偏移量，越过源码开头时回到尚未读取任何字符的状态
+    fn backward(source: &str, cursor: Option<usize>) -> Option<usize> {
+        match cursor {
+            None | Some(0) => None,
+            Some(offset) => source[..offset]
+                .chars()
+                .next_back()
+                .map(|chr| offset - chr.len_utf8()),
+        }
+    }
+
+    #[inline(always)]
+    pub(super) fn current(&self) -> Option<char> {
+        self.current_chr
+    }
+
+    #[inline(always)]
+    pub(super) fn lookahead(&self) -> Option<char> {
+        self.lookahead_chr
+    }
+
+    #[inline(always)]
+    pub(super) fn offset(&self) -> usize {
+        self.cursor.unwrap_or(0)
+    }
+}
+
+/// 获取指定字节偏移量处的字符
+#[inline(always)]
+fn char_at(source: &str, offset: usize) -> Option<char> {
+    source[offset..].chars().next()
+}
+
+/// 读取在 Rust 代码内写 EMCAScript 源码
+///
+/// 游标以字节偏移量记录在原始源码上，因此支持向前与向后移动
+pub(crate) struct InlineSourceReader<'s> {
+    source: &'s str,
+    cursor: ByteCursor,
+}
+
 impl<'s> InlineSourceReader<'s> {
     /// 构造一个读取 Rust 代码内写 EMCAScript 源码的 SourceReader
     ///
@@ -19,10 +97,8 @@ impl<'s> InlineSourceReader<'s> {
     /// SourceReader 的一个实现
     pub(crate) fn new(source: &'s str) -> Self {
         InlineSourceReader {
-            source_chars: source.chars(),
-
-            current_chr: None,
-            lookahead_chr: None,
+            source,
+            cursor: ByteCursor::default(),
         }
     }
 }
@@ -30,25 +106,21 @@ impl<'s> InlineSourceReader<'s> {
 impl<'s> reader::SourceReader for InlineSourceReader<'s> {
     #[inline(always)]
     fn next(&mut self, off: isize) {
-        for _ in 0..off {
-            if self.lookahead_chr.is_some() {
-                self.current_chr = self.lookahead_chr;
-                self.lookahead_chr = None;
-                continue;
-            }
-            self.current_chr = self.source_chars.next();
-        }
-
-        self.lookahead_chr = self.source_chars.next();
+        self.cursor.next(self.source, off);
     }
 
     #[inline(always)]
     fn current(&self) -> Option<char> {
-        self.current_chr
+        self.cursor.current()
     }
 
     #[inline(always)]
     fn lookahead(&self) -> Option<char> {
-        self.lookahead_chr
+        self.cursor.lookahead()
+    }
+
+    #[inline(always)]
+    fn offset(&self) -> usize {
+        self.cursor.offset()
     }
 }
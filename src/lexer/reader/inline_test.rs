@@ -15,3 +15,47 @@ fn test_InlineSourceReader_next() {
     assert_eq!(reader.current(), Some('n'));
     assert_eq!(reader.lookahead(), Some('c'));
 }
+
+#[test]
+fn test_InlineSourceReader_seek_backward() {
+    let mut reader = inline::InlineSourceReader::new("a张三b");
+    reader.next(3);
+    assert_eq!(reader.current(), Some('三'));
+    assert_eq!(reader.lookahead(), Some('b'));
+
+    reader.next(-2);
+    assert_eq!(reader.current(), Some('a'));
+    assert_eq!(reader.lookahead(), Some('张'));
+
+    reader.next(3);
+    assert_eq!(reader.current(), Some('b'));
+    assert_eq!(reader.lookahead(), None);
+    reader.next(2);
+    assert_eq!(reader.current(), None);
+    reader.next(-1);
+    assert_eq!(reader.current(), Some('b'));
+
+    // 越过源码开头时回到尚未读取任何字符的状态
+    reader.next(-10);
+    assert_eq!(reader.current(), None);
+    assert_eq!(reader.lookahead(), Some('a'));
+    reader.next(1);
+    assert_eq!(reader.current(), Some('a'));
+}
+
+#[test]
+fn test_InlineSourceReader_offset() {
+    let mut reader = inline::InlineSourceReader::new("a张三b");
+    assert_eq!(reader.offset(), 0);
+
+    let mut offsets = Vec::new();
+    for _ in 0..5 {
+        reader.next(1);
+        offsets.push(reader.offset());
+    }
+    assert_eq!(offsets, vec![0, 1, 4, 7, 8]);
+
+    reader.next(-2);
+    assert_eq!(reader.current(), Some('三'));
+    assert_eq!(reader.offset(), 4);
+}
@@ -1,9 +1,30 @@
+mod chunked;
+mod file;
 mod inline;
+mod read;
+#[allow(clippy::module_inception)]
 mod reader;
+mod stream;
 
 pub(crate) use inline::InlineSourceReader;
 pub(crate) use reader::SourceReader;
 
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod chunked_test;
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod file_test;
+
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod inline_test;
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod read_test;
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod stream_test;
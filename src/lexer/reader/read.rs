@@ -0,0 +1,155 @@
+use std::io::{self, Read};
+
+use super::{reader, stream::StreamCursor};
+
+/// 每次从底层读取的字节数
+const READ_CHUNK_SIZE: usize = 4096;
+
+/// 从 `std::io::Read` 中流式读取 EMCAScript 源码
+///
+/// 按需从底层读取字节并增量解码 UTF-8，无需将整个源码读入内存。
+/// 一个多字节字符可能被拆分在两次读取之间，此时会继续读取直到字符完整。
+/// 读取失败或遇到不合法的 UTF-8 时不再产出字符，报错通过 SourceReader::error 获取，
+/// 词法分析器随后返回 `LexerErrorKind::ReadError`
+pub(crate) struct ReadSourceReader<R: Read> {
+    inner: R,
+    buf: Vec<u8>,
+    pos: usize,
+    consumed: usize,
+    eof: bool,
+    error: Option<io::Error>,
+
+    cursor: StreamCursor,
+}
+
+impl<R: Read> ReadSourceReader<R> {
+    /// 构造一个从 `std::io::Read` 读取源码的 SourceReader
+    ///
+    /// # Arguments
+    /// `inner` - 源码的字节流
+    /// # Returns
+    /// SourceReader 的一个实现
+    pub(crate) fn new(inner: R) -> Self {
+        ReadSourceReader {
+            inner,
+            buf: Vec::new(),
+            pos: 0,
+            consumed: 0,
+            eof: false,
+            error: None,
+
+            cursor: StreamCursor::default(),
+        }
+    }
+
+    /// 从底层读取一块字节追加到缓冲区末尾，已解码的字节会被丢弃
+    fn fill(&mut self) {
+        self.buf.drain(..self.pos);
+        self.pos = 0;
+
+        let len = self.buf.len();
+        self.buf.resize(len + READ_CHUNK_SIZE, 0);
+        let read = loop {
+            match self.inner.read(&mut self.buf[len..]) {
+                Ok(0) => {
+                    self.eof = true;
+                    break 0;
+                }
+                Ok(n) => break n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => {
+                    self.error = Some(err);
+                    break 0;
+                }
+            }
+        };
+        self.buf.truncate(len + read);
+    }
+
+    /// 记录不合法的 UTF-8 报错
+    fn invalid(&mut self) -> Option<char> {
+        self.error = Some(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid UTF-8 source".to_string(),
+        ));
+
+        None
+    }
+
+    /// 解码下一个字符，缓冲区中的字节不足一个字符时继续从底层读取
+    fn next_char(&mut self) -> Option<char> {
+        loop {
+            if self.error.is_some() {
+                return None;
+            }
+
+            let pending = &self.buf[self.pos..];
+            if let Some(&lead) = pending.first() {
+                let width = utf8_width(lead);
+                if width == 0 {
+                    return self.invalid();
+                }
+                if pending.len() >= width {
+                    return match std::str::from_utf8(&pending[..width]) {
+                        Ok(chr) => {
+                            self.pos += width;
+                            self.consumed += width;
+                            chr.chars().next()
+                        }
+                        Err(_) => self.invalid(),
+                    };
+                }
+            }
+
+            if self.eof {
+                // 源码在一个多字节字符的中间结束
+                return if pending.is_empty() {
+                    None
+                } else {
+                    self.invalid()
+                };
+            }
+            self.fill();
+        }
+    }
+}
+
+/// 根据 UTF-8 首字节计算字符所占的字节数，不合法的首字节返回 0
+const fn utf8_width(lead: u8) -> usize {
+    match lead {
+        0x00..=0x7f => 1,
+        0xc2..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf4 => 4,
+        _ => 0,
+    }
+}
+
+impl<R: Read> reader::SourceReader for ReadSourceReader<R> {
+    #[inline(always)]
+    fn next(&mut self, off: isize) {
+        let mut cursor = self.cursor;
+        cursor.next(off, || self.next_char());
+        self.cursor = cursor;
+    }
+
+    #[inline(always)]
+    fn current(&self) -> Option<char> {
+        self.cursor.current()
+    }
+
+    #[inline(always)]
+    fn lookahead(&self) -> Option<char> {
+        self.cursor.lookahead()
+    }
+
+    #[inline(always)]
+    fn offset(&self) -> usize {
+        self.cursor.offset(self.consumed)
+    }
+
+    #[inline(always)]
+    fn error(&self) -> Option<&io::Error> {
+        self.error.as_ref()
+    }
+}
@@ -0,0 +1,117 @@
+use std::io::{self, Read};
+
+use crate::lexer::{tokenize, Lexer, LexerErrorKind, Token};
+
+use super::{read::ReadSourceReader, reader::SourceReader};
+
+/// 每次仅读取一个字节，使多字节字符跨越多次读取
+struct ByteReader<'s>(&'s [u8]);
+
+impl Read for ByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.0.split_first() {
+            Some((&byte, rest)) if !buf.is_empty() => {
+                buf[0] = byte;
+                self.0 = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+#[test]
+fn test_ReadSourceReader_straddle() {
+    let source = "let 名字 = \"😀\"; // ü";
+    let mut reader = ReadSourceReader::new(ByteReader(source.as_bytes()));
+
+    let mut lexer = Lexer::new(&mut reader);
+    let mut tokens = Vec::new();
+    loop {
+        lexer.next_token().unwrap();
+        tokens.push(lexer.current().clone());
+        if matches!(lexer.current(), Token::EOF) {
+            break;
+        }
+    }
+    assert_eq!(tokens, tokenize(source).unwrap());
+
+    reader.next(1);
+    assert_eq!(reader.current(), None);
+    assert_eq!(reader.lookahead(), None);
+    assert!(reader.error().is_none());
+}
+
+#[test]
+fn test_ReadSourceReader_lookahead() {
+    let mut reader = ReadSourceReader::new("a名".as_bytes());
+    reader.next(1);
+    assert_eq!(reader.current(), Some('a'));
+    assert_eq!(reader.lookahead(), Some('名'));
+    assert_eq!(reader.offset(), 0);
+    reader.next(1);
+    assert_eq!(reader.current(), Some('名'));
+    assert_eq!(reader.lookahead(), None);
+    assert_eq!(reader.offset(), 1);
+    reader.next(1);
+    assert_eq!(reader.current(), None);
+    assert_eq!(reader.offset(), 4);
+}
+
+#[test]
+fn test_ReadSourceReader_invalid() {
+    let mut reader = ReadSourceReader::new(ByteReader(b"ab\xe5\x90"));
+    reader.next(1);
+    assert_eq!(reader.current(), Some('a'));
+    assert_eq!(reader.lookahead(), Some('b'));
+    reader.next(1);
+    assert_eq!(reader.lookahead(), None);
+    assert_eq!(reader.error().unwrap().kind(), io::ErrorKind::InvalidData);
+
+    let mut reader = ReadSourceReader::new(&b"a\xff"[..]);
+    reader.next(1);
+    assert_eq!(reader.current(), Some('a'));
+    assert_eq!(reader.lookahead(), None);
+    assert_eq!(reader.error().unwrap().kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_ReadSourceReader_lexer_error() {
+    let mut reader = ReadSourceReader::new(ByteReader(b"ab\xe5\x90"));
+    let mut lexer = Lexer::new(&mut reader);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::IdentifierName("ab".to_string()));
+    assert_eq!(
+        lexer.next_token().unwrap_err().kind(),
+        LexerErrorKind::ReadError
+    );
+
+    // 读取失败发生在 Token 中间时同样报告读取失败
+    let mut reader = ReadSourceReader::new(&b"'a\xff'"[..]);
+    let mut lexer = Lexer::new(&mut reader);
+    assert_eq!(
+        lexer.next_token().unwrap_err().kind(),
+        LexerErrorKind::ReadError
+    );
+
+    /// 读取若干字节后返回 IO 报错
+    struct FailingReader(bool);
+
+    impl Read for FailingReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if std::mem::replace(&mut self.0, true) {
+                return Err(io::Error::other("broken pipe"));
+            }
+            buf[..2].copy_from_slice(b"a ");
+            Ok(2)
+        }
+    }
+
+    let mut reader = ReadSourceReader::new(FailingReader(false));
+    let mut lexer = Lexer::new(&mut reader);
+    lexer.next_token().unwrap();
+    assert_eq!(lexer.current(), &Token::IdentifierName("a".to_string()));
+    let err = lexer.next_token().unwrap_err();
+    assert_eq!(err.kind(), LexerErrorKind::ReadError);
+    assert!(!lexer.is_eof());
+}
@@ -1,3 +1,5 @@
+use std::io;
+
 /// 读取 EMCAScript 源码
 ///
 /// 实现该 Trait 应维护一个读取源码的游标，从源码中读取游标指定的字符。
@@ -7,6 +9,10 @@
 pub(crate) trait SourceReader {
     /// 将源码游标向下移动一个字符
     ///
+    /// `off` 为负数时向前回退。持有完整源码的 InlineSourceReader 与 FileSourceReader 支持回退；
+    /// 按流读取的 ChunkedSourceReader 与 ReadSourceReader 不保留已越过的字符，回退时游标保持不变。
+    /// `off` 为 0 时游标保持不变
+    ///
     /// # Arguments
     /// * `off` - 游标移动偏移量
     fn next(&mut self, off: isize);
@@ -22,4 +28,24 @@ pub(crate) trait SourceReader {
     /// # Returns
     /// 返回当前游标指向的下一个字符
     fn lookahead(&self) -> Option<char>;
+
+    /// 获取当前游标指向的字符在源码中的字节偏移量
+    ///
+    /// 偏移量按 UTF-8 编码的字节数计算；尚未读取任何字符时为 0，
+    /// 到达源码末尾后为源码的总字节数
+    ///
+    /// # Returns
+    /// 返回当前字符的字节偏移量
+    fn offset(&self) -> usize;
+
+    /// 获取读取源码时遇到的报错
+    ///
+    /// 读取失败时 current 返回 None，词法分析器据此区分源码结束与读取失败。
+    /// 源码来自内存等不会失败的来源时，无需实现该方法
+    ///
+    /// # Returns
+    /// 读取失败时返回报错，否则返回 None
+    fn error(&self) -> Option<&io::Error> {
+        None
+    }
 }
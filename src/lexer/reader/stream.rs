@@ -0,0 +1,57 @@
+/// 只能向后移动的源码游标，记录当前字符与预读的下一个字符
+///
+/// 由按流读取源码的 ChunkedSourceReader 与 ReadSourceReader 共用。
+/// 这类来源中已被越过的字符不再保留，因此不支持回退
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct StreamCursor {
+    current_chr: Option<char>,
+    lookahead_chr: Option<char>,
+}
+
+impl StreamCursor {
+    /// 将游标向后移动，并重新读取预读字符
+    ///
+    /// `off` 不为正数时游标与预读字符均保持不变，不会从来源读取字符
+    ///
+    /// # Arguments
+    /// `off` - 游标移动偏移量
+    /// `next_char` - 从来源读取下一个字符，来源结束时返回 None
+    pub(super) fn next(&mut self, off: isize, mut next_char: impl FnMut() -> Option<char>) {
+        if off <= 0 {
+            return;
+        }
+        for _ in 0..off {
+            self.current_chr = match self.lookahead_chr.take() {
+                Some(chr) => Some(chr),
+                None => next_char(),
+            };
+        }
+
+        self.lookahead_chr = next_char();
+    }
+
+    #[inline(always)]
+    pub(super) fn current(&self) -> Option<char> {
+        self.current_chr
+    }
+
+    #[inline(always)]
+    pub(super) fn lookahead(&self) -> Option<char> {
+        self.lookahead_chr
+    }
+
+    /// 根据已从来源读取的字节数计算当前字符的字节偏移量
+    ///
+    /// 已读取的字节中包含尚未被游标越过的当前字符与预读字符
+    ///
+    /// # Arguments
+    /// `consumed` - 已从来源读取的字节数
+    /// # Returns
+    /// 返回当前字符的字节偏移量
+    #[inline(always)]
+    pub(super) fn offset(&self, consumed: usize) -> usize {
+        consumed
+            - self.current_chr.map_or(0, char::len_utf8)
+            - self.lookahead_chr.map_or(0, char::len_utf8)
+    }
+}
@@ -0,0 +1,45 @@
+use super::stream::StreamCursor;
+
+#[test]
+fn test_StreamCursor_next() {
+    let mut chars = "a张b".chars();
+    let mut next_char = || chars.next();
+
+    let mut cursor = StreamCursor::default();
+    cursor.next(1, &mut next_char);
+    assert_eq!(cursor.current(), Some('a'));
+    assert_eq!(cursor.lookahead(), Some('张'));
+
+    // 不移动游标时保持预读字符不变，也不从来源读取字符
+    cursor.next(0, &mut next_char);
+    cursor.next(-1, &mut next_char);
+    assert_eq!(cursor.current(), Some('a'));
+    assert_eq!(cursor.lookahead(), Some('张'));
+
+    cursor.next(1, &mut next_char);
+    assert_eq!(cursor.current(), Some('张'));
+    assert_eq!(cursor.lookahead(), Some('b'));
+
+    cursor.next(2, &mut next_char);
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.lookahead(), None);
+}
+
+#[test]
+fn test_StreamCursor_offset() {
+    let mut chars = "a张b".chars();
+    let mut consumed = 0;
+    let mut cursor = StreamCursor::default();
+    assert_eq!(cursor.offset(consumed), 0);
+
+    let mut offsets = Vec::new();
+    for _ in 0..4 {
+        cursor.next(1, || {
+            let chr = chars.next()?;
+            consumed += chr.len_utf8();
+            Some(chr)
+        });
+        offsets.push(cursor.offset(consumed));
+    }
+    assert_eq!(offsets, vec![0, 1, 4, 5]);
+}
@@ -0,0 +1,58 @@
+/// 源码中的一个位置
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Position {
+    /// 字节偏移量，从 0 开始
+    pub offset: usize,
+    /// 行号，从 1 开始
+    pub line: usize,
+    /// 行内的列号（按字符计），从 1 开始
+    pub column: usize,
+}
+
+impl Position {
+    /// 构造一个源码位置
+    ///
+    /// # Arguments
+    /// `offset` - 字节偏移量
+    /// `line` - 行号
+    /// `column` - 列号
+    /// # Returns
+    /// 返回源码位置
+    pub const fn new(offset: usize, line: usize, column: usize) -> Self {
+        Self {
+            offset,
+            line,
+            column,
+        }
+    }
+}
+
+/// Token 在源码中的位置，左闭右开区间
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    /// 构造一个源码位置区间
+    ///
+    /// # Arguments
+    /// `start` - 起始位置（包含）
+    /// `end` - 结束位置（不包含）
+    /// # Returns
+    /// 返回源码位置区间
+    pub const fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+
+    /// 判断字节偏移量是否落在区间内
+    ///
+    /// # Arguments
+    /// `offset` - 字节偏移量
+    /// # Returns
+    /// 若 `start <= offset < end` 则返回 true
+    pub const fn contains(&self, offset: usize) -> bool {
+        self.start.offset <= offset && offset < self.end.offset
+    }
+}
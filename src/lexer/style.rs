@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use super::{code_points, lines::split_lines};
+
+/// 缩进风格
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Indent {
+    /// 使用 `<TAB>` 缩进
+    Tabs,
+
+    /// 使用空格缩进，参数为每级缩进的空格数
+    Spaces(usize),
+}
+
+/// 换行风格
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LineEnding {
+    /// `<LF>`
+    Lf,
+
+    /// `<CR><LF>`
+    CrLf,
+
+    /// `<CR>`
+    Cr,
+}
+
+/// 源码的代码风格
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct StyleInfo {
+    pub(crate) indent: Indent,
+    pub(crate) line_ending: LineEnding,
+}
+
+/// 推断源码中占主导的缩进与换行风格
+///
+/// 缩进以行首空白判定：以 `<TAB>` 开头的行多于以空格开头的行时视为 Tab 缩进，
+/// 否则取相邻非空行之间缩进增量出现次数最多者作为缩进宽度。
+/// 无法推断时缩进默认为 4 个空格，换行默认为 `<LF>`
+///
+/// # Arguments
+/// `source` - EMCAScript 源码
+/// # Returns
+/// 返回推断出的代码风格
+pub(crate) fn detect_style(source: &str) -> StyleInfo {
+    StyleInfo {
+        indent: detect_indent(source),
+        line_ending: detect_line_ending(source),
+    }
+}
+
+fn detect_indent(source: &str) -> Indent {
+    let mut tabs = 0usize;
+    let mut spaces = 0usize;
+    let mut widths = HashMap::new();
+    let mut prev = 0usize;
+
+    for line in split_lines(source) {
+        let content = line.trim_start_matches(code_points::is_whitespace);
+        if content.is_empty() {
+            continue;
+        }
+        let leading = &line[..line.len() - content.len()];
+
+        match leading.chars().next() {
+            Some('\t') => {
+                tabs += 1;
+                continue;
+            }
+            Some(' ') => spaces += 1,
+            _ => {}
+        }
+
+        let width = leading.chars().take_while(|&chr| chr == ' ').count();
+        if width > prev {
+            *widths.entry(width - prev).or_insert(0usize) += 1;
+        }
+        prev = width;
+    }
+
+    if tabs > spaces {
+        return Indent::Tabs;
+    }
+    widths
+        .into_iter()
+        .max_by(|(lwidth, lcount), (rwidth, rcount)| lcount.cmp(rcount).then(rwidth.cmp(lwidth)))
+        .map_or(Indent::Spaces(4), |(width, _)| Indent::Spaces(width))
+}
+
+fn detect_line_ending(source: &str) -> LineEnding {
+    let (mut lf, mut crlf, mut cr) = (0usize, 0usize, 0usize);
+
+    let mut chars = source.chars().peekable();
+    while let Some(chr) = chars.next() {
+        match chr {
+            code_points::LF => lf += 1,
+            code_points::CR if chars.peek() == Some(&code_points::LF) => {
+                chars.next();
+                crlf += 1;
+            }
+            code_points::CR => cr += 1,
+            _ => {}
+        }
+    }
+
+    if crlf > lf && crlf >= cr {
+        LineEnding::CrLf
+    } else if cr > lf && cr > crlf {
+        LineEnding::Cr
+    } else {
+        LineEnding::Lf
+    }
+}
@@ -0,0 +1,33 @@
+use super::style::{detect_style, Indent, LineEnding, StyleInfo};
+
+#[test]
+fn test_detect_style() {
+    assert_eq!(
+        detect_style("function f() {\n\tif (a) {\n\t\tb();\n\t}\n}\n"),
+        StyleInfo {
+            indent: Indent::Tabs,
+            line_ending: LineEnding::Lf,
+        }
+    );
+    assert_eq!(
+        detect_style("function f() {\r\n  if (a) {\r\n    b();\r\n\r\n    c();\r\n  }\r\n}\r\n"),
+        StyleInfo {
+            indent: Indent::Spaces(2),
+            line_ending: LineEnding::CrLf,
+        }
+    );
+    assert_eq!(
+        detect_style("class A {\n    m() {\n        /**\n         * doc\n         */\n    }\n}\n"),
+        StyleInfo {
+            indent: Indent::Spaces(4),
+            line_ending: LineEnding::Lf,
+        }
+    );
+    assert_eq!(
+        detect_style("a;\rb;\r"),
+        StyleInfo {
+            indent: Indent::Spaces(4),
+            line_ending: LineEnding::Cr,
+        }
+    );
+}
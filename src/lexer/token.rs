@@ -1,10 +1,23 @@
+use std::fmt;
+
+use super::span::Span;
+
+/// 词法分析得到的 Token
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, PartialEq)]
-pub(crate) enum Token {
+#[non_exhaustive]
+pub enum Token {
     EOF,
 
-    Comment(String),
+    LineComment(String),
+    BlockComment(String),
     HashbangComment(String),
 
+    /// 连续的空白字符，仅在 `LexerOptions::preserve_trivia` 启用时产出
+    Whitespace(String),
+    /// 一个换行符，`<CR><LF>` 视为一个换行，仅在 `LexerOptions::preserve_trivia` 启用时产出
+    Newline(String),
+
     IdentifierName(String),
     PrivateIdentifier(String),
 
@@ -47,8 +60,6 @@ pub(crate) enum Token {
     With,
     Yield,
 
-    LineTerminator,
-
     DivAssign,
     Spread,
     LE,
@@ -85,8 +96,288 @@ pub(crate) enum Token {
     Operator(char),
     Number(String),
     Str(String),
-    Regular(String),
-    TemplateHead(String),
-    TemplateMiddle(String),
-    TemplateTail(String),
+    Regex {
+        pattern: String,
+        flags: String,
+    },
+
+    /// template 的各部分，`cooked` 为解释转义序列后的值，转义序列不合法时为 None；
+    /// `raw` 为源码中的原始文本（<CR><LF> 与 <CR> 规范化为 <LF>）
+    NoSubstitutionTemplate {
+        cooked: Option<String>,
+        raw: String,
+    },
+    TemplateHead {
+        cooked: Option<String>,
+        raw: String,
+    },
+    TemplateMiddle {
+        cooked: Option<String>,
+        raw: String,
+    },
+    TemplateTail {
+        cooked: Option<String>,
+        raw: String,
+    },
+
+    /// 容错模式下，由无法识别的源码片段产生的 Token，参数为该片段的位置
+    Error(Span),
+}
+
+/// 注释类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CommentKind {
+    /// 单行注释，如 `// comment`
+    Line,
+    /// 多行注释，如 `/* comment */`
+    Block,
+}
+
+/// Token 的粗粒度分类，供语法高亮等场景使用
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum TokenCategory {
+    Keyword,
+    Identifier,
+    String,
+    Number,
+    Comment,
+    Operator,
+    Regex,
+    Template,
+    Punctuation,
+}
+
+impl Token {
+    /// 获取保留字 Token 在源码中的拼写
+    ///
+    /// # Returns
+    /// 若当前 Token 为保留字，则返回其拼写，否则返回 None
+    pub(crate) const fn keyword(&self) -> Option<&'static str> {
+        match self {
+            Token::Await => Some("await"),
+            Token::Break => Some("break"),
+            Token::Case => Some("case"),
+            Token::Catch => Some("catch"),
+            Token::Class => Some("class"),
+            Token::Const => Some("const"),
+            Token::Continue => Some("continue"),
+            Token::Debugger => Some("debugger"),
+            Token::Default => Some("default"),
+            Token::Delete => Some("delete"),
+            Token::Do => Some("do"),
+            Token::Else => Some("else"),
+            Token::Enum => Some("enum"),
+            Token::Export => Some("export"),
+            Token::Extends => Some("extends"),
+            Token::False => Some("false"),
+            Token::Finally => Some("finally"),
+            Token::For => Some("for"),
+            Token::Function => Some("function"),
+            Token::If => Some("if"),
+            Token::Import => Some("import"),
+            Token::In => Some("in"),
+            Token::InstanceOf => Some("instanceof"),
+            Token::New => Some("new"),
+            Token::Null => Some("null"),
+            Token::Return => Some("return"),
+            Token::Super => Some("super"),
+            Token::Switch => Some("switch"),
+            Token::This => Some("this"),
+            Token::Throw => Some("throw"),
+            Token::True => Some("true"),
+            Token::Try => Some("try"),
+            Token::TypeOf => Some("typeof"),
+            Token::Var => Some("var"),
+            Token::Void => Some("void"),
+            Token::While => Some("while"),
+            Token::With => Some("with"),
+            Token::Yield => Some("yield"),
+            _ => None,
+        }
+    }
+
+    /// 获取多字符操作符 Token 在源码中的拼写
+    ///
+    /// # Returns
+    /// 若当前 Token 为多字符操作符，则返回其拼写，否则返回 None
+    pub(crate) const fn punctuator(&self) -> Option<&'static str> {
+        match self {
+            Token::DivAssign => Some("/="),
+            Token::Spread => Some("..."),
+            Token::LE => Some("<="),
+            Token::GE => Some(">="),
+            Token::SHL => Some("<<"),
+            Token::SHLAssign => Some("<<="),
+            Token::SHR => Some(">>"),
+            Token::SHRAssign => Some(">>="),
+            Token::USHR => Some(">>>"),
+            Token::USHRAssign => Some(">>>="),
+            Token::StrictEqual => Some("==="),
+            Token::Equal => Some("=="),
+            Token::ArrowFunction => Some("=>"),
+            Token::NotEqual => Some("!="),
+            Token::StrictNotEqual => Some("!=="),
+            Token::Exp => Some("**"),
+            Token::ExpAssign => Some("**="),
+            Token::MulAssign => Some("*="),
+            Token::Incr => Some("++"),
+            Token::AddAssign => Some("+="),
+            Token::Decr => Some("--"),
+            Token::SubAssign => Some("-="),
+            Token::And => Some("&&"),
+            Token::AndAssign => Some("&&="),
+            Token::BitAndAssign => Some("&="),
+            Token::Or => Some("||"),
+            Token::OrAssign => Some("||="),
+            Token::BitOrAssign => Some("|="),
+            Token::XORAssign => Some("^="),
+            Token::CoalNull => Some("??"),
+            Token::CoalNullAssign => Some("??="),
+            Token::Chain => Some("?."),
+            Token::ModAssign => Some("%="),
+            _ => None,
+        }
+    }
+
+    /// 是否为空白、换行或注释等不影响语法的 Token
+    pub(crate) const fn is_trivia(&self) -> bool {
+        matches!(
+            self,
+            Token::Whitespace(..)
+                | Token::Newline(..)
+                | Token::LineComment(..)
+                | Token::BlockComment(..)
+                | Token::HashbangComment(..)
+        )
+    }
+
+    /// 获取 Token 的粗粒度分类
+    ///
+    /// # Returns
+    /// 返回 Token 所属分类，EOF、空白、换行符与错误 Token 不属于任何分类，返回 None
+    pub(crate) fn category(&self) -> Option<TokenCategory> {
+        let category = match self {
+            Token::EOF | Token::Whitespace(..) | Token::Newline(..) | Token::Error(..) => {
+                return None
+            }
+            Token::LineComment(..) | Token::BlockComment(..) | Token::HashbangComment(..) => {
+                TokenCategory::Comment
+            }
+            Token::IdentifierName(..) | Token::PrivateIdentifier(..) => TokenCategory::Identifier,
+            Token::Number(..) => TokenCategory::Number,
+            Token::Str(..) => TokenCategory::String,
+            Token::Regex { .. } => TokenCategory::Regex,
+            Token::NoSubstitutionTemplate { .. }
+            | Token::TemplateHead { .. }
+            | Token::TemplateMiddle { .. }
+            | Token::TemplateTail { .. } => TokenCategory::Template,
+            Token::Operator('{' | '}' | '(' | ')' | '[' | ']' | ';' | ',') => {
+                TokenCategory::Punctuation
+            }
+            _ if self.keyword().is_some() => TokenCategory::Keyword,
+            _ => TokenCategory::Operator,
+        };
+
+        Some(category)
+    }
+}
+
+/// 将 Token 渲染为其在源码中对应的文本
+///
+/// 保留字与多字符操作符渲染为其拼写，字符串、数字与标识符渲染为其内容，
+/// EOF 渲染为 `<eof>`，错误 Token 渲染为 `<error>`；
+/// 注释按其类型带上 `//`、`/* */` 或 `#!` 定界符渲染；
+/// 模板各部分以其 raw 值渲染并带上 `` ` ``、`${`、`}` 等定界符，正则表达式渲染为 `/pattern/flags`
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::EOF => f.write_str("<eof>"),
+            Token::Error(..) => f.write_str("<error>"),
+            Token::Whitespace(text) | Token::Newline(text) => f.write_str(text),
+            Token::LineComment(text) => write!(f, "//{}", text),
+            Token::BlockComment(text) => write!(f, "/*{}*/", text),
+            Token::HashbangComment(text) => write!(f, "#!{}", text),
+            Token::IdentifierName(text)
+            | Token::PrivateIdentifier(text)
+            | Token::Number(text)
+            | Token::Str(text) => f.write_str(text),
+            Token::Operator(chr) => write!(f, "{}", chr),
+            Token::Regex { pattern, flags } => write!(f, "/{}/{}", pattern, flags),
+            Token::NoSubstitutionTemplate { raw, .. } => write!(f, "`{}`", raw),
+            Token::TemplateHead { raw, .. } => write!(f, "`{}${{", raw),
+            Token::TemplateMiddle { raw, .. } => write!(f, "}}{}${{", raw),
+            Token::TemplateTail { raw, .. } => write!(f, "}}{}`", raw),
+            Token::Await
+            | Token::Break
+            | Token::Case
+            | Token::Catch
+            | Token::Class
+            | Token::Const
+            | Token::Continue
+            | Token::Debugger
+            | Token::Default
+            | Token::Delete
+            | Token::Do
+            | Token::Else
+            | Token::Enum
+            | Token::Export
+            | Token::Extends
+            | Token::False
+            | Token::Finally
+            | Token::For
+            | Token::Function
+            | Token::If
+            | Token::Import
+            | Token::In
+            | Token::InstanceOf
+            | Token::New
+            | Token::Null
+            | Token::Return
+            | Token::Super
+            | Token::Switch
+            | Token::This
+            | Token::Throw
+            | Token::True
+            | Token::Try
+            | Token::TypeOf
+            | Token::Var
+            | Token::Void
+            | Token::While
+            | Token::With
+            | Token::Yield => f.write_str(self.keyword().unwrap_or_default()),
+            Token::DivAssign
+            | Token::Spread
+            | Token::LE
+            | Token::GE
+            | Token::SHL
+            | Token::SHLAssign
+            | Token::SHR
+            | Token::SHRAssign
+            | Token::USHR
+            | Token::USHRAssign
+            | Token::StrictEqual
+            | Token::Equal
+            | Token::ArrowFunction
+            | Token::NotEqual
+            | Token::StrictNotEqual
+            | Token::Exp
+            | Token::ExpAssign
+            | Token::MulAssign
+            | Token::Incr
+            | Token::AddAssign
+            | Token::Decr
+            | Token::SubAssign
+            | Token::And
+            | Token::AndAssign
+            | Token::BitAndAssign
+            | Token::Or
+            | Token::OrAssign
+            | Token::BitOrAssign
+            | Token::XORAssign
+            | Token::CoalNull
+            | Token::CoalNullAssign
+            | Token::Chain
+            | Token::ModAssign => f.write_str(self.punctuator().unwrap_or_default()),
+        }
+    }
 }
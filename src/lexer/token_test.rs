@@ -0,0 +1,100 @@
+use super::{
+    token::{Token, TokenCategory},
+    tokenize,
+};
+
+#[test]
+fn test_Token_category() {
+    assert_eq!(Token::Class.category(), Some(TokenCategory::Keyword));
+    assert_eq!(Token::True.category(), Some(TokenCategory::Keyword));
+    assert_eq!(
+        Token::IdentifierName("foo".to_string()).category(),
+        Some(TokenCategory::Identifier)
+    );
+    assert_eq!(
+        Token::PrivateIdentifier("#foo".to_string()).category(),
+        Some(TokenCategory::Identifier)
+    );
+    assert_eq!(
+        Token::Str("foo".to_string()).category(),
+        Some(TokenCategory::String)
+    );
+    assert_eq!(
+        Token::Number("1".to_string()).category(),
+        Some(TokenCategory::Number)
+    );
+    assert_eq!(
+        Token::HashbangComment("#!".to_string()).category(),
+        Some(TokenCategory::Comment)
+    );
+    assert_eq!(
+        Token::LineComment(" a".to_string()).category(),
+        Some(TokenCategory::Comment)
+    );
+    assert_eq!(
+        Token::Regex {
+            pattern: "a".to_string(),
+            flags: "g".to_string(),
+        }
+        .category(),
+        Some(TokenCategory::Regex)
+    );
+    assert_eq!(
+        Token::TemplateHead {
+            cooked: Some("a".to_string()),
+            raw: "a".to_string(),
+        }
+        .category(),
+        Some(TokenCategory::Template)
+    );
+    assert_eq!(Token::StrictEqual.category(), Some(TokenCategory::Operator));
+    assert_eq!(
+        Token::Operator('+').category(),
+        Some(TokenCategory::Operator)
+    );
+    assert_eq!(
+        Token::Operator('{').category(),
+        Some(TokenCategory::Punctuation)
+    );
+    assert_eq!(Token::EOF.category(), None);
+}
+
+#[test]
+fn test_Token_display() {
+    assert_eq!(Token::EOF.to_string(), "<eof>");
+    assert_eq!(Token::Operator('{').to_string(), "{");
+    assert_eq!(Token::InstanceOf.to_string(), "instanceof");
+    assert_eq!(Token::USHRAssign.to_string(), ">>>=");
+    assert_eq!(Token::Str("a b".to_string()).to_string(), "a b");
+    assert_eq!(Token::LineComment(" a".to_string()).to_string(), "// a");
+    assert_eq!(
+        Token::BlockComment(" a ".to_string()).to_string(),
+        "/* a */"
+    );
+    assert_eq!(
+        Token::HashbangComment("/usr/bin/env node".to_string()).to_string(),
+        "#!/usr/bin/env node"
+    );
+    assert_eq!(
+        Token::Regex {
+            pattern: "a.b".to_string(),
+            flags: "gi".to_string(),
+        }
+        .to_string(),
+        "/a.b/gi"
+    );
+
+    let source = "for (const x of y?.z ?? []) a **= `h${b}m${c}t` + /re/g.source;";
+    let rendered = tokenize(source)
+        .unwrap()
+        .iter()
+        .filter(|tok| !matches!(tok, Token::EOF))
+        .map(Token::to_string)
+        .collect::<Vec<_>>()
+        .join(" ");
+    assert_eq!(
+        rendered,
+        "for ( const x of y ?. z ?? [ ] ) a **= `h${ b }m${ c }t` + /re/g . source ;"
+    );
+    assert_eq!(tokenize(&rendered).unwrap(), tokenize(source).unwrap());
+}
@@ -0,0 +1,251 @@
+use std::{collections::HashMap, hash::Hasher};
+
+use super::{
+    lexer::Lexer,
+    lexer_error::LexerError,
+    lines::split_lines,
+    span::Span,
+    token::{Token, TokenCategory},
+};
+
+/// 源码的词法统计信息
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct LexStats {
+    /// 各分类的 Token 数量
+    pub(crate) categories: HashMap<TokenCategory, usize>,
+    /// Token 总数，不包括 EOF
+    pub(crate) total_tokens: usize,
+    /// 源码行数
+    pub(crate) lines: usize,
+    /// 最长 Token 的字节长度
+    pub(crate) longest_token: usize,
+}
+
+impl LexStats {
+    /// 获取指定分类的 Token 数量
+    pub(crate) fn count(&self, category: TokenCategory) -> usize {
+        self.categories.get(&category).copied().unwrap_or(0)
+    }
+}
+
+/// 将 EMCAScript 源码拆解为完整的 Token 序列
+///
+/// # Arguments
+/// `source` - EMCAScript 源码
+/// # Returns
+/// 返回源码中的全部 Token，最后一个 Token 为 `Token::EOF`
+pub(crate) fn tokenize(source: &str) -> Result<Vec<Token>, LexerError> {
+    Lexer::from_source(source).collect()
+}
+
+/// 收集源码中引用到的全部标识符
+///
+/// 保留字不会被收集，重复出现的标识符仅保留第一次出现的位置
+///
+/// # Arguments
+/// `source` - EMCAScript 源码
+/// # Returns
+/// 按出现顺序返回去重后的标识符
+pub(crate) fn collect_identifiers(source: &str) -> Result<Vec<String>, LexerError> {
+    let mut identifiers: Vec<String> = Vec::new();
+
+    for tok in tokenize(source)? {
+        if let Token::IdentifierName(name) = tok {
+            if !identifiers.contains(&name) {
+                identifiers.push(name);
+            }
+        }
+    }
+
+    Ok(identifiers)
+}
+
+/// 获取源码中覆盖指定字节偏移量的 Token
+///
+/// 若偏移量恰好位于两个 Token 的边界上，则返回后一个 Token；
+/// 若偏移量落在空白处、超出源码范围或源码存在词法错误，则返回 None
+///
+/// # Arguments
+/// `source` - EMCAScript 源码
+/// `offset` - 字节偏移量
+/// # Returns
+/// 返回覆盖该偏移量的 Token 及其位置
+pub(crate) fn token_at(source: &str, offset: usize) -> Option<(Token, Span)> {
+    let mut lexer = Lexer::from_source(source);
+
+    loop {
+        lexer.next_token().ok()?;
+
+        let span = lexer.span();
+        if matches!(lexer.current(), Token::EOF) || span.start.offset > offset {
+            return None;
+        }
+        if span.contains(offset) {
+            return Some((lexer.current().clone(), span));
+        }
+    }
+}
+
+/// 统计源码的词法信息，供工具报告文件复杂度使用
+///
+/// # Arguments
+/// `source` - EMCAScript 源码
+/// # Returns
+/// 返回各分类的 Token 数量、Token 总数、行数以及最长 Token 的长度
+pub(crate) fn lex_stats(source: &str) -> Result<LexStats, LexerError> {
+    let mut lexer = Lexer::from_source(source);
+
+    let mut stats = LexStats {
+        lines: split_lines(source).len(),
+        ..LexStats::default()
+    };
+    loop {
+        lexer.next_token()?;
+        if matches!(lexer.current(), Token::EOF) {
+            return Ok(stats);
+        }
+
+        if let Some(category) = lexer.current().category() {
+            *stats.categories.entry(category).or_insert(0) += 1;
+        }
+        let span = lexer.span();
+        stats.total_tokens += 1;
+        stats.longest_token = stats.longest_token.max(span.end.offset - span.start.offset);
+    }
+}
+
+/// 计算源码中有效 Token 序列的指纹
+///
+/// 空白与注释不参与计算，因此仅格式不同的源码具有相同的指纹。
+/// 每个 Token 编码为一个类型标记字节及其各部分文本，文本以 8 字节小端长度为前缀；
+/// 编码使用 FNV-1a 算法计算，结果不随运行环境与编译器版本变化，可用于构建缓存
+///
+/// # Arguments
+/// `source` - EMCAScript 源码
+/// # Returns
+/// 返回 64 位指纹
+pub(crate) fn token_fingerprint(source: &str) -> Result<u64, LexerError> {
+    let mut hasher = Fnv1aHasher::default();
+
+    for tok in tokenize(source)? {
+        match &tok {
+            Token::EOF => {}
+            tok if tok.is_trivia() => {}
+            Token::IdentifierName(name) => hasher.write_part(1, &[name]),
+            Token::PrivateIdentifier(name) => hasher.write_part(2, &[name]),
+            Token::Number(val) => hasher.write_part(3, &[val]),
+            Token::Str(val) => hasher.write_part(4, &[val]),
+            Token::Regex { pattern, flags } => hasher.write_part(5, &[pattern, flags]),
+            Token::NoSubstitutionTemplate { raw, .. } => hasher.write_part(6, &[raw]),
+            Token::TemplateHead { raw, .. } => hasher.write_part(7, &[raw]),
+            Token::TemplateMiddle { raw, .. } => hasher.write_part(8, &[raw]),
+            Token::TemplateTail { raw, .. } => hasher.write_part(9, &[raw]),
+            Token::Operator(c) => hasher.write_part(10, &[c.encode_utf8(&mut [0; 4])]),
+            Token::Error(span) => hasher.write_part(
+                11,
+                &[&span.start.offset.to_string(), &span.end.offset.to_string()],
+            ),
+            // 其余 Token 均为保留字或多字符操作符，以其拼写编码
+            tok => hasher.write_part(
+                12,
+                &[tok
+                    .keyword()
+                    .or_else(|| tok.punctuator())
+                    .unwrap_or_default()],
+            ),
+        }
+    }
+
+    Ok(hasher.finish())
+}
+
+/// 64 位 FNV-1a 哈希
+struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Fnv1aHasher {
+    /// 写入一个 Token 的编码
+    ///
+    /// # Arguments
+    /// `tag` - Token 的类型标记
+    /// `parts` - Token 的各部分文本
+    fn write_part(&mut self, tag: u8, parts: &[&str]) {
+        self.write(&[tag]);
+        for part in parts {
+            self.write(&(part.len() as u64).to_le_bytes());
+            self.write(part.as_bytes());
+        }
+    }
+}
+
+/// 将源码中的字符串字面量统一为指定的引号风格
+///
+/// 字符串内容保持不变，必要时重新转义；其余 Token 及空白、注释原样保留。
+/// template literal 始终使用反引号，不受影响
+///
+/// # Arguments
+/// `source` - EMCAScript 源码
+/// `quote` - 目标引号，`'` 或 `"`
+/// # Returns
+/// 返回转换后的源码
+pub(crate) fn normalize_quotes(source: &str, quote: char) -> Result<String, LexerError> {
+    let mut lexer = Lexer::from_source(source);
+
+    let mut result = String::with_capacity(source.len());
+    let mut emitted = 0;
+    loop {
+        lexer.next_token()?;
+
+        let span = lexer.span();
+        match lexer.current() {
+            Token::EOF => break,
+            Token::Str(val) => {
+                result.push_str(&source[emitted..span.start.offset]);
+                push_quoted(&mut result, val, quote);
+                emitted = span.end.offset;
+            }
+            _ => {}
+        }
+    }
+    result.push_str(&source[emitted..]);
+
+    Ok(result)
+}
+
+/// 以指定引号包裹字符串内容，并转义其中的特殊字符
+fn push_quoted(result: &mut String, val: &str, quote: char) {
+    result.push(quote);
+    for chr in val.chars() {
+        match chr {
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\u{2028}' => result.push_str("\\u2028"),
+            '\u{2029}' => result.push_str("\\u2029"),
+            _ if chr == quote => {
+                result.push('\\');
+                result.push(chr);
+            }
+            _ => result.push(chr),
+        }
+    }
+    result.push(quote);
+}
@@ -0,0 +1,125 @@
+use super::{
+    span::{Position, Span},
+    token::{Token, TokenCategory},
+    tokenize::{
+        collect_identifiers, lex_stats, normalize_quotes, token_at, token_fingerprint, tokenize,
+    },
+};
+
+#[test]
+fn test_tokenize() {
+    let tokens = tokenize("a + 1").unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            Token::IdentifierName("a".to_string()),
+            Token::Operator('+'),
+            Token::Number("1".to_string()),
+            Token::EOF,
+        ]
+    );
+}
+
+#[test]
+fn test_collect_identifiers() {
+    let identifiers = collect_identifiers(
+        r#"function add(foo, bar) {
+            return foo + bar;
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(identifiers, vec!["add", "foo", "bar"]);
+}
+
+#[test]
+fn test_token_at() {
+    let source = "let 名字 = foobar+1;";
+
+    assert_eq!(
+        token_at(source, 16),
+        Some((
+            Token::IdentifierName("foobar".to_string()),
+            Span::new(Position::new(13, 1, 10), Position::new(19, 1, 16))
+        ))
+    );
+    assert_eq!(
+        token_at(source, 4),
+        Some((
+            Token::IdentifierName("名字".to_string()),
+            Span::new(Position::new(4, 1, 5), Position::new(10, 1, 7))
+        ))
+    );
+    // 位于 `foobar` 与 `+` 的边界上时返回后一个 Token
+    assert_eq!(
+        token_at(source, 19),
+        Some((
+            Token::Operator('+'),
+            Span::new(Position::new(19, 1, 16), Position::new(20, 1, 17))
+        ))
+    );
+    assert_eq!(token_at(source, 3), None);
+    assert_eq!(token_at(source, 100), None);
+}
+
+#[test]
+fn test_lex_stats() {
+    let stats =
+        lex_stats("// add\r\nconst total = add(1, 2);\nlet s = `a${s}` + \"hello\";").unwrap();
+
+    assert_eq!(stats.count(TokenCategory::Comment), 1);
+    assert_eq!(stats.count(TokenCategory::Keyword), 1);
+    assert_eq!(stats.count(TokenCategory::Identifier), 5);
+    assert_eq!(stats.count(TokenCategory::Number), 2);
+    assert_eq!(stats.count(TokenCategory::String), 1);
+    assert_eq!(stats.count(TokenCategory::Template), 2);
+    assert_eq!(stats.count(TokenCategory::Operator), 3);
+    assert_eq!(stats.count(TokenCategory::Punctuation), 5);
+    assert_eq!(stats.count(TokenCategory::Regex), 0);
+    assert_eq!(stats.total_tokens, 20);
+    assert_eq!(stats.lines, 3);
+    assert_eq!(stats.longest_token, 7);
+}
+
+#[test]
+fn test_normalize_quotes() {
+    assert_eq!(normalize_quotes(r#""a'b""#, '\'').unwrap(), r#"'a\'b'"#);
+    assert_eq!(
+        normalize_quotes(r#"f("x",  'y\"z') // "c""#, '\'').unwrap(),
+        r#"f('x',  'y"z') // "c""#
+    );
+    assert_eq!(
+        normalize_quotes(r#"a = 'b\nc\\' + `t${"u"}v`"#, '"').unwrap(),
+        r#"a = "b\nc\\" + `t${"u"}v`"#
+    );
+    assert_eq!(normalize_quotes("`\"`", '\'').unwrap(), "`\"`");
+}
+
+#[test]
+fn test_token_fingerprint() {
+    let fingerprint = token_fingerprint("let a = 1; // comment\nf(a)").unwrap();
+
+    assert_eq!(
+        token_fingerprint("#!/usr/bin/env node\nlet   a=1;\n/* block */\n\n  f( a )").unwrap(),
+        fingerprint
+    );
+    assert_ne!(token_fingerprint("let a = 2;\nf(a)").unwrap(), fingerprint);
+    assert_ne!(token_fingerprint("let a = 1;\nf(b)").unwrap(), fingerprint);
+    assert_ne!(
+        token_fingerprint(r#""ab" + "c""#).unwrap(),
+        token_fingerprint(r#""a" + "bc""#).unwrap()
+    );
+    assert_ne!(
+        token_fingerprint(r#"a"#).unwrap(),
+        token_fingerprint(r#""a""#).unwrap()
+    );
+    assert_eq!(
+        token_fingerprint(r#"'a'"#).unwrap(),
+        token_fingerprint(r#""a""#).unwrap()
+    );
+
+    // 指纹由固定的编码与哈希算法决定，不随运行环境变化
+    assert_eq!(token_fingerprint("").unwrap(), 0xcbf29ce484222325);
+    assert_eq!(token_fingerprint("a").unwrap(), 0xe4981b2632418b44);
+}
@@ -6,3 +6,18 @@ mod parser;
 
 #[allow(dead_code)]
 mod vals;
+
+pub use lexer::{LexerError, LexerErrorKind, Position, Span, Token};
+pub use vals::JSValue;
+
+/// 将 EMCAScript 源码拆解为完整的 Token 序列
+///
+/// 注释同样作为 Token 返回
+///
+/// # Arguments
+/// `source` - EMCAScript 源码
+/// # Returns
+/// 返回源码中的全部 Token，最后一个 Token 为 `Token::EOF`；遇到词法错误时返回报错
+pub fn tokenize(source: &str) -> Result<Vec<Token>, LexerError> {
+    lexer::tokenize(source)
+}
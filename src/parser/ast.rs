@@ -0,0 +1,442 @@
+use crate::vals::JSValue;
+
+use super::parser::SourceType;
+
+/// 表达式节点
+///
+/// 各节点暂不记录源码位置，之后可在需要的变体上增加 span 字段
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Expr {
+    /// 字面量，如 `1`、`"str"`
+    Literal(JSValue),
+
+    /// 标识符引用
+    Identifier(String),
+
+    /// `this`
+    This,
+
+    /// `super`，仅出现在 `super.prop`、`super[expr]` 与 `super(args)` 中
+    Super,
+
+    /// 私有名称，仅出现在成员访问中，如 `this.#x` 中的 `#x`
+    PrivateName(String),
+
+    /// 正则表达式字面量，如 `/a.b/gi`
+    Regex { pattern: String, flags: String },
+
+    /// 成员访问表达式
+    ///
+    /// `computed` 为 true 时表示 `object[property]`，否则表示 `object.property`
+    Member {
+        object: Box<Expr>,
+        property: Box<Expr>,
+        computed: bool,
+    },
+
+    /// `new` 表达式，如 `new Foo(a)`，省略参数列表的 `new Foo` 以空参数列表表示
+    New {
+        callee: Box<Expr>,
+        arguments: Vec<Expr>,
+    },
+
+    /// 函数调用表达式
+    Call {
+        callee: Box<Expr>,
+        arguments: Vec<Expr>,
+    },
+
+    /// 元属性，如 `import.meta`
+    MetaProperty { meta: String, property: String },
+
+    /// 动态导入，`import(specifier)`
+    ImportCall(Box<Expr>),
+
+    /// 一元运算，如 `-a`、`typeof a`
+    Unary { op: UnaryOp, operand: Box<Expr> },
+
+    /// 二元运算，如 `a + b`
+    Binary {
+        op: BinaryOp,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+
+    /// 条件表达式，如 `a ? b : c`
+    Conditional {
+        test: Box<Expr>,
+        consequent: Box<Expr>,
+        alternate: Box<Expr>,
+    },
+
+    /// 赋值表达式，如 `a = 1`、`a += 1`
+    ///
+    /// `op` 为 None 时表示 `=`，否则为复合赋值对应的二元运算符，如 `+=` 对应 `BinaryOp::Add`。
+    /// `=` 的目标可以是数组或对象字面量形式的解构赋值，如 `[a, b = 1] = c`，
+    /// 其中的默认值同样以 op 为 None 的赋值表达式表示
+    Assign {
+        op: Option<BinaryOp>,
+        target: Box<Expr>,
+        value: Box<Expr>,
+    },
+
+    /// 逗号表达式，如 `a, b`
+    Sequence(Vec<Expr>),
+
+    /// 箭头函数，如 `(a, b) => a + b`
+    Arrow {
+        params: Vec<Pattern>,
+        rest: Option<Box<Pattern>>,
+        body: ArrowBody,
+    },
+
+    /// 数组字面量，如 `[1, 2]`
+    Array(Vec<Expr>),
+
+    /// 对象字面量，如 `{ a: 1 }`
+    Object(Vec<Property>),
+
+    /// 函数表达式
+    Function(Box<Function>),
+
+    /// 类表达式
+    Class(Box<Class>),
+}
+
+/// 箭头函数的函数体
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ArrowBody {
+    /// 简写形式，如 `a => a + 1`
+    Expr(Box<Expr>),
+
+    /// 语句块形式，如 `a => { return a; }`
+    Block(Vec<Stmt>),
+}
+
+/// 一元运算符
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnaryOp {
+    /// `-`
+    Neg,
+    /// `+`
+    Plus,
+    /// `!`
+    Not,
+    /// `~`
+    BitNot,
+    /// `typeof`
+    TypeOf,
+    /// `void`
+    Void,
+    /// `delete`
+    Delete,
+}
+
+/// 二元运算符
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BinaryOp {
+    /// `??`
+    Coalesce,
+    /// `||`
+    LogicalOr,
+    /// `&&`
+    LogicalAnd,
+    /// `|`
+    BitOr,
+    /// `^`
+    BitXor,
+    /// `&`
+    BitAnd,
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `===`
+    StrictEqual,
+    /// `!==`
+    StrictNotEqual,
+    /// `<`
+    Less,
+    /// `>`
+    Greater,
+    /// `<=`
+    LessEqual,
+    /// `>=`
+    GreaterEqual,
+    /// `instanceof`
+    InstanceOf,
+    /// `in`
+    In,
+    /// `<<`
+    Shl,
+    /// `>>`
+    Shr,
+    /// `>>>`
+    UShr,
+    /// `+`
+    Add,
+    /// `-`
+    Sub,
+    /// `*`
+    Mul,
+    /// `/`
+    Div,
+    /// `%`
+    Mod,
+    /// `**`
+    Exp,
+}
+
+/// 绑定模式，用于函数参数等需要解构赋值的位置
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Pattern {
+    /// 标识符绑定，如 `a`
+    Identifier(String),
+
+    /// 对象解构，如 `{ a, b: c, ...rest }`
+    Object {
+        properties: Vec<(PropertyKey, Pattern)>,
+        rest: Option<Box<Pattern>>,
+    },
+
+    /// 数组解构，如 `[a, , b, ...rest]`，省略的元素为 None
+    Array {
+        elements: Vec<Option<Pattern>>,
+        rest: Option<Box<Pattern>>,
+    },
+
+    /// 带默认值的绑定，如 `a = 1`
+    Assign {
+        target: Box<Pattern>,
+        value: Box<Expr>,
+    },
+}
+
+/// 函数，包括类中的方法
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Function {
+    pub(crate) name: Option<String>,
+    pub(crate) params: Vec<Pattern>,
+    pub(crate) rest: Option<Box<Pattern>>,
+    pub(crate) body: Vec<Stmt>,
+}
+
+/// 属性名
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PropertyKey {
+    /// 标识符（包括保留字）作为属性名，如 `{ a: 1 }`
+    Identifier(String),
+
+    /// 私有名称，如 `#a`
+    Private(String),
+
+    /// 字符串或数字字面量作为属性名，如 `{ "a": 1 }`
+    Literal(JSValue),
+
+    /// 计算属性名，如 `{ [expr]: 1 }`
+    Computed(Box<Expr>),
+}
+
+/// 对象字面量中的属性
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Property {
+    /// `key: value`，简写形式 `{ x }` 中 value 为同名标识符
+    Init {
+        key: PropertyKey,
+        value: Expr,
+        shorthand: bool,
+    },
+
+    /// 方法简写、getter 或 setter，如 `{ m() {} }`、`{ get p() {} }`
+    Method {
+        key: PropertyKey,
+        kind: MethodKind,
+        value: Function,
+    },
+}
+
+/// 方法类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MethodKind {
+    Constructor,
+    Method,
+    Getter,
+    Setter,
+}
+
+/// 类成员
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ClassMember {
+    /// 方法，包括构造函数、getter 与 setter
+    Method {
+        key: PropertyKey,
+        kind: MethodKind,
+        is_static: bool,
+        value: Function,
+    },
+
+    /// 字段，如 `static x = 1;`
+    Field {
+        key: PropertyKey,
+        is_static: bool,
+        value: Option<Expr>,
+    },
+}
+
+/// 类
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Class {
+    pub(crate) name: Option<String>,
+    pub(crate) super_class: Option<Box<Expr>>,
+    pub(crate) body: Vec<ClassMember>,
+}
+
+/// 变量声明类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VarKind {
+    Var,
+    Let,
+    Const,
+}
+
+/// for 语句中的初始化部分，或 for-in/for-of 语句的左侧
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ForInit {
+    VarDecl {
+        kind: VarKind,
+        declarations: Vec<VarDeclarator>,
+    },
+    Expr(Expr),
+}
+
+/// 变量声明中的单个声明项，如 `x = 1`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct VarDeclarator {
+    pub(crate) name: String,
+    pub(crate) init: Option<Expr>,
+}
+
+/// 导出说明符，如 `export { local as exported }`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ExportSpecifier {
+    pub(crate) local: String,
+    pub(crate) exported: String,
+}
+
+/// 导入说明符，如 `import { imported as local } from "mod"`
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ImportSpecifier {
+    pub(crate) imported: String,
+    pub(crate) local: String,
+}
+
+/// 语句节点
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Stmt {
+    /// 指令，即指令序言 (Directive Prologue) 中的字符串字面量语句，如 `"use strict";`
+    ///
+    /// 值为引号之间的源码原文；含转义序列的字符串不会构成 `"use strict"` 等有特殊含义的指令，
+    /// 仍以表达式语句表示
+    Directive(String),
+
+    /// 表达式语句
+    Expr(Expr),
+
+    /// 语句块，如 `{ a; b; }`
+    Block(Vec<Stmt>),
+
+    /// `if (test) consequent else alternate` 语句，没有 `else` 分支时 alternate 为 None
+    If {
+        test: Expr,
+        consequent: Box<Stmt>,
+        alternate: Option<Box<Stmt>>,
+    },
+
+    /// `return` 语句，省略返回值时为 None
+    Return(Option<Expr>),
+
+    /// `debugger` 语句
+    Debugger,
+
+    /// 函数声明
+    Function(Function),
+
+    /// 类声明
+    Class(Class),
+
+    /// 空语句 `;`
+    Empty,
+
+    /// 变量声明语句，如 `let a = 1, b;`
+    VarDecl {
+        kind: VarKind,
+        declarations: Vec<VarDeclarator>,
+    },
+
+    /// `while (test) body` 语句
+    While { test: Expr, body: Box<Stmt> },
+
+    /// `do body while (test);` 语句
+    DoWhile { body: Box<Stmt>, test: Expr },
+
+    /// `for (init; test; update) body` 语句
+    For {
+        init: Option<ForInit>,
+        test: Option<Expr>,
+        update: Option<Expr>,
+        body: Box<Stmt>,
+    },
+
+    /// `for (left in right) body` 语句
+    ForIn {
+        left: ForInit,
+        right: Expr,
+        body: Box<Stmt>,
+    },
+
+    /// `for (left of right) body` 语句
+    ForOf {
+        left: ForInit,
+        right: Expr,
+        body: Box<Stmt>,
+    },
+
+    /// 导入声明
+    ///
+    /// `import def, * as ns from "mod"` 中 `def` 对应 default，`ns` 对应 namespace；
+    /// `import { a as b } from "mod"` 对应 specifiers；`import "mod"` 三者均为空
+    Import {
+        default: Option<String>,
+        namespace: Option<String>,
+        specifiers: Vec<ImportSpecifier>,
+        source: String,
+    },
+
+    /// 导出声明，如 `export const x = 1`
+    ExportDecl(Box<Stmt>),
+
+    /// 默认导出，如 `export default expr`
+    ///
+    /// `export default function () {}` 与 `export default class {}` 形式的声明
+    /// 分别以函数表达式与类表达式表示
+    ExportDefault(Expr),
+
+    /// 具名导出，如 `export { a, b as c }`，带 `from` 时为再导出
+    ExportNamed {
+        specifiers: Vec<ExportSpecifier>,
+        source: Option<String>,
+    },
+
+    /// 全部再导出，如 `export * from "mod"` 或 `export * as ns from "mod"`
+    ExportAll {
+        exported: Option<String>,
+        source: String,
+    },
+}
+
+/// 语法树的根节点
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Program {
+    pub(crate) body: Vec<Stmt>,
+    pub(crate) source_type: SourceType,
+}
@@ -0,0 +1,157 @@
+use crate::lexer::{Lexer, Span, Token};
+
+use super::parse_error::ParseError;
+
+/// Token 游标
+///
+/// 在词法分析器之上提供 `peek`/`bump`/`eat`/`expect` 语义，
+/// 游标指向的 Token 即为下一个待消费的 Token。
+/// 注释、空白与换行等 Token 会被自动跳过，与词法分析器的选项无关
+pub(crate) struct TokenCursor<'s> {
+    lexer: Lexer<'s>,
+
+    tok: Token,
+    span: Span,
+    newline_before: bool,
+    /// 预读的下一个非空白、非注释的 Token，及其位置与之前是否存在换行
+    peeked: Option<(Token, Span, bool)>,
+}
+
+impl<'s> TokenCursor<'s> {
+    /// 构建一个 Token 游标
+    ///
+    /// # Arguments
+    /// `lexer` - 词法分析器
+    /// # Returns
+    /// 返回 Token 游标，此时游标已指向第一个 Token
+    pub(crate) fn new(lexer: Lexer<'s>) -> Result<Self, ParseError> {
+        let mut cursor = TokenCursor {
+            lexer,
+            tok: Token::EOF,
+            span: Span::default(),
+            newline_before: false,
+            peeked: None,
+        };
+        cursor.advance()?;
+
+        Ok(cursor)
+    }
+
+    /// 将游标移动到下一个非空白、非注释的 Token
+    fn advance(&mut self) -> Result<(), ParseError> {
+        let (tok, span, newline_before) = match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.scan()?,
+        };
+        self.tok = tok;
+        self.span = span;
+        self.newline_before = newline_before;
+
+        Ok(())
+    }
+
+    /// 从词法分析器中读取下一个非空白、非注释的 Token
+    ///
+    /// 被跳过的注释中出现的换行与换行 Token 同样计入该 Token 之前的换行
+    ///
+    /// # Returns
+    /// 返回 Token、Token 在源码中的位置，以及 Token 之前是否存在换行
+    fn scan(&mut self) -> Result<(Token, Span, bool), ParseError> {
+        let mut newline_before = false;
+        loop {
+            self.lexer.next_token()?;
+            let tok = self.lexer.current();
+            newline_before |= self.lexer.has_newline_before() || matches!(tok, Token::Newline(..));
+
+            if !tok.is_trivia() {
+                return Ok((tok.clone(), self.lexer.span(), newline_before));
+            }
+        }
+    }
+
+    /// 获取游标指向的 Token
+    #[inline(always)]
+    pub(crate) fn peek(&self) -> &Token {
+        &self.tok
+    }
+
+    /// 获取游标指向的 Token 之后的一个非空白、非注释的 Token，不移动游标
+    ///
+    /// # Returns
+    /// 返回下一个 Token
+    pub(crate) fn peek_next(&mut self) -> Result<&Token, ParseError> {
+        let peeked = match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.scan()?,
+        };
+
+        Ok(&self.peeked.insert(peeked).0)
+    }
+
+    /// 获取游标指向的 Token 在源码中的位置
+    #[inline(always)]
+    pub(crate) fn span(&self) -> Span {
+        self.span
+    }
+
+    /// 游标指向的 Token 之前是否存在换行
+    #[inline(always)]
+    pub(crate) fn has_newline_before(&self) -> bool {
+        self.newline_before
+    }
+
+    /// 游标是否已到达源码末尾
+    #[inline(always)]
+    pub(crate) fn is_eof(&self) -> bool {
+        matches!(self.peek(), Token::EOF)
+    }
+
+    /// 消费游标指向的 Token，并移动游标
+    ///
+    /// 到达源码末尾后，重复调用将一直返回 `Token::EOF`
+    ///
+    /// # Returns
+    /// 返回被消费的 Token
+    pub(crate) fn bump(&mut self) -> Result<Token, ParseError> {
+        let tok = self.peek().clone();
+        if !self.is_eof() {
+            self.advance()?;
+        }
+
+        Ok(tok)
+    }
+
+    /// 若游标指向的 Token 与期望的 Token 一致，则消费该 Token
+    ///
+    /// # Arguments
+    /// `tok` - 期望的 Token
+    /// # Returns
+    /// 返回是否消费了 Token
+    pub(crate) fn eat(&mut self, tok: &Token) -> Result<bool, ParseError> {
+        if self.peek() != tok {
+            return Ok(false);
+        }
+        self.bump()?;
+
+        Ok(true)
+    }
+
+    /// 消费一个期望的 Token，若游标指向的 Token 不符合期望则返回报错
+    ///
+    /// # Arguments
+    /// `tok` - 期望的 Token
+    /// # Returns
+    /// 返回被消费的 Token
+    pub(crate) fn expect(&mut self, tok: &Token) -> Result<Token, ParseError> {
+        if self.peek() != tok {
+            let expected = tok.to_string();
+            return Err(ParseError::unexpected(
+                self.span(),
+                self.peek().clone(),
+                &[&expected],
+            ));
+        }
+
+        self.bump()
+    }
+}
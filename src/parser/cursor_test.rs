@@ -0,0 +1,76 @@
+use crate::lexer::{InlineSourceReader, Lexer, LexerOptions, Token};
+
+use super::cursor::TokenCursor;
+
+#[test]
+fn test_TokenCursor_expect() {
+    let mut src = InlineSourceReader::new(r#"foo ( /* comment */ )"#);
+    let mut cursor = TokenCursor::new(Lexer::new(&mut src)).unwrap();
+
+    assert_eq!(cursor.peek(), &Token::IdentifierName("foo".to_string()));
+    assert_eq!(cursor.peek_next().unwrap(), &Token::Operator('('));
+    assert_eq!(
+        cursor.bump().unwrap(),
+        Token::IdentifierName("foo".to_string())
+    );
+    assert_eq!(
+        cursor.expect(&Token::Operator('(')).unwrap(),
+        Token::Operator('(')
+    );
+    assert!(cursor.expect(&Token::Operator(']')).is_err());
+    assert_eq!(cursor.peek(), &Token::Operator(')'));
+    assert!(!cursor.eat(&Token::Operator('(')).unwrap());
+    assert!(cursor.eat(&Token::Operator(')')).unwrap());
+    assert!(cursor.is_eof());
+}
+
+#[test]
+fn test_TokenCursor_bump_eof() {
+    let mut src = InlineSourceReader::new(r#"a"#);
+    let mut cursor = TokenCursor::new(Lexer::new(&mut src)).unwrap();
+
+    cursor.bump().unwrap();
+    assert_eq!(cursor.bump().unwrap(), Token::EOF);
+    assert_eq!(cursor.bump().unwrap(), Token::EOF);
+    assert!(cursor.expect(&Token::Operator(';')).is_err());
+}
+
+#[test]
+fn test_TokenCursor_peek_next_skips_trivia() {
+    for options in [
+        LexerOptions::default(),
+        LexerOptions {
+            preserve_trivia: true,
+            ..LexerOptions::default()
+        },
+        LexerOptions {
+            skip_comments: true,
+            ..LexerOptions::default()
+        },
+    ] {
+        let mut src = InlineSourceReader::new("a /* x */ // y\n\t b c");
+        let mut cursor = TokenCursor::new(Lexer::with_options(&mut src, options)).unwrap();
+
+        assert_eq!(cursor.peek(), &Token::IdentifierName("a".to_string()));
+        assert_eq!(
+            cursor.peek_next().unwrap(),
+            &Token::IdentifierName("b".to_string())
+        );
+        assert_eq!(
+            cursor.peek_next().unwrap(),
+            &Token::IdentifierName("b".to_string())
+        );
+        assert_eq!(cursor.peek(), &Token::IdentifierName("a".to_string()));
+        assert!(!cursor.has_newline_before());
+
+        cursor.bump().unwrap();
+        assert_eq!(cursor.peek(), &Token::IdentifierName("b".to_string()));
+        assert!(cursor.has_newline_before());
+        assert_eq!(cursor.span().start.offset, 17);
+
+        cursor.bump().unwrap();
+        assert_eq!(cursor.peek(), &Token::IdentifierName("c".to_string()));
+        assert!(!cursor.has_newline_before());
+        assert_eq!(cursor.peek_next().unwrap(), &Token::EOF);
+    }
+}
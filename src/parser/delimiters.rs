@@ -0,0 +1,42 @@
+use crate::lexer::{Lexer, Span, Token};
+
+use super::parse_error::ParseError;
+
+/// 检查源码中的 `()`、`[]`、`{}` 是否成对出现且正确嵌套
+///
+/// 字符串、template、注释与正则表达式中的括号由词法分析器处理，不参与检查
+///
+/// # Arguments
+/// `source` - EMCAScript 源码
+/// # Returns
+/// 括号不匹配时返回报错，报错位置为不匹配的右括号，
+/// 或缺少对应右括号的左括号
+pub(crate) fn check_delimiters(source: &str) -> Result<(), ParseError> {
+    let mut lexer = Lexer::from_source(source);
+
+    let mut opened: Vec<(char, Span)> = Vec::new();
+    loop {
+        lexer.next_token()?;
+
+        let close = match lexer.current() {
+            Token::EOF => break,
+            Token::Operator(open @ ('(' | '[' | '{')) => {
+                opened.push((*open, lexer.span()));
+                continue;
+            }
+            Token::Operator(')') => '(',
+            Token::Operator(']') => '[',
+            Token::Operator('}') => '{',
+            _ => continue,
+        };
+        match opened.pop() {
+            Some((open, _)) if open == close => {}
+            _ => return Err(ParseError::at(lexer.span())),
+        }
+    }
+
+    match opened.pop() {
+        Some((_, span)) => Err(ParseError::at(span)),
+        None => Ok(()),
+    }
+}
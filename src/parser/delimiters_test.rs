@@ -0,0 +1,26 @@
+use crate::lexer::{Position, Span};
+
+use super::delimiters::check_delimiters;
+
+#[test]
+fn test_check_delimiters() {
+    assert!(check_delimiters(r#"f(a[0], { b: `${c}` }, "(", /[(]/, ']') // {"#).is_ok());
+
+    let err = check_delimiters("if (a) {\n  b();\n").err().unwrap();
+    assert_eq!(
+        err.span(),
+        Some(Span::new(Position::new(7, 1, 8), Position::new(8, 1, 9)))
+    );
+
+    let err = check_delimiters("(a]").err().unwrap();
+    assert_eq!(
+        err.span(),
+        Some(Span::new(Position::new(2, 1, 3), Position::new(3, 1, 4)))
+    );
+
+    let err = check_delimiters("a)").err().unwrap();
+    assert_eq!(
+        err.span(),
+        Some(Span::new(Position::new(1, 1, 2), Position::new(2, 1, 3)))
+    );
+}
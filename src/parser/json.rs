@@ -0,0 +1,266 @@
+use crate::{
+    lexer::{Lexer, Token},
+    vals::{strconv, JSValue},
+};
+
+use super::parse_error::ParseError;
+
+type ParseResult<T> = Result<T, ParseError>;
+
+/// 将 JSON 文本解析为值
+///
+/// 复用 EMCAScript 的词法分析器，并拒绝 JSON 不支持的语法：
+/// 注释、末尾逗号、单引号字符串、未加引号的属性名、`undefined`、
+/// 十六进制等非十进制数字、JSON 之外的空白与转义序列
+///
+/// # Arguments
+/// `source` - JSON 文本
+/// # Returns
+/// 返回 JSON 文本对应的值，顶层值之后出现其他 Token 时返回报错
+pub(crate) fn parse_json(source: &str) -> ParseResult<JSValue> {
+    let mut parser = JsonParser {
+        source,
+        lexer: Lexer::from_source(source),
+        end: 0,
+    };
+    parser.bump()?;
+
+    let val = parser.parse_value()?;
+    match parser.lexer.current() {
+        Token::EOF => Ok(val),
+        _ => Err(parser.error(&[])),
+    }
+}
+
+/// JSON 语法分析器
+///
+/// JSON 的各项限制需要检查 Token 的原始文本以及 Token 之间的空白与注释，
+/// 而 Parser 使用的 TokenCursor 会跳过注释且不保留原始文本，因此 JSON 直接基于词法分析器解析
+struct JsonParser<'s> {
+    source: &'s str,
+    lexer: Lexer<'s>,
+
+    /// 上一个 Token 结束处的字节偏移量
+    end: usize,
+}
+
+impl JsonParser<'_> {
+    /// 读取下一个 Token，并检查它与上一个 Token 之间只有 JSON 空白
+    fn bump(&mut self) -> ParseResult<()> {
+        self.lexer.next_token()?;
+
+        let span = self.lexer.span();
+        let gap = &self.source[self.end..span.start.offset];
+        if !gap
+            .chars()
+            .all(|chr| matches!(chr, ' ' | '\t' | '\n' | '\r'))
+        {
+            return Err(self.error(&[]));
+        }
+        self.end = span.end.offset;
+
+        Ok(())
+    }
+
+    /// 构造一个指向当前 Token 的报错，说明期望出现的 Token
+    ///
+    /// # Arguments
+    /// `expected` - 期望出现的 Token 的描述，如 `,`、`]`
+    /// # Returns
+    /// 返回语法错误
+    fn error(&self, expected: &[&str]) -> ParseError {
+        ParseError::unexpected(self.lexer.span(), self.lexer.current().clone(), expected)
+    }
+
+    /// 当前 Token 在源码中的原始文本
+    fn raw(&self) -> &str {
+        let span = self.lexer.span();
+
+        &self.source[span.start.offset..span.end.offset]
+    }
+
+    /// 解析一个 JSON 值
+    ///
+    /// JSONValue ::
+    ///     JSONNullLiteral
+    ///     JSONBooleanLiteral
+    ///     JSONObject
+    ///     JSONArray
+    ///     JSONString
+    ///     JSONNumber
+    fn parse_value(&mut self) -> ParseResult<JSValue> {
+        let val = match self.lexer.current() {
+            Token::Str(val) if is_json_string(self.raw()) => JSValue::Str(val.clone()),
+            Token::Number(_) => return self.parse_number(self.lexer.span().start.offset),
+            Token::Operator('-') => {
+                let span = self.lexer.span();
+                self.bump()?;
+                // `-` 与数字之间不能有空白
+                if !matches!(self.lexer.current(), Token::Number(_))
+                    || self.lexer.span().start.offset != span.end.offset
+                {
+                    return Err(self.error(&["number"]));
+                }
+                return self.parse_number(span.start.offset);
+            }
+            Token::True => JSValue::Bool(true),
+            Token::False => JSValue::Bool(false),
+            Token::Null => JSValue::Null,
+            Token::Operator('[') => return self.parse_array(),
+            Token::Operator('{') => return self.parse_object(),
+            _ => return Err(self.error(&[])),
+        };
+        self.bump()?;
+
+        Ok(val)
+    }
+
+    /// 解析一个 JSON 数字，游标指向数字 Token
+    ///
+    /// # Arguments
+    /// `start` - 数字（包括可能的 `-`）在源码中的起始字节偏移量
+    fn parse_number(&mut self, start: usize) -> ParseResult<JSValue> {
+        let text = &self.source[start..self.lexer.span().end.offset];
+        if !is_json_number(text) {
+            return Err(self.error(&[]));
+        }
+        let val = strconv::to_number(text);
+        self.bump()?;
+
+        Ok(val)
+    }
+
+    /// 解析 JSON 数组
+    ///
+    /// JSONArray ::
+    ///     `[` `]`
+    ///     `[` JSONElementList `]`
+    fn parse_array(&mut self) -> ParseResult<JSValue> {
+        self.bump()?;
+
+        let mut elements = Vec::new();
+        if !matches!(self.lexer.current(), Token::Operator(']')) {
+            loop {
+                elements.push(self.parse_value()?);
+                if !matches!(self.lexer.current(), Token::Operator(',')) {
+                    break;
+                }
+                self.bump()?;
+            }
+        }
+        self.expect(']', &[",", "]"])?;
+
+        Ok(JSValue::Array(elements))
+    }
+
+    /// 解析 JSON 对象，重复的属性名保留最后一次出现的值
+    ///
+    /// JSONObject ::
+    ///     `{` `}`
+    ///     `{` JSONMemberList `}`
+    fn parse_object(&mut self) -> ParseResult<JSValue> {
+        self.bump()?;
+
+        let mut properties: Vec<(String, JSValue)> = Vec::new();
+        if !matches!(self.lexer.current(), Token::Operator('}')) {
+            loop {
+                let key = match self.lexer.current() {
+                    Token::Str(key) if is_json_string(self.raw()) => key.clone(),
+                    _ => return Err(self.error(&["string"])),
+                };
+                self.bump()?;
+                self.expect(':', &[":"])?;
+                let value = self.parse_value()?;
+
+                match properties.iter_mut().find(|(name, _)| *name == key) {
+                    Some(property) => property.1 = value,
+                    None => properties.push((key, value)),
+                }
+
+                if !matches!(self.lexer.current(), Token::Operator(',')) {
+                    break;
+                }
+                self.bump()?;
+            }
+        }
+        self.expect('}', &[",", "}"])?;
+
+        Ok(JSValue::Object(properties))
+    }
+
+    /// 消费一个期望的标点符号
+    ///
+    /// # Arguments
+    /// `punctuator` - 期望的标点符号
+    /// `expected` - 报错时说明的期望出现的 Token
+    fn expect(&mut self, punctuator: char, expected: &[&str]) -> ParseResult<()> {
+        if self.lexer.current() != &Token::Operator(punctuator) {
+            return Err(self.error(expected));
+        }
+
+        self.bump()
+    }
+}
+
+/// 判断字符串字面量的原始文本是否符合 JSONString 文法
+///
+/// 要求使用双引号，不包含未转义的控制字符，且只使用 JSON 支持的转义序列
+fn is_json_string(raw: &str) -> bool {
+    let Some(content) = raw.strip_prefix('"').and_then(|raw| raw.strip_suffix('"')) else {
+        return false;
+    };
+
+    let mut chars = content.chars();
+    while let Some(chr) = chars.next() {
+        match chr {
+            '\u{0000}'..='\u{001f}' => return false,
+            '\\' => match chars.next() {
+                Some('"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't') => {}
+                Some('u') => {
+                    if !(0..4).all(|_| chars.next().is_some_and(|chr| chr.is_ascii_hexdigit())) {
+                        return false;
+                    }
+                }
+                _ => return false,
+            },
+            _ => {}
+        }
+    }
+
+    true
+}
+
+/// 判断数字的原始文本是否符合 JSONNumber 文法
+///
+/// JSONNumber ::
+///     [`-`] DecimalIntegerLiteral [`.` DecimalDigits] [ExponentPart]
+///
+/// 整数部分不能有多余的前导 0，小数点与指数标记之后必须有数字
+fn is_json_number(text: &str) -> bool {
+    let digits = |s: &str| s.bytes().take_while(u8::is_ascii_digit).count();
+
+    let unsigned = text.strip_prefix('-').unwrap_or(text);
+    let len = digits(unsigned);
+    if len == 0 || (len > 1 && unsigned.starts_with('0')) {
+        return false;
+    }
+
+    let mut rest = &unsigned[len..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = digits(fraction);
+        if len == 0 {
+            return false;
+        }
+        rest = &fraction[len..];
+    }
+    if let Some(exponent) = rest.strip_prefix(['e', 'E']) {
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        let len = digits(exponent);
+        if len == 0 {
+            return false;
+        }
+        rest = &exponent[len..];
+    }
+
+    rest.is_empty()
+}
@@ -0,0 +1,105 @@
+use crate::{lexer::Token, vals::JSValue};
+
+use super::json::parse_json;
+
+#[test]
+fn test_parse_json() {
+    let str = |val: &str| JSValue::Str(val.to_string());
+    assert_eq!(parse_json(r#"null"#).unwrap(), JSValue::Null);
+    assert_eq!(parse_json(" \t\r\ntrue\n").unwrap(), JSValue::Bool(true));
+    assert_eq!(
+        parse_json(r#""a\"b\\c\/d\u0041\n""#).unwrap(),
+        str("a\"b\\c/dA\n")
+    );
+    assert_eq!(parse_json(r#""\uD83D\ude00""#).unwrap(), str("\u{1f600}"));
+
+    assert_eq!(
+        parse_json(r#"{"a": [1, -2, 3.5e2, -0], "b": {"c": null, "d": ""}, "a": false}"#).unwrap(),
+        JSValue::Object(vec![
+            ("a".to_string(), JSValue::Bool(false)),
+            (
+                "b".to_string(),
+                JSValue::Object(vec![
+                    ("c".to_string(), JSValue::Null),
+                    ("d".to_string(), str("")),
+                ])
+            ),
+        ])
+    );
+    assert_eq!(
+        parse_json(r#"[[], {}, [1, -2, 3.5e2, -0.25, 1E-2]]"#).unwrap(),
+        JSValue::Array(vec![
+            JSValue::Array(vec![]),
+            JSValue::Object(vec![]),
+            JSValue::Array(vec![
+                JSValue::Int(1),
+                JSValue::Int(-2),
+                JSValue::Float(350.0),
+                JSValue::Float(-0.25),
+                JSValue::Float(0.01),
+            ]),
+        ])
+    );
+    assert!(
+        matches!(parse_json("-0").unwrap(), JSValue::Float(val) if val == 0.0 && val.is_sign_negative())
+    );
+}
+
+#[test]
+fn test_parse_json_reject() {
+    for source in [
+        r#""#,
+        r#"// comment
+        1"#,
+        r#"1 /* comment */"#,
+        r#"[1, 2,]"#,
+        r#"{"a": 1,}"#,
+        r#"[, 1]"#,
+        r#"'a'"#,
+        r#"{a: 1}"#,
+        r#"{1: 1}"#,
+        r#"undefined"#,
+        r#"[1] 2"#,
+        r#"01"#,
+        r#"0x10"#,
+        r#"1."#,
+        r#".5"#,
+        r#"1e"#,
+        r#"+1"#,
+        r#"- 1"#,
+        r#"1n"#,
+        r#"1_000"#,
+        r#"Infinity"#,
+        r#""\x41""#,
+        r#""\u{41}""#,
+        r#""\'""#,
+        "\"a\tb\"",
+        "\u{a0}1",
+        r#"`a`"#,
+    ] {
+        assert!(parse_json(source).is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_parse_json_error() {
+    let err = parse_json(r#"[1 2]"#).unwrap_err();
+    assert_eq!(err.found(), Some(&Token::Number("2".to_string())));
+    assert_eq!(err.expected(), [",", "]"]);
+    assert_eq!(err.column(), Some(4));
+
+    let err = parse_json(r#"{"a" 1}"#).unwrap_err();
+    assert_eq!(err.found(), Some(&Token::Number("1".to_string())));
+    assert_eq!(err.expected(), [":"]);
+
+    let err = parse_json(r#"{a: 1}"#).unwrap_err();
+    assert_eq!(err.found(), Some(&Token::IdentifierName("a".to_string())));
+    assert_eq!(err.expected(), ["string"]);
+
+    let err = parse_json(r#"- 1"#).unwrap_err();
+    assert_eq!(err.expected(), ["number"]);
+
+    let err = parse_json(r#"[1] 2"#).unwrap_err();
+    assert_eq!(err.found(), Some(&Token::Number("2".to_string())));
+    assert_eq!(err.to_string(), "unexpected `2` at line 1, column 5");
+}
@@ -1,2 +1,29 @@
+mod ast;
+mod cursor;
+mod delimiters;
+mod json;
 mod parse_error;
+#[allow(clippy::module_inception)]
 mod parser;
+mod visit;
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod cursor_test;
+
+#[cfg(test)]
+mod delimiters_test;
+
+#[cfg(test)]
+mod json_test;
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod parse_error_test;
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod parser_test;
+
+#[cfg(test)]
+mod visit_test;
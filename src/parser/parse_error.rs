@@ -1,9 +1,131 @@
-use crate::lexer::LexerError;
+use std::{error, fmt};
 
-pub(crate) struct ParseError {}
+use crate::lexer::{LexerError, LexerErrorKind, Span, Token};
+
+#[derive(Debug, Default)]
+pub(crate) struct ParseError {
+    span: Option<Span>,
+    /// 出错位置的行号与列号
+    location: Option<(usize, usize)>,
+    lexer_kind: Option<LexerErrorKind>,
+    found: Option<Box<Token>>,
+    expected: Vec<String>,
+}
+
+impl ParseError {
+    /// 构造一个指向源码中指定位置的语法错误
+    ///
+    /// # Arguments
+    /// `span` - 出错的 Token 在源码中的位置
+    pub(crate) const fn at(span: Span) -> Self {
+        Self {
+            span: Some(span),
+            location: Some((span.start.line, span.start.column)),
+            lexer_kind: None,
+            found: None,
+            expected: Vec::new(),
+        }
+    }
+
+    /// 构造一个遇到非预期 Token 的语法错误
+    ///
+    /// # Arguments
+    /// `span` - 出错的 Token 在源码中的位置
+    /// `found` - 实际遇到的 Token
+    /// `expected` - 期望出现的 Token 的描述，如 `,`、`]`
+    pub(crate) fn unexpected(span: Span, found: Token, expected: &[&str]) -> Self {
+        Self {
+            found: Some(Box::new(found)),
+            expected: expected.iter().map(|desc| desc.to_string()).collect(),
+            ..Self::at(span)
+        }
+    }
+
+    /// 获取出错位置，未记录位置时返回 None
+    #[inline(always)]
+    pub(crate) const fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// 获取出错位置的行号，从 1 开始，未记录位置时返回 None
+    #[inline(always)]
+    pub(crate) const fn line(&self) -> Option<usize> {
+        match self.location {
+            Some((line, _)) => Some(line),
+            None => None,
+        }
+    }
+
+    /// 获取出错位置在行内的列号，从 1 开始，未记录位置时返回 None
+    #[inline(always)]
+    pub(crate) const fn column(&self) -> Option<usize> {
+        match self.location {
+            Some((_, column)) => Some(column),
+            None => None,
+        }
+    }
+
+    /// 获取实际遇到的 Token，不是由非预期 Token 引起的错误返回 None
+    #[inline(always)]
+    pub(crate) const fn found(&self) -> Option<&Token> {
+        match &self.found {
+            Some(found) => Some(found),
+            None => None,
+        }
+    }
+
+    /// 获取期望出现的 Token 的描述
+    #[inline(always)]
+    pub(crate) fn expected(&self) -> &[String] {
+        &self.expected
+    }
+
+    /// 获取词法错误类型，语法错误不是由词法错误引起时返回 None
+    #[inline(always)]
+    pub(crate) const fn lexer_kind(&self) -> Option<LexerErrorKind> {
+        self.lexer_kind
+    }
+}
 
 impl From<LexerError> for ParseError {
-    fn from(_: LexerError) -> Self {
-        Self {}
+    fn from(err: LexerError) -> Self {
+        Self {
+            location: Some((err.line(), err.column())),
+            lexer_kind: Some(err.kind()),
+            ..Self::default()
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.lexer_kind, self.found.as_deref()) {
+            (Some(kind), _) => write!(f, "{}", kind)?,
+            (None, Some(found)) => {
+                if let Some((last, init)) = self.expected.split_last() {
+                    f.write_str("expected ")?;
+                    for (idx, desc) in init.iter().enumerate() {
+                        let sep = if idx == 0 { "" } else { ", " };
+                        write!(f, "{}`{}`", sep, desc)?;
+                    }
+                    let sep = if init.is_empty() { "" } else { " or " };
+                    write!(f, "{}`{}`, found ", sep, last)?;
+                } else {
+                    f.write_str("unexpected ")?;
+                }
+                match found {
+                    Token::EOF => f.write_str("end of input")?,
+                    found => write!(f, "`{}`", found)?,
+                }
+            }
+            (None, None) => f.write_str("syntax error")?,
+        }
+        if let Some((line, column)) = self.location {
+            write!(f, " at line {}, column {}", line, column)?;
+        }
+
+        Ok(())
     }
 }
+
+impl error::Error for ParseError {}
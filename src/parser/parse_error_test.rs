@@ -0,0 +1,111 @@
+use crate::lexer::{InlineSourceReader, LexerErrorKind, Position, Span, Token};
+
+use super::{
+    parse_error::ParseError,
+    parser::{parse, Parser, SourceType},
+};
+
+#[test]
+fn test_ParseError_from_lexer_error() {
+    let err = parse("let a = 1;\nlet b = \"abc", SourceType::Script)
+        .err()
+        .unwrap();
+    assert_eq!(err.lexer_kind(), Some(LexerErrorKind::UnterminatedString));
+    assert_eq!(err.line(), Some(2));
+    assert!(err.column().is_some());
+    assert_eq!(err.span(), None);
+}
+
+#[test]
+fn test_ParseError_display() {
+    let err = parse("a + 0x;", SourceType::Script).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "invalid numeric literal at line 1, column 7"
+    );
+
+    let err = ParseError::at(Span::new(Position::new(4, 2, 3), Position::new(5, 2, 4)));
+    assert_eq!(err.to_string(), "syntax error at line 2, column 3");
+
+    assert_eq!(ParseError::default().to_string(), "syntax error");
+}
+
+#[test]
+fn test_ParseError_unexpected() {
+    let mut src = InlineSourceReader::new("[1 2]");
+    let mut parser = Parser::new(&mut src).unwrap();
+    let err = parser.parse_value().err().unwrap();
+    assert_eq!(err.found(), Some(&Token::Number("2".to_string())));
+    assert_eq!(err.expected(), [",", "]"]);
+    assert_eq!(
+        err.to_string(),
+        "expected `,` or `]`, found `2` at line 1, column 4"
+    );
+
+    let mut src = InlineSourceReader::new("[a, b");
+    let mut parser = Parser::new(&mut src).unwrap();
+    let err = parser.parse_expression().err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "expected `,` or `]`, found end of input at line 1, column 6"
+    );
+
+    let mut src = InlineSourceReader::new("f(a;");
+    let mut parser = Parser::new(&mut src).unwrap();
+    let err = parser.parse_expression().err().unwrap();
+    assert_eq!(err.found(), Some(&Token::Operator(';')));
+}
+
+#[test]
+fn test_ParseError_missing_semicolon() {
+    let err = parse("a b", SourceType::Script).err().unwrap();
+    assert_eq!(err.found(), Some(&Token::IdentifierName("b".to_string())));
+    assert_eq!(err.expected(), [";"]);
+    assert_eq!(
+        err.to_string(),
+        "expected `;`, found `b` at line 1, column 3"
+    );
+}
+
+#[test]
+fn test_ParseError_primary_expression() {
+    let err = parse("a + ;", SourceType::Script).err().unwrap();
+    assert_eq!(err.found(), Some(&Token::Operator(';')));
+    assert!(err.expected().is_empty());
+    assert_eq!(err.to_string(), "unexpected `;` at line 1, column 5");
+
+    let err = parse("a +", SourceType::Script).err().unwrap();
+    assert_eq!(err.found(), Some(&Token::EOF));
+    assert_eq!(
+        err.to_string(),
+        "unexpected end of input at line 1, column 4"
+    );
+}
+
+#[test]
+fn test_ParseError_always_located() {
+    for source in [
+        "const a;",
+        "import * from 'x';",
+        "function () {}",
+        "({ #a: 1 })",
+        "({ get a: 1 })",
+        "();",
+        "(a + 1) => a",
+        "super.x",
+        "import.meta",
+    ] {
+        let err = parse(source, SourceType::Script).expect_err(source);
+        assert!(err.span().is_some(), "{}", source);
+        assert!(err.found().is_some(), "{}", source);
+    }
+}
+
+#[test]
+fn test_ParseError_signed_bigint_value() {
+    let mut src = InlineSourceReader::new("+1n");
+    let mut parser = Parser::new(&mut src).unwrap();
+    let err = parser.parse_value().err().unwrap();
+    assert_eq!(err.found(), Some(&Token::Number("1n".to_string())));
+    assert_eq!(err.column(), Some(2));
+}
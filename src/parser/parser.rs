@@ -1,25 +1,2400 @@
 use crate::{
-    lexer::{Lexer, SourceReader, Token},
-    vals::JSValue,
+    lexer::{InlineSourceReader, Lexer, LexerOptions, SourceReader, Token},
+    vals::{strconv, JSValue},
 };
 
-use super::parse_error;
+use super::{
+    ast::{
+        ArrowBody, BinaryOp, Class, ClassMember, ExportSpecifier, Expr, ForInit, Function,
+        ImportSpecifier, MethodKind, Pattern, Program, Property, PropertyKey, Stmt, UnaryOp,
+        VarDeclarator, VarKind,
+    },
+    cursor::TokenCursor,
+    parse_error,
+};
+
+type ParseResult<T> = Result<T, parse_error::ParseError>;
+
+/// 源码类型
+///
+/// Module 中允许出现 `import.meta`、`import`/`export` 声明等模块语法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SourceType {
+    Script,
+    Module,
+}
 
+/// 语法分析器
 pub(crate) struct Parser<'s> {
-    pub(super) lexer: Lexer<'s>,
+    pub(super) cursor: TokenCursor<'s>,
+
+    source_type: SourceType,
+
+    /// 对应文法中的 `[In]` 参数，为 false 时 `in` 不作为二元运算符
+    allow_in: bool,
+
+    /// 是否允许 `super.prop` 与 `super[expr]`，仅在方法中允许
+    allow_super_property: bool,
+
+    /// 是否允许 `super(args)`，仅在派生类的构造函数中允许
+    allow_super_call: bool,
+
+    /// 是否允许 `return` 语句，仅在函数体中允许
+    allow_return: bool,
+
+    /// 尚未报告的 CoverInitializedName（如对象字面量中的 `{ a = 1 }`）对应的报错
+    ///
+    /// 该形式只能出现在解构赋值的目标与箭头函数的参数中，
+    /// 在确定所在的表达式不会被重新解释为绑定模式后才报告
+    cover_initializer: Option<parse_error::ParseError>,
 }
 
 impl<'s> Parser<'s> {
-    pub(crate) fn new(reader: &'s mut dyn SourceReader) -> Self {
-        Parser {
-            lexer: Lexer::new(reader),
+    /// 构建一个解析 Script 的语法分析器
+    ///
+    /// # Arguments
+    /// `reader` - EMCAScript 源码读取器
+    /// # Returns
+    /// 返回语法分析器，此时已读取了第一个 Token
+    pub(crate) fn new(reader: &'s mut dyn SourceReader) -> ParseResult<Self> {
+        Self::with_source_type(reader, SourceType::Script)
+    }
+
+    /// 构建一个指定源码类型的语法分析器
+    ///
+    /// # Arguments
+    /// `reader` - EMCAScript 源码读取器
+    /// `source_type` - 源码类型
+    /// # Returns
+    /// 返回语法分析器，此时已读取了第一个 Token
+    pub(crate) fn with_source_type(
+        reader: &'s mut dyn SourceReader,
+        source_type: SourceType,
+    ) -> ParseResult<Self> {
+        Ok(Parser {
+            cursor: TokenCursor::new(Lexer::with_options(
+                reader,
+                LexerOptions {
+                    skip_comments: true,
+                    ..LexerOptions::default()
+                },
+            ))?,
+            source_type,
+            allow_in: true,
+            allow_super_property: false,
+            allow_super_call: false,
+            allow_return: false,
+            cover_initializer: None,
+        })
+    }
+
+    /// 构造一个指向当前 Token 的报错，说明期望出现的 Token
+    ///
+    /// # Arguments
+    /// `expected` - 期望出现的 Token 的描述，如 `,`、`]`
+    /// # Returns
+    /// 返回语法错误
+    fn unexpected(&self, expected: &[&str]) -> parse_error::ParseError {
+        parse_error::ParseError::unexpected(
+            self.cursor.span(),
+            self.cursor.peek().clone(),
+            expected,
+        )
+    }
+
+    /// 将数字字面量转换为数字
+    ///
+    /// BigInt 以 i128 表示，超出其范围的 BigInt 字面量视为错误
+    ///
+    /// # Arguments
+    /// `text` - 当前数字 Token 的文本
+    /// # Returns
+    /// 返回 JSValue 表示的数字
+    fn numeric_literal(&self, text: &str) -> ParseResult<JSValue> {
+        match strconv::to_numeric(text) {
+            JSValue::Float(val) if val.is_nan() && text.ends_with('n') => Err(self.unexpected(&[])),
+            val => Ok(val),
+        }
+    }
+
+    /// 消费一个 IdentifierName（包括保留字）
+    ///
+    /// # Returns
+    /// 返回 IdentifierName 的文本
+    fn expect_identifier_name(&mut self) -> ParseResult<String> {
+        let name = match self.cursor.peek() {
+            Token::IdentifierName(name) => name.clone(),
+            tok => match tok.keyword() {
+                Some(keyword) => keyword.to_string(),
+                None => return Err(self.unexpected(&["identifier"])),
+            },
+        };
+        self.cursor.bump()?;
+
+        Ok(name)
+    }
+
+    /// 消费一个 BindingIdentifier
+    ///
+    /// # Returns
+    /// 返回标识符的文本
+    fn expect_identifier(&mut self) -> ParseResult<String> {
+        let name = match self.cursor.peek() {
+            Token::IdentifierName(name) => name.clone(),
+            _ => return Err(self.unexpected(&["identifier"])),
+        };
+        self.cursor.bump()?;
+
+        Ok(name)
+    }
+
+    /// 消费一个字符串字面量
+    ///
+    /// # Returns
+    /// 返回字符串内容
+    fn expect_string(&mut self) -> ParseResult<String> {
+        let val = match self.cursor.peek() {
+            Token::Str(val) => val.clone(),
+            _ => return Err(self.unexpected(&["string"])),
+        };
+        self.cursor.bump()?;
+
+        Ok(val)
+    }
+
+    /// 当前 Token 是否为指定的上下文关键字（如 `as`、`from`、`let`）
+    #[inline(always)]
+    fn is_contextual(&self, keyword: &str) -> bool {
+        matches!(self.cursor.peek(), Token::IdentifierName(name) if name == keyword)
+    }
+
+    /// 消费一个上下文关键字，若不匹配则返回报错
+    fn expect_contextual(&mut self, keyword: &str) -> ParseResult<()> {
+        if !self.is_contextual(keyword) {
+            return Err(self.unexpected(&[keyword]));
+        }
+        self.cursor.bump()?;
+
+        Ok(())
+    }
+
+    /// 消费语句结尾的 `;`
+    ///
+    /// 按自动分号插入 (ASI) 规则，在 `}`、EOF 之前或换行之后允许省略分号
+    fn consume_semicolon(&mut self) -> ParseResult<()> {
+        if self.cursor.eat(&Token::Operator(';'))? {
+            return Ok(());
+        }
+        if self.cursor.has_newline_before()
+            || matches!(self.cursor.peek(), Token::Operator('}') | Token::EOF)
+        {
+            return Ok(());
+        }
+
+        Err(self.unexpected(&[";"]))
+    }
+
+    /// 在指定的 `[In]` 参数下执行解析，结束后恢复原参数
+    ///
+    /// # Arguments
+    /// `allow_in` - 是否允许 `in` 作为二元运算符
+    /// `parse` - 解析过程
+    /// # Returns
+    /// 返回解析结果
+    fn with_allow_in<T>(
+        &mut self,
+        allow_in: bool,
+        parse: impl FnOnce(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<T> {
+        let saved = std::mem::replace(&mut self.allow_in, allow_in);
+        let result = parse(self);
+        self.allow_in = saved;
+
+        result
+    }
+
+    /// 在指定的 `super` 上下文中执行解析，结束后恢复原上下文
+    ///
+    /// 箭头函数沿用外层的上下文，因此不调用该方法
+    ///
+    /// # Arguments
+    /// `property` - 是否允许 `super.prop` 与 `super[expr]`
+    /// `call` - 是否允许 `super(args)`
+    /// `parse` - 解析过程
+    /// # Returns
+    /// 返回解析结果
+    fn with_super<T>(
+        &mut self,
+        property: bool,
+        call: bool,
+        parse: impl FnOnce(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<T> {
+        let saved_property = std::mem::replace(&mut self.allow_super_property, property);
+        let saved_call = std::mem::replace(&mut self.allow_super_call, call);
+        let result = parse(self);
+        self.allow_super_property = saved_property;
+        self.allow_super_call = saved_call;
+
+        result
+    }
+
+    /// 解析一个由字面量构成的常量值
+    ///
+    /// 支持字符串、数字（可带一元 `-`/`+`）、`true`/`false`/`null`/`undefined`
+    /// 以及由它们嵌套构成的数组与对象字面量
+    ///
+    /// # Returns
+    /// 返回字面量对应的值
+    pub(crate) fn parse_value(&mut self) -> ParseResult<JSValue> {
+        let val = match self.cursor.peek() {
+            Token::Str(val) => JSValue::Str(val.clone()),
+            Token::Number(val) => self.numeric_literal(val)?,
+            Token::True => JSValue::Bool(true),
+            Token::False => JSValue::Bool(false),
+            Token::Null => JSValue::Null,
+            // `undefined` 不是关键字，而是全局对象上的属性
+            Token::IdentifierName(name) if name == "undefined" => JSValue::Undefined,
+            Token::Operator('[') => return self.parse_array_value(),
+            Token::Operator('{') => return self.parse_object_value(),
+            Token::Operator(sign @ ('-' | '+')) => {
+                let negative = *sign == '-';
+                self.cursor.bump()?;
+                return self.parse_signed_number_value(negative);
+            }
+            _ => return Err(self.unexpected(&[])),
+        };
+        self.cursor.bump()?;
+
+        Ok(val)
+    }
+
+    /// 解析一元 `-`/`+` 之后的数字
+    ///
+    /// `-0` 得到 `Float(-0.0)`，Int 取反溢出时转为 Float。
+    /// BigInt 不支持一元 `+`，视为错误
+    ///
+    /// # Arguments
+    /// `negative` - 符号是否为 `-`
+    /// # Returns
+    /// 返回带符号的数字
+    fn parse_signed_number_value(&mut self, negative: bool) -> ParseResult<JSValue> {
+        let val = match self.cursor.peek() {
+            // BigInt 不支持一元 `+`
+            Token::Number(val) => match self.numeric_literal(val)? {
+                JSValue::BigInt(_) if !negative => return Err(self.unexpected(&[])),
+                val => val,
+            },
+            _ => return Err(self.unexpected(&["number"])),
+        };
+        self.cursor.bump()?;
+
+        let val = match (negative, val) {
+            (false, val) => val,
+            (true, JSValue::Int(0)) => JSValue::Float(-0.0),
+            (true, JSValue::Int(val)) => val
+                .checked_neg()
+                .map_or(JSValue::Float(-(val as f64)), JSValue::Int),
+            (true, JSValue::Float(val)) => JSValue::Float(-val),
+            (true, JSValue::BigInt(val)) => JSValue::BigInt(-val),
+            (true, val) => val,
+        };
+
+        Ok(val)
+    }
+
+    /// 解析由字面量构成的数组
+    ///
+    /// ArrayLiteral ::
+    ///     `[` [Elision] `]`
+    ///     `[` ElementList `]`
+    ///     `[` ElementList `,` [Elision] `]`
+    ///
+    /// 省略的元素（空位）以 `JSValue::Undefined` 表示，末尾的单个 `,` 不产生空位
+    ///
+    /// # Returns
+    /// 返回数组
+    fn parse_array_value(&mut self) -> ParseResult<JSValue> {
+        self.cursor.expect(&Token::Operator('['))?;
+
+        let mut elements = Vec::new();
+        loop {
+            match self.cursor.peek() {
+                Token::Operator(']') => break,
+                Token::Operator(',') => {
+                    self.cursor.bump()?;
+                    elements.push(JSValue::Undefined);
+                }
+                _ => {
+                    elements.push(self.parse_value()?);
+                    if !self.cursor.eat(&Token::Operator(','))? {
+                        break;
+                    }
+                }
+            }
+        }
+        if !self.cursor.eat(&Token::Operator(']'))? {
+            return Err(self.unexpected(&[",", "]"]));
+        }
+
+        Ok(JSValue::Array(elements))
+    }
+
+    /// 解析由字面量构成的对象
+    ///
+    /// ObjectLiteral ::
+    ///     `{` `}`
+    ///     `{` PropertyDefinitionList `}`
+    ///     `{` PropertyDefinitionList `,` `}`
+    ///
+    /// 属性名可以是标识符（包括保留字）、字符串或数字，数字属性名按 ToString 转换。
+    /// 属性按首次出现的顺序保存，重复的属性名保留最后一次出现的值
+    ///
+    /// # Returns
+    /// 返回对象
+    fn parse_object_value(&mut self) -> ParseResult<JSValue> {
+        self.cursor.expect(&Token::Operator('{'))?;
+
+        let mut properties: Vec<(String, JSValue)> = Vec::new();
+        while !matches!(self.cursor.peek(), Token::Operator('}')) {
+            let key = match self.cursor.peek() {
+                Token::Str(key) => {
+                    let key = key.clone();
+                    self.cursor.bump()?;
+                    key
+                }
+                Token::Number(val) => {
+                    let key = strconv::to_string(&self.numeric_literal(val)?);
+                    self.cursor.bump()?;
+                    key
+                }
+                _ => self.expect_identifier_name()?,
+            };
+            self.cursor.expect(&Token::Operator(':'))?;
+            let value = self.parse_value()?;
+
+            match properties.iter_mut().find(|(name, _)| *name == key) {
+                Some(property) => property.1 = value,
+                None => properties.push((key, value)),
+            }
+
+            if !self.cursor.eat(&Token::Operator(','))? {
+                break;
+            }
+        }
+        if !self.cursor.eat(&Token::Operator('}'))? {
+            return Err(self.unexpected(&[",", "}"]));
+        }
+
+        Ok(JSValue::Object(properties))
+    }
+
+    /// 解析整个源码
+    ///
+    /// Script ::
+    ///     [ScriptBody]
+    ///
+    /// Module ::
+    ///     [ModuleBody]
+    ///
+    /// 源码开头由字符串字面量构成的表达式语句组成指令序言 (Directive Prologue)，
+    /// 这些语句以 `Stmt::Directive` 表示。指令按源码原文而非字符串的值判定，
+    /// 如 `"use\x20strict";` 不是 `"use strict"` 指令，此时仍以表达式语句表示，但不会结束指令序言
+    ///
+    /// # Returns
+    /// 返回语法树的根节点
+    pub(crate) fn parse_program(&mut self) -> ParseResult<Program> {
+        let mut body = Vec::new();
+        let mut in_prologue = true;
+        while !self.cursor.is_eof() {
+            // 字符串字面量的源码原文（不含引号）与其值的字节数相同时，两者一致；
+            // 转义序列与续行在源码中所占的字节数总是多于其表示的字符，因此可据此判定
+            let raw_len = match self.cursor.peek() {
+                Token::Str(_) => {
+                    let span = self.cursor.span();
+                    Some(span.end.offset - span.start.offset - 2)
+                }
+                _ => None,
+            };
+            let stmt = self.parse_module_item()?;
+
+            in_prologue &= raw_len.is_some();
+            match stmt {
+                Stmt::Expr(Expr::Literal(JSValue::Str(directive)))
+                    if in_prologue && raw_len == Some(directive.len()) =>
+                {
+                    body.push(Stmt::Directive(directive))
+                }
+                stmt @ Stmt::Expr(Expr::Literal(JSValue::Str(_))) if in_prologue => body.push(stmt),
+                stmt => {
+                    in_prologue = false;
+                    body.push(stmt);
+                }
+            }
+        }
+
+        Ok(Program {
+            body,
+            source_type: self.source_type,
+        })
+    }
+
+    /// 解析 ModuleItem
+    ///
+    /// ModuleItem ::
+    ///     ImportDeclaration
+    ///     ExportDeclaration
+    ///     StatementListItem
+    ///
+    /// 以 `import (` 或 `import .` 开头的是表达式语句，而非导入声明
+    ///
+    /// # Returns
+    /// 返回语句节点
+    pub(crate) fn parse_module_item(&mut self) -> ParseResult<Stmt> {
+        match self.cursor.peek() {
+            Token::Import if self.source_type == SourceType::Module => {
+                if matches!(
+                    self.cursor.peek_next()?,
+                    Token::Operator('(') | Token::Operator('.')
+                ) {
+                    self.parse_statement_list_item()
+                } else {
+                    self.parse_import_declaration()
+                }
+            }
+            Token::Export if self.source_type == SourceType::Module => {
+                self.parse_export_declaration()
+            }
+            _ => self.parse_statement_list_item(),
+        }
+    }
+
+    /// 解析 ImportDeclaration
+    ///
+    /// ImportDeclaration ::
+    ///     `import` ImportClause FromClause `;`
+    ///     `import` ModuleSpecifier `;`
+    ///
+    /// ImportClause ::
+    ///     ImportedDefaultBinding
+    ///     NameSpaceImport
+    ///     NamedImports
+    ///     ImportedDefaultBinding `,` NameSpaceImport
+    ///     ImportedDefaultBinding `,` NamedImports
+    ///
+    /// NameSpaceImport ::
+    ///     `*` `as` ImportedBinding
+    ///
+    /// # Returns
+    /// 返回导入语句节点
+    fn parse_import_declaration(&mut self) -> ParseResult<Stmt> {
+        self.cursor.expect(&Token::Import)?;
+
+        let mut default = None;
+        let mut namespace = None;
+        let mut specifiers = Vec::new();
+
+        if let Token::Str(..) = self.cursor.peek() {
+            let source = self.expect_string()?;
+            self.consume_semicolon()?;
+
+            return Ok(Stmt::Import {
+                default,
+                namespace,
+                specifiers,
+                source,
+            });
+        }
+
+        let has_binding = if let Token::IdentifierName(..) = self.cursor.peek() {
+            default = Some(self.expect_identifier()?);
+            self.cursor.eat(&Token::Operator(','))?
+        } else {
+            true
+        };
+        if has_binding {
+            match self.cursor.peek() {
+                Token::Operator('*') => {
+                    self.cursor.bump()?;
+                    self.expect_contextual("as")?;
+                    namespace = Some(self.expect_identifier()?);
+                }
+                Token::Operator('{') => specifiers = self.parse_named_imports()?,
+                _ => return Err(self.unexpected(&["*", "{"])),
+            }
+        }
+
+        self.expect_contextual("from")?;
+        let source = self.expect_string()?;
+        self.consume_semicolon()?;
+
+        Ok(Stmt::Import {
+            default,
+            namespace,
+            specifiers,
+            source,
+        })
+    }
+
+    /// 解析 NamedImports
+    ///
+    /// NamedImports ::
+    ///     `{` `}`
+    ///     `{` ImportsList `}`
+    ///     `{` ImportsList `,` `}`
+    ///
+    /// ImportSpecifier ::
+    ///     ImportedBinding
+    ///     ModuleExportName `as` ImportedBinding
+    ///
+    /// # Returns
+    /// 返回导入说明符列表
+    fn parse_named_imports(&mut self) -> ParseResult<Vec<ImportSpecifier>> {
+        self.cursor.expect(&Token::Operator('{'))?;
+
+        let mut specifiers = Vec::new();
+        while !self.cursor.eat(&Token::Operator('}'))? {
+            let imported = self.parse_module_export_name()?;
+            let local = if self.is_contextual("as") {
+                self.cursor.bump()?;
+                self.expect_identifier()?
+            } else {
+                imported.clone()
+            };
+            specifiers.push(ImportSpecifier { imported, local });
+
+            if !matches!(self.cursor.peek(), Token::Operator('}'))
+                && !self.cursor.eat(&Token::Operator(','))?
+            {
+                return Err(self.unexpected(&[",", "}"]));
+            }
+        }
+
+        Ok(specifiers)
+    }
+
+    /// 解析 ExportDeclaration
+    ///
+    /// ExportDeclaration ::
+    ///     `export` ExportFromClause FromClause `;`
+    ///     `export` NamedExports `;`
+    ///     `export` VariableStatement
+    ///     `export` Declaration
+    ///     `export` `default` HoistableDeclaration[Default]
+    ///     `export` `default` ClassDeclaration[Default]
+    ///     `export` `default` AssignmentExpression `;`
+    ///
+    /// `default` 之后的 `function` 与 `class` 按声明解析，其名称可以省略；
+    /// 声明之后不再解析成员访问、调用等后缀，也不需要 `;`，
+    /// 如 `export default function () {} (1);` 中的 `(1);` 是另一条表达式语句
+    ///
+    /// ExportFromClause ::
+    ///     `*`
+    ///     `*` `as` ModuleExportName
+    ///     NamedExports
+    ///
+    /// # Returns
+    /// 返回导出语句节点
+    fn parse_export_declaration(&mut self) -> ParseResult<Stmt> {
+        self.cursor.expect(&Token::Export)?;
+
+        match self.cursor.peek() {
+            Token::Default => {
+                self.cursor.bump()?;
+                match self.cursor.peek() {
+                    Token::Function => {
+                        let function = self.parse_function(false)?;
+                        return Ok(Stmt::ExportDefault(Expr::Function(Box::new(function))));
+                    }
+                    Token::Class => {
+                        let class = self.parse_class(false)?;
+                        return Ok(Stmt::ExportDefault(Expr::Class(Box::new(class))));
+                    }
+                    _ => {}
+                }
+                let expr = self.parse_assignment_expression()?;
+                self.consume_semicolon()?;
+
+                Ok(Stmt::ExportDefault(expr))
+            }
+            Token::Operator('*') => {
+                self.cursor.bump()?;
+                let exported = if self.is_contextual("as") {
+                    self.cursor.bump()?;
+                    Some(self.parse_module_export_name()?)
+                } else {
+                    None
+                };
+                self.expect_contextual("from")?;
+                let source = self.expect_string()?;
+                self.consume_semicolon()?;
+
+                Ok(Stmt::ExportAll { exported, source })
+            }
+            Token::Operator('{') => {
+                let specifiers = self.parse_named_exports()?;
+                let source = if self.is_contextual("from") {
+                    self.cursor.bump()?;
+                    Some(self.expect_string()?)
+                } else {
+                    None
+                };
+                self.consume_semicolon()?;
+
+                Ok(Stmt::ExportNamed { specifiers, source })
+            }
+            Token::Var | Token::Const | Token::Function | Token::Class => Ok(Stmt::ExportDecl(
+                Box::new(self.parse_statement_list_item()?),
+            )),
+            _ if self.is_contextual("let") => Ok(Stmt::ExportDecl(Box::new(
+                self.parse_statement_list_item()?,
+            ))),
+            _ => Err(self.unexpected(&[])),
+        }
+    }
+
+    /// 解析 NamedExports
+    ///
+    /// NamedExports ::
+    ///     `{` `}`
+    ///     `{` ExportsList `}`
+    ///     `{` ExportsList `,` `}`
+    ///
+    /// ExportSpecifier ::
+    ///     ModuleExportName
+    ///     ModuleExportName `as` ModuleExportName
+    ///
+    /// # Returns
+    /// 返回导出说明符列表
+    fn parse_named_exports(&mut self) -> ParseResult<Vec<ExportSpecifier>> {
+        self.cursor.expect(&Token::Operator('{'))?;
+
+        let mut specifiers = Vec::new();
+        while !self.cursor.eat(&Token::Operator('}'))? {
+            let local = self.parse_module_export_name()?;
+            let exported = if self.is_contextual("as") {
+                self.cursor.bump()?;
+                self.parse_module_export_name()?
+            } else {
+                local.clone()
+            };
+            specifiers.push(ExportSpecifier { local, exported });
+
+            if !matches!(self.cursor.peek(), Token::Operator('}')) {
+                self.cursor.expect(&Token::Operator(','))?;
+            }
+        }
+
+        Ok(specifiers)
+    }
+
+    /// 解析 ModuleExportName
+    ///
+    /// ModuleExportName ::
+    ///     IdentifierName
+    ///     StringLiteral
+    fn parse_module_export_name(&mut self) -> ParseResult<String> {
+        match self.cursor.peek() {
+            Token::Str(..) => self.expect_string(),
+            _ => self.expect_identifier_name(),
+        }
+    }
+
+    /// 解析 StatementListItem
+    ///
+    /// StatementListItem ::
+    ///     Statement
+    ///     Declaration
+    ///
+    /// # Returns
+    /// 返回语句节点
+    pub(crate) fn parse_statement_list_item(&mut self) -> ParseResult<Stmt> {
+        match self.cursor.peek() {
+            Token::Var => self.parse_variable_statement(VarKind::Var),
+            Token::Const => self.parse_variable_statement(VarKind::Const),
+            Token::Function => Ok(Stmt::Function(self.parse_function(true)?)),
+            Token::Class => Ok(Stmt::Class(self.parse_class(true)?)),
+            _ if self.is_contextual("let") => self.parse_variable_statement(VarKind::Let),
+            _ => self.parse_statement(),
+        }
+    }
+
+    /// 解析 Statement
+    ///
+    /// Statement ::
+    ///     BlockStatement
+    ///     EmptyStatement
+    ///     ExpressionStatement
+    ///     IfStatement
+    ///     IterationStatement
+    ///     ReturnStatement
+    ///     DebuggerStatement
+    ///
+    /// IterationStatement ::
+    ///     DoWhileStatement
+    ///     WhileStatement
+    ///     ForStatement
+    ///     ForInOfStatement
+    ///
+    /// # Returns
+    /// 返回语句节点
+    fn parse_statement(&mut self) -> ParseResult<Stmt> {
+        match self.cursor.peek() {
+            Token::Operator('{') => self.parse_block_statement(),
+            Token::Operator(';') => {
+                self.cursor.bump()?;
+                Ok(Stmt::Empty)
+            }
+            Token::If => self.parse_if_statement(),
+            Token::Do => self.parse_do_while_statement(),
+            Token::While => self.parse_while_statement(),
+            Token::For => self.parse_for_statement(),
+            Token::Return => self.parse_return_statement(),
+            Token::Debugger => self.parse_debugger_statement(),
+            _ => self.parse_expression_statement(),
+        }
+    }
+
+    /// 解析 ExpressionStatement
+    ///
+    /// ExpressionStatement ::
+    ///     Expression `;`
+    ///
+    /// # Returns
+    /// 返回表达式语句节点
+    fn parse_expression_statement(&mut self) -> ParseResult<Stmt> {
+        let expr = self.parse_expression()?;
+        self.consume_semicolon()?;
+
+        Ok(Stmt::Expr(expr))
+    }
+
+    /// 解析 IfStatement
+    ///
+    /// IfStatement ::
+    ///     `if` `(` Expression `)` Statement `else` Statement
+    ///     `if` `(` Expression `)` Statement
+    ///
+    /// `else` 与最近的尚未匹配 `else` 的 `if` 结合
+    ///
+    /// # Returns
+    /// 返回 if 语句节点
+    fn parse_if_statement(&mut self) -> ParseResult<Stmt> {
+        self.cursor.expect(&Token::If)?;
+        let test = self.parse_paren_condition()?;
+        let consequent = Box::new(self.parse_statement()?);
+        let alternate = if self.cursor.eat(&Token::Else)? {
+            Some(Box::new(self.parse_statement()?))
+        } else {
+            None
+        };
+
+        Ok(Stmt::If {
+            test,
+            consequent,
+            alternate,
+        })
+    }
+
+    /// 解析 ReturnStatement
+    ///
+    /// ReturnStatement ::
+    ///     `return` `;`
+    ///     `return` [no LineTerminator here] Expression `;`
+    ///
+    /// `return` 只能出现在函数体中
+    ///
+    /// # Returns
+    /// 返回 return 语句节点
+    fn parse_return_statement(&mut self) -> ParseResult<Stmt> {
+        if !self.allow_return {
+            return Err(self.unexpected(&[]));
+        }
+        self.cursor.expect(&Token::Return)?;
+
+        let argument = match self.cursor.peek() {
+            Token::Operator(';' | '}') | Token::EOF => None,
+            _ if self.cursor.has_newline_before() => None,
+            _ => Some(self.parse_expression()?),
+        };
+        self.consume_semicolon()?;
+
+        Ok(Stmt::Return(argument))
+    }
+
+    /// 解析 DebuggerStatement
+    ///
+    /// DebuggerStatement ::
+    ///     `debugger` `;`
+    ///
+    /// # Returns
+    /// 返回 debugger 语句节点
+    fn parse_debugger_statement(&mut self) -> ParseResult<Stmt> {
+        self.cursor.expect(&Token::Debugger)?;
+        self.consume_semicolon()?;
+
+        Ok(Stmt::Debugger)
+    }
+
+    /// 解析 BlockStatement
+    ///
+    /// Block ::
+    ///     `{` [StatementList] `}`
+    ///
+    /// # Returns
+    /// 返回语句块节点
+    fn parse_block_statement(&mut self) -> ParseResult<Stmt> {
+        self.cursor.expect(&Token::Operator('{'))?;
+
+        let mut body = Vec::new();
+        while !self.cursor.eat(&Token::Operator('}'))? {
+            body.push(self.parse_statement_list_item()?);
+        }
+
+        Ok(Stmt::Block(body))
+    }
+
+    /// 解析变量声明语句
+    ///
+    /// VariableStatement ::
+    ///     `var` VariableDeclarationList `;`
+    ///
+    /// LexicalDeclaration ::
+    ///     LetOrConst BindingList `;`
+    ///
+    /// # Arguments
+    /// `kind` - 变量声明类型
+    /// # Returns
+    /// 返回变量声明语句节点
+    fn parse_variable_statement(&mut self, kind: VarKind) -> ParseResult<Stmt> {
+        self.cursor.bump()?;
+
+        let declarations = self.parse_variable_declaration_list()?;
+        if kind == VarKind::Const && declarations.iter().any(|decl| decl.init.is_none()) {
+            return Err(self.unexpected(&["="]));
+        }
+        self.consume_semicolon()?;
+
+        Ok(Stmt::VarDecl { kind, declarations })
+    }
+
+    /// 解析变量声明列表，`[In]` 参数由调用方控制
+    ///
+    /// VariableDeclarationList ::
+    ///     VariableDeclaration
+    ///     VariableDeclarationList `,` VariableDeclaration
+    ///
+    /// # Returns
+    /// 返回声明项列表
+    fn parse_variable_declaration_list(&mut self) -> ParseResult<Vec<VarDeclarator>> {
+        let mut declarations = Vec::new();
+        loop {
+            let name = self.expect_identifier()?;
+            let init = if self.cursor.eat(&Token::Operator('='))? {
+                Some(self.parse_assignment_expression()?)
+            } else {
+                None
+            };
+            declarations.push(VarDeclarator { name, init });
+
+            if !self.cursor.eat(&Token::Operator(','))? {
+                return Ok(declarations);
+            }
+        }
+    }
+
+    /// 解析 if 语句与循环语句中括号包裹的条件
+    ///
+    /// # Returns
+    /// 返回条件表达式节点
+    fn parse_paren_condition(&mut self) -> ParseResult<Expr> {
+        self.cursor.expect(&Token::Operator('('))?;
+        let test = self.parse_expression()?;
+        self.cursor.expect(&Token::Operator(')'))?;
+
+        Ok(test)
+    }
+
+    /// 解析 while 语句
+    ///
+    /// WhileStatement ::
+    ///     `while` `(` Expression `)` Statement
+    ///
+    /// # Returns
+    /// 返回 while 语句节点
+    fn parse_while_statement(&mut self) -> ParseResult<Stmt> {
+        self.cursor.expect(&Token::While)?;
+        let test = self.parse_paren_condition()?;
+        let body = Box::new(self.parse_statement()?);
+
+        Ok(Stmt::While { test, body })
+    }
+
+    /// 解析 do-while 语句
+    ///
+    /// DoWhileStatement ::
+    ///     `do` Statement `while` `(` Expression `)` `;`
+    ///
+    /// 按自动分号插入 (ASI) 规则，结尾的 `;` 即使不满足换行条件也可以省略
+    ///
+    /// # Returns
+    /// 返回 do-while 语句节点
+    fn parse_do_while_statement(&mut self) -> ParseResult<Stmt> {
+        self.cursor.expect(&Token::Do)?;
+        let body = Box::new(self.parse_statement()?);
+        self.cursor.expect(&Token::While)?;
+        let test = self.parse_paren_condition()?;
+        self.cursor.eat(&Token::Operator(';'))?;
+
+        Ok(Stmt::DoWhile { body, test })
+    }
+
+    /// 解析 for 语句
+    ///
+    /// ForStatement ::
+    ///     `for` `(` [Expression[~In]] `;` [Expression] `;` [Expression] `)` Statement
+    ///     `for` `(` `var` VariableDeclarationList[~In] `;` [Expression] `;` [Expression] `)` Statement
+    ///     `for` `(` LexicalDeclaration[~In] [Expression] `;` [Expression] `)` Statement
+    ///
+    /// ForInOfStatement ::
+    ///     `for` `(` LeftHandSideExpression `in` Expression `)` Statement
+    ///     `for` `(` ForDeclaration `in` Expression `)` Statement
+    ///     `for` `(` LeftHandSideExpression `of` AssignmentExpression `)` Statement
+    ///     `for` `(` ForDeclaration `of` AssignmentExpression `)` Statement
+    ///
+    /// # Returns
+    /// 返回 for、for-in 或 for-of 语句节点
+    fn parse_for_statement(&mut self) -> ParseResult<Stmt> {
+        self.cursor.expect(&Token::For)?;
+        self.cursor.expect(&Token::Operator('('))?;
+
+        let kind = match self.cursor.peek() {
+            Token::Var => Some(VarKind::Var),
+            Token::Const => Some(VarKind::Const),
+            _ if self.is_contextual("let") => Some(VarKind::Let),
+            _ => None,
+        };
+        let init = match kind {
+            Some(kind) => {
+                self.cursor.bump()?;
+                let declarations =
+                    self.with_allow_in(false, |p| p.parse_variable_declaration_list())?;
+                Some(ForInit::VarDecl { kind, declarations })
+            }
+            None if matches!(self.cursor.peek(), Token::Operator(';')) => None,
+            None => Some(ForInit::Expr(self.parse_expression_no_in()?)),
+        };
+
+        if let Some(left) = init {
+            let is_of = self.is_contextual("of");
+            if is_of || matches!(self.cursor.peek(), Token::In) {
+                if !Self::is_for_in_of_left(&left) {
+                    return Err(self.unexpected(&[";"]));
+                }
+                self.cursor.bump()?;
+                let right = if is_of {
+                    self.parse_assignment_expression()?
+                } else {
+                    self.parse_expression()?
+                };
+                self.cursor.expect(&Token::Operator(')'))?;
+                let body = Box::new(self.parse_statement()?);
+
+                return Ok(if is_of {
+                    Stmt::ForOf { left, right, body }
+                } else {
+                    Stmt::ForIn { left, right, body }
+                });
+            }
+
+            if let ForInit::VarDecl {
+                kind: VarKind::Const,
+                declarations,
+            } = &left
+            {
+                if declarations.iter().any(|decl| decl.init.is_none()) {
+                    return Err(self.unexpected(&["="]));
+                }
+            }
+            self.parse_for_rest(Some(left))
+        } else {
+            self.parse_for_rest(None)
+        }
+    }
+
+    /// 解析 for 语句初始化部分之后的内容
+    ///
+    /// # Arguments
+    /// `init` - 已解析的初始化部分
+    /// # Returns
+    /// 返回 for 语句节点
+    fn parse_for_rest(&mut self, init: Option<ForInit>) -> ParseResult<Stmt> {
+        self.cursor.expect(&Token::Operator(';'))?;
+        let test = match self.cursor.peek() {
+            Token::Operator(';') => None,
+            _ => Some(self.parse_expression()?),
+        };
+        self.cursor.expect(&Token::Operator(';'))?;
+        let update = match self.cursor.peek() {
+            Token::Operator(')') => None,
+            _ => Some(self.parse_expression()?),
+        };
+        self.cursor.expect(&Token::Operator(')'))?;
+        let body = Box::new(self.parse_statement()?);
+
+        Ok(Stmt::For {
+            init,
+            test,
+            update,
+            body,
+        })
+    }
+
+    /// 判断 for-in/for-of 语句的左侧是否合法
+    ///
+    /// 声明只能包含一个不带初始值的声明项，表达式只能是标识符或成员访问
+    fn is_for_in_of_left(left: &ForInit) -> bool {
+        match left {
+            ForInit::VarDecl { declarations, .. } => {
+                matches!(declarations.as_slice(), [decl] if decl.init.is_none())
+            }
+            ForInit::Expr(expr) => matches!(expr, Expr::Identifier(..) | Expr::Member { .. }),
+        }
+    }
+
+    /// 解析表达式
+    ///
+    /// # Returns
+    /// 返回表达式节点
+    ///
+    /// Expression ::
+    ///     AssignmentExpression
+    ///     Expression `,` AssignmentExpression
+    pub(crate) fn parse_expression(&mut self) -> ParseResult<Expr> {
+        let expr = self.parse_assignment_expression()?;
+        if !matches!(self.cursor.peek(), Token::Operator(',')) {
+            return Ok(expr);
+        }
+
+        let mut expressions = vec![expr];
+        while self.cursor.eat(&Token::Operator(','))? {
+            expressions.push(self.parse_assignment_expression()?);
+        }
+
+        Ok(Expr::Sequence(expressions))
+    }
+
+    /// 解析不允许 `in` 运算符的表达式，对应文法中的 Expression[~In]
+    ///
+    /// 用于 for 语句的初始化部分，此时 `in` 被视为 for-in 的分隔符；
+    /// 括号等嵌套结构内部仍允许使用 `in`
+    ///
+    /// # Returns
+    /// 返回表达式节点
+    pub(crate) fn parse_expression_no_in(&mut self) -> ParseResult<Expr> {
+        self.with_allow_in(false, |p| p.parse_expression())
+    }
+
+    /// 解析 AssignmentExpression
+    ///
+    /// AssignmentExpression ::
+    ///     ConditionalExpression
+    ///     ArrowFunction
+    ///     LeftHandSideExpression `=` AssignmentExpression
+    ///     LeftHandSideExpression AssignmentOperator AssignmentExpression
+    ///
+    /// 箭头函数只能作为完整的 AssignmentExpression 出现，
+    /// 因此 `1 + a => a` 与 `() => {}(1)` 均为语法错误
+    ///
+    /// # Returns
+    /// 返回表达式节点
+    fn parse_assignment_expression(&mut self) -> ParseResult<Expr> {
+        let outer = self.cover_initializer.take();
+        let expr = self.parse_assignment_expression_cover();
+        let inner = std::mem::replace(&mut self.cover_initializer, outer);
+
+        match inner {
+            Some(err) if expr.is_ok() => Err(err),
+            _ => expr,
+        }
+    }
+
+    /// 解析可能被重新解释为解构赋值目标或箭头函数参数的 AssignmentExpression
+    ///
+    /// 用于数组字面量的元素、对象字面量的属性值与括号内的表达式。
+    /// 与 parse_assignment_expression 不同，其中的 CoverInitializedName 不会立即报错，
+    /// 而是留给外层在确定表达式的含义后处理
+    ///
+    /// # Returns
+    /// 返回表达式节点
+    fn parse_assignment_expression_cover(&mut self) -> ParseResult<Expr> {
+        let outer = self.cover_initializer.take();
+        let mut parenthesized = false;
+        let (target, target_op) = match self.cursor.peek() {
+            Token::IdentifierName(name) => {
+                let name = name.clone();
+                self.cursor.bump()?;
+                if self.is_arrow() {
+                    self.cover_initializer = outer;
+                    let params = vec![Pattern::Identifier(name)];
+                    return self.parse_arrow_function(params, None);
+                }
+
+                let left = self.parse_left_hand_side_suffix(Expr::Identifier(name))?;
+                self.parse_binary_expression_from(left, 0)?
+            }
+            Token::Operator('(') => {
+                let (expressions, rest, trailing_comma) =
+                    self.with_allow_in(true, |p| p.parse_cover_parenthesized())?;
+                if self.is_arrow() {
+                    self.cover_initializer = outer;
+                    let params = expressions
+                        .into_iter()
+                        .map(Self::expression_to_pattern)
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or_else(|| self.unexpected(&[]))?;
+                    return self.parse_arrow_function(params, rest);
+                }
+                // 括号表达式不会被重新解释为绑定模式，如 `({ a = 1 })` 为语法错误
+                if let Some(err) = self.cover_initializer.take() {
+                    return Err(err);
+                }
+
+                parenthesized = true;
+                let expr = self.cover_to_parenthesized(expressions, rest, trailing_comma)?;
+                let left = self.parse_left_hand_side_suffix(expr)?;
+                self.parse_binary_expression_from(left, 0)?
+            }
+            _ => self.parse_binary_expression(0)?,
+        };
+
+        if matches!(self.cursor.peek(), Token::Operator('?')) {
+            self.cover_initializer = outer.or(self.cover_initializer.take());
+            return self.parse_conditional_expression(target);
+        }
+        let Some(op) = self.peek_assignment_operator() else {
+            self.cover_initializer = outer.or(self.cover_initializer.take());
+            return Ok(target);
+        };
+        // 加括号的数组与对象字面量不能作为解构赋值的目标，如 `([a]) = b`
+        let valid = target_op.is_none()
+            && !(parenthesized && matches!(target, Expr::Array(..) | Expr::Object(..)))
+            && Self::is_assignment_target(&target, op.is_none());
+        if !valid {
+            return Err(self.unexpected(&[]));
+        }
+        self.cursor.bump()?;
+
+        // 赋值目标中的 CoverInitializedName 已作为默认值使用
+        self.cover_initializer = outer;
+        Ok(Expr::Assign {
+            op,
+            target: Box::new(target),
+            value: Box::new(self.parse_assignment_expression()?),
+        })
+    }
+
+    /// 以已解析的条件解析 ConditionalExpression 的其余部分
+    ///
+    /// ConditionalExpression ::
+    ///     ShortCircuitExpression
+    ///     ShortCircuitExpression `?` AssignmentExpression[+In] `:` AssignmentExpression
+    ///
+    /// # Arguments
+    /// `test` - 已解析的条件
+    /// # Returns
+    /// 返回条件表达式节点
+    fn parse_conditional_expression(&mut self, test: Expr) -> ParseResult<Expr> {
+        self.cursor.expect(&Token::Operator('?'))?;
+        let consequent = self.with_allow_in(true, |p| p.parse_assignment_expression())?;
+        self.cursor.expect(&Token::Operator(':'))?;
+        let alternate = self.parse_assignment_expression()?;
+
+        Ok(Expr::Conditional {
+            test: Box::new(test),
+            consequent: Box::new(consequent),
+            alternate: Box::new(alternate),
+        })
+    }
+
+    /// 获取当前 Token 对应的赋值运算符
+    ///
+    /// # Returns
+    /// 当前 Token 不是赋值运算符时返回 None；
+    /// 否则 `=` 返回 `Some(None)`，复合赋值运算符返回其对应的二元运算符
+    fn peek_assignment_operator(&self) -> Option<Option<BinaryOp>> {
+        let op = match self.cursor.peek() {
+            Token::Operator('=') => None,
+            Token::MulAssign => Some(BinaryOp::Mul),
+            Token::DivAssign => Some(BinaryOp::Div),
+            Token::ModAssign => Some(BinaryOp::Mod),
+            Token::AddAssign => Some(BinaryOp::Add),
+            Token::SubAssign => Some(BinaryOp::Sub),
+            Token::SHLAssign => Some(BinaryOp::Shl),
+            Token::SHRAssign => Some(BinaryOp::Shr),
+            Token::USHRAssign => Some(BinaryOp::UShr),
+            Token::BitAndAssign => Some(BinaryOp::BitAnd),
+            Token::XORAssign => Some(BinaryOp::BitXor),
+            Token::BitOrAssign => Some(BinaryOp::BitOr),
+            Token::ExpAssign => Some(BinaryOp::Exp),
+            Token::AndAssign => Some(BinaryOp::LogicalAnd),
+            Token::OrAssign => Some(BinaryOp::LogicalOr),
+            Token::CoalNullAssign => Some(BinaryOp::Coalesce),
+            _ => return None,
+        };
+
+        Some(op)
+    }
+
+    /// 表达式能否作为赋值的目标
+    ///
+    /// 标识符与成员访问总是可以作为赋值目标；`=` 的目标还可以是数组或对象字面量形式的解构赋值，
+    /// 此时其中的各元素需同样可以作为赋值目标，并可以带有默认值
+    ///
+    /// # Arguments
+    /// `expr` - 表达式
+    /// `destructuring` - 是否允许解构赋值
+    /// # Returns
+    /// 返回表达式能否作为赋值的目标
+    fn is_assignment_target(expr: &Expr, destructuring: bool) -> bool {
+        let is_element = |element: &Expr| match element {
+            Expr::Assign {
+                op: None, target, ..
+            } => Self::is_assignment_target(target, true),
+            _ => Self::is_assignment_target(element, true),
+        };
+
+        match expr {
+            Expr::Identifier(..) | Expr::Member { .. } => true,
+            Expr::Array(elements) if destructuring => elements.iter().all(is_element),
+            Expr::Object(properties) if destructuring => properties.iter().all(
+                |property| matches!(property, Property::Init { value, .. } if is_element(value)),
+            ),
+            _ => false,
+        }
+    }
+
+    /// 获取当前 Token 对应的二元运算符及其左右结合力
+    ///
+    /// 结合力越大结合越紧密。左结合运算符的右结合力大于左结合力，
+    /// 右结合运算符（`**`）则相反。当不允许 `in` 运算符时，`in` 不视为二元运算符
+    ///
+    /// # Returns
+    /// 返回二元运算符及其左、右结合力
+    fn peek_binary_operator(&self) -> Option<(BinaryOp, u8, u8)> {
+        let op = match self.cursor.peek() {
+            Token::CoalNull => (BinaryOp::Coalesce, 1, 2),
+            Token::Or => (BinaryOp::LogicalOr, 3, 4),
+            Token::And => (BinaryOp::LogicalAnd, 5, 6),
+            Token::Operator('|') => (BinaryOp::BitOr, 7, 8),
+            Token::Operator('^') => (BinaryOp::BitXor, 9, 10),
+            Token::Operator('&') => (BinaryOp::BitAnd, 11, 12),
+            Token::Equal => (BinaryOp::Equal, 13, 14),
+            Token::NotEqual => (BinaryOp::NotEqual, 13, 14),
+            Token::StrictEqual => (BinaryOp::StrictEqual, 13, 14),
+            Token::StrictNotEqual => (BinaryOp::StrictNotEqual, 13, 14),
+            Token::Operator('<') => (BinaryOp::Less, 15, 16),
+            Token::Operator('>') => (BinaryOp::Greater, 15, 16),
+            Token::LE => (BinaryOp::LessEqual, 15, 16),
+            Token::GE => (BinaryOp::GreaterEqual, 15, 16),
+            Token::InstanceOf => (BinaryOp::InstanceOf, 15, 16),
+            Token::In if self.allow_in => (BinaryOp::In, 15, 16),
+            Token::SHL => (BinaryOp::Shl, 17, 18),
+            Token::SHR => (BinaryOp::Shr, 17, 18),
+            Token::USHR => (BinaryOp::UShr, 17, 18),
+            Token::Operator('+') => (BinaryOp::Add, 19, 20),
+            Token::Operator('-') => (BinaryOp::Sub, 19, 20),
+            Token::Operator('*') => (BinaryOp::Mul, 21, 22),
+            Token::Operator('/') => (BinaryOp::Div, 21, 22),
+            Token::Operator('%') => (BinaryOp::Mod, 21, 22),
+            Token::Exp => (BinaryOp::Exp, 24, 23),
+            _ => return None,
+        };
+
+        Some(op)
+    }
+
+    /// 按结合力解析二元运算表达式
+    ///
+    /// ShortCircuitExpression ::
+    ///     LogicalORExpression
+    ///     CoalesceExpression
+    ///
+    /// 其余各级二元运算表达式（LogicalANDExpression 至 ExponentiationExpression）
+    /// 均按结合力统一处理。CoalesceExpression 的操作数不能是未加括号的 `||` 或 `&&` 表达式，
+    /// 反之亦然，如 `a || b ?? c` 需写作 `(a || b) ?? c`
+    ///
+    /// # Arguments
+    /// `min_bp` - 可接受的最低左结合力
+    /// # Returns
+    /// 返回表达式节点，以及该表达式顶层未加括号的二元运算符
+    fn parse_binary_expression(&mut self, min_bp: u8) -> ParseResult<(Expr, Option<BinaryOp>)> {
+        let left = self.parse_unary_expression()?;
+        self.parse_binary_expression_from(left, min_bp)
+    }
+
+    /// 以已解析的表达式作为最左侧的操作数，按结合力继续解析二元运算表达式
+    ///
+    /// # Arguments
+    /// `left` - 已解析的左操作数
+    /// `min_bp` - 可接受的最低左结合力
+    /// # Returns
+    /// 返回表达式节点，以及该表达式顶层未加括号的二元运算符
+    fn parse_binary_expression_from(
+        &mut self,
+        mut left: Expr,
+        min_bp: u8,
+    ) -> ParseResult<(Expr, Option<BinaryOp>)> {
+        let mut left_op = None;
+
+        while let Some((op, left_bp, right_bp)) = self.peek_binary_operator() {
+            if left_bp < min_bp {
+                break;
+            }
+            let err = self.unexpected(&[]);
+            self.cursor.bump()?;
+
+            let (right, right_op) = self.parse_binary_expression(right_bp)?;
+            if [left_op, right_op]
+                .into_iter()
+                .flatten()
+                .any(|operand_op| mixes_coalesce(op, operand_op))
+            {
+                return Err(err);
+            }
+            left = Expr::Binary {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+            left_op = Some(op);
+        }
+
+        Ok((left, left_op))
+    }
+
+    /// 解析 UnaryExpression
+    ///
+    /// 一元运算表达式不能直接作为 `**` 的左操作数，如 `-a ** b` 需写作 `(-a) ** b`
+    ///
+    /// UnaryExpression ::
+    ///     LeftHandSideExpression
+    ///     `delete` UnaryExpression
+    ///     `void` UnaryExpression
+    ///     `typeof` UnaryExpression
+    ///     `+` UnaryExpression
+    ///     `-` UnaryExpression
+    ///     `~` UnaryExpression
+    ///     `!` UnaryExpression
+    fn parse_unary_expression(&mut self) -> ParseResult<Expr> {
+        let op = match self.cursor.peek() {
+            Token::Delete => UnaryOp::Delete,
+            Token::Void => UnaryOp::Void,
+            Token::TypeOf => UnaryOp::TypeOf,
+            Token::Operator('+') => UnaryOp::Plus,
+            Token::Operator('-') => UnaryOp::Neg,
+            Token::Operator('~') => UnaryOp::BitNot,
+            Token::Operator('!') => UnaryOp::Not,
+            _ => return self.parse_left_hand_side_expression(),
+        };
+        self.cursor.bump()?;
+
+        let operand = self.parse_unary_expression()?;
+        if matches!(self.cursor.peek(), Token::Exp) {
+            return Err(self.unexpected(&[]));
+        }
+
+        Ok(Expr::Unary {
+            op,
+            operand: Box::new(operand),
+        })
+    }
+
+    /// 解析 LeftHandSideExpression
+    ///
+    /// LeftHandSideExpression ::
+    ///     NewExpression
+    ///     CallExpression
+    ///
+    /// CallExpression ::
+    ///     ImportCall
+    ///     CallExpression Arguments
+    ///     CallExpression `[` Expression `]`
+    ///     CallExpression `.` IdentifierName
+    ///
+    /// MemberExpression ::
+    ///     PrimaryExpression
+    ///     MemberExpression `[` Expression `]`
+    ///     MemberExpression `.` IdentifierName
+    ///     MemberExpression `.` PrivateIdentifier
+    ///     MetaProperty
+    ///     `new` MemberExpression Arguments
+    fn parse_left_hand_side_expression(&mut self) -> ParseResult<Expr> {
+        let expr = match self.cursor.peek() {
+            Token::New => self.parse_new_expression()?,
+            _ => self.parse_primary_expression()?,
+        };
+        self.parse_left_hand_side_suffix(expr)
+    }
+
+    /// 解析 `new` 表达式
+    ///
+    /// NewExpression ::
+    ///     MemberExpression
+    ///     `new` NewExpression
+    ///
+    /// 被构造的表达式中不包含函数调用，其后的第一个参数列表属于 `new`，
+    /// 如 `new a.b(c)(d)` 中的 `(d)` 是对 `new a.b(c)` 结果的调用。
+    /// 省略参数列表时，如 `new a`，以空参数列表表示
+    ///
+    /// # Returns
+    /// 返回 new 表达式节点
+    fn parse_new_expression(&mut self) -> ParseResult<Expr> {
+        self.cursor.expect(&Token::New)?;
+
+        let callee = match self.cursor.peek() {
+            Token::New => self.parse_new_expression()?,
+            _ => self.parse_primary_expression()?,
+        };
+        let callee = self.parse_member_suffix(callee, false)?;
+        let arguments = match self.cursor.peek() {
+            Token::Operator('(') => self.with_allow_in(true, |p| p.parse_arguments())?,
+            _ => Vec::new(),
+        };
+
+        Ok(Expr::New {
+            callee: Box::new(callee),
+            arguments,
+        })
+    }
+
+    /// 解析 PrimaryExpression 之后的成员访问与函数调用
+    ///
+    /// # Arguments
+    /// `expr` - 已解析的 PrimaryExpression
+    /// # Returns
+    /// 返回表达式节点
+    fn parse_left_hand_side_suffix(&mut self, expr: Expr) -> ParseResult<Expr> {
+        self.parse_member_suffix(expr, true)
+    }
+
+    /// 解析成员访问，以及可选的函数调用
+    ///
+    /// # Arguments
+    /// `expr` - 已解析的表达式
+    /// `allow_call` - 是否解析函数调用，`new` 的被构造表达式中不包含函数调用
+    /// # Returns
+    /// 返回表达式节点
+    fn parse_member_suffix(&mut self, mut expr: Expr, allow_call: bool) -> ParseResult<Expr> {
+        loop {
+            match self.cursor.peek() {
+                Token::Operator('.') => {
+                    self.cursor.bump()?;
+                    let property = match self.cursor.peek() {
+                        Token::PrivateIdentifier(name) => {
+                            let name = name.clone();
+                            self.cursor.bump()?;
+                            Expr::PrivateName(name)
+                        }
+                        _ => Expr::Identifier(self.expect_identifier_name()?),
+                    };
+                    expr = Expr::Member {
+                        object: Box::new(expr),
+                        property: Box::new(property),
+                        computed: false,
+                    };
+                }
+                Token::Operator('[') => {
+                    self.cursor.bump()?;
+                    let property = self.with_allow_in(true, |p| p.parse_expression())?;
+                    self.cursor.expect(&Token::Operator(']'))?;
+                    expr = Expr::Member {
+                        object: Box::new(expr),
+                        property: Box::new(property),
+                        computed: true,
+                    };
+                }
+                Token::Operator('(') if allow_call => {
+                    let arguments = self.with_allow_in(true, |p| p.parse_arguments())?;
+                    expr = Expr::Call {
+                        callee: Box::new(expr),
+                        arguments,
+                    };
+                }
+                _ => break,
+            }
+        }
+
+        Ok(expr)
+    }
+
+    /// 解析函数调用参数列表
+    ///
+    /// Arguments ::
+    ///     `(` `)`
+    ///     `(` ArgumentList `)`
+    ///     `(` ArgumentList `,` `)`
+    ///
+    /// # Returns
+    /// 返回参数表达式列表
+    fn parse_arguments(&mut self) -> ParseResult<Vec<Expr>> {
+        self.cursor.expect(&Token::Operator('('))?;
+
+        let mut arguments = Vec::new();
+        while !self.cursor.eat(&Token::Operator(')'))? {
+            arguments.push(self.parse_assignment_expression()?);
+
+            if !matches!(self.cursor.peek(), Token::Operator(')')) {
+                self.cursor.expect(&Token::Operator(','))?;
+            }
+        }
+
+        Ok(arguments)
+    }
+
+    /// 解析 PrimaryExpression
+    ///
+    /// PrimaryExpression ::
+    ///     `this`
+    ///     `super`（仅限 SuperProperty 与 SuperCall）
+    ///     IdentifierReference
+    ///     Literal
+    ///     ArrayLiteral
+    ///     ObjectLiteral
+    ///     FunctionExpression
+    ///     CoverParenthesizedExpressionAndArrowParameterList
+    ///     ClassExpression
+    ///     RegularExpressionLiteral
+    ///
+    /// PrimaryExpression 均为自包含的结构，其内部总是允许 `in` 运算符
+    ///
+    /// # Returns
+    /// 返回表达式节点
+    fn parse_primary_expression(&mut self) -> ParseResult<Expr> {
+        self.with_allow_in(true, |p| p.parse_primary_expression_in())
+    }
+
+    /// 解析 PrimaryExpression，`[In]` 参数由 parse_primary_expression 设置
+    fn parse_primary_expression_in(&mut self) -> ParseResult<Expr> {
+        let expr = match self.cursor.peek() {
+            Token::This => Expr::This,
+            Token::IdentifierName(name) => Expr::Identifier(name.clone()),
+            Token::Str(val)
+            | Token::NoSubstitutionTemplate {
+                cooked: Some(val), ..
+            } => Expr::Literal(JSValue::Str(val.clone())),
+            Token::Number(val) => Expr::Literal(self.numeric_literal(val)?),
+            Token::True => Expr::Literal(JSValue::Bool(true)),
+            Token::False => Expr::Literal(JSValue::Bool(false)),
+            Token::Null => Expr::Literal(JSValue::Null),
+            Token::Regex { pattern, flags } => Expr::Regex {
+                pattern: pattern.clone(),
+                flags: flags.clone(),
+            },
+            Token::Import => return self.parse_import_expression(),
+            Token::Super => return self.parse_super(),
+            Token::Operator('(') => {
+                let (expressions, rest, trailing_comma) = self.parse_cover_parenthesized()?;
+                return self.cover_to_parenthesized(expressions, rest, trailing_comma);
+            }
+            Token::Operator('[') => return self.parse_array_literal(),
+            Token::Operator('{') => return self.parse_object_literal(),
+            Token::Function => return Ok(Expr::Function(Box::new(self.parse_function(false)?))),
+            Token::Class => return Ok(Expr::Class(Box::new(self.parse_class(false)?))),
+            _ => return Err(self.unexpected(&[])),
+        };
+        self.cursor.bump()?;
+
+        Ok(expr)
+    }
+
+    /// 解析 `super`，其后的成员访问或调用由 parse_left_hand_side_expression 处理
+    ///
+    /// SuperProperty ::
+    ///     `super` `[` Expression `]`
+    ///     `super` `.` IdentifierName
+    ///
+    /// SuperCall ::
+    ///     `super` Arguments
+    ///
+    /// # Returns
+    /// 返回 Super 节点，`super` 单独出现或不在允许的上下文中时返回报错
+    fn parse_super(&mut self) -> ParseResult<Expr> {
+        self.cursor.expect(&Token::Super)?;
+
+        let allowed = match self.cursor.peek() {
+            Token::Operator('.' | '[') => self.allow_super_property,
+            Token::Operator('(') => self.allow_super_call,
+            _ => false,
+        };
+        if !allowed {
+            return Err(self.unexpected(&[]));
+        }
+
+        Ok(Expr::Super)
+    }
+
+    /// 当前 Token 是否为箭头函数的 `=>`，`=>` 之前不允许换行
+    fn is_arrow(&self) -> bool {
+        matches!(self.cursor.peek(), Token::ArrowFunction) && !self.cursor.has_newline_before()
+    }
+
+    /// 解析括号表达式或箭头函数的参数列表
+    ///
+    /// CoverParenthesizedExpressionAndArrowParameterList ::
+    ///     `(` Expression `)`
+    ///     `(` Expression `,` `)`
+    ///     `(` `)`
+    ///     `(` `...` BindingIdentifier `)`
+    ///     `(` `...` BindingPattern `)`
+    ///     `(` Expression `,` `...` BindingIdentifier `)`
+    ///     `(` Expression `,` `...` BindingPattern `)`
+    ///
+    /// 括号内的内容只解析一次：由 parse_assignment_expression 判断其后是否紧跟 `=>`，
+    /// 是则重新解释为箭头函数的参数列表，否则由 cover_to_parenthesized 转换为括号表达式
+    ///
+    /// # Returns
+    /// 返回括号内的表达式列表、剩余参数，以及最后一个表达式之后是否有逗号
+    fn parse_cover_parenthesized(
+        &mut self,
+    ) -> ParseResult<(Vec<Expr>, Option<Box<Pattern>>, bool)> {
+        self.cursor.expect(&Token::Operator('('))?;
+
+        let mut expressions = Vec::new();
+        let mut rest = None;
+        let mut trailing_comma = false;
+        while !self.cursor.eat(&Token::Operator(')'))? {
+            if self.cursor.eat(&Token::Spread)? {
+                rest = Some(Box::new(self.parse_binding_target()?));
+                self.cursor.expect(&Token::Operator(')'))?;
+                break;
+            }
+            expressions.push(self.parse_assignment_expression_cover()?);
+
+            trailing_comma = self.cursor.eat(&Token::Operator(','))?;
+            if !trailing_comma {
+                self.cursor.expect(&Token::Operator(')'))?;
+                break;
+            }
+        }
+
+        Ok((expressions, rest, trailing_comma))
+    }
+
+    /// 将 cover grammar 中解析得到的括号内容转换为括号表达式，仅允许 `(` Expression `)` 形式
+    ///
+    /// # Arguments
+    /// `expressions` - 括号内的表达式列表
+    /// `rest` - 括号内的剩余参数
+    /// `trailing_comma` - 最后一个表达式之后是否有逗号
+    /// # Returns
+    /// 返回括号内的表达式节点
+    fn cover_to_parenthesized(
+        &self,
+        mut expressions: Vec<Expr>,
+        rest: Option<Box<Pattern>>,
+        trailing_comma: bool,
+    ) -> ParseResult<Expr> {
+        if rest.is_some() || trailing_comma || expressions.is_empty() {
+            return Err(self.unexpected(&["=>"]));
+        }
+
+        Ok(match expressions.len() {
+            1 => expressions.remove(0),
+            _ => Expr::Sequence(expressions),
+        })
+    }
+
+    /// 将 cover grammar 中解析得到的表达式重新解释为绑定模式
+    ///
+    /// op 为 None 的赋值表达式（包括 CoverInitializedName）转换为带默认值的绑定，
+    /// 如 `(a = 1, { b = 2 }, [c = 3]) => a` 中的各参数
+    ///
+    /// # Arguments
+    /// `expr` - 表达式
+    /// # Returns
+    /// 返回对应的绑定模式，表达式无法作为绑定时返回 None
+    fn expression_to_pattern(expr: Expr) -> Option<Pattern> {
+        match expr {
+            Expr::Identifier(name) => Some(Pattern::Identifier(name)),
+            Expr::Assign {
+                op: None,
+                target,
+                value,
+            } => Some(Pattern::Assign {
+                target: Box::new(Self::expression_to_pattern(*target)?),
+                value,
+            }),
+            Expr::Array(elements) => Some(Pattern::Array {
+                elements: elements
+                    .into_iter()
+                    .map(|element| Self::expression_to_pattern(element).map(Some))
+                    .collect::<Option<Vec<_>>>()?,
+                rest: None,
+            }),
+            Expr::Object(properties) => Some(Pattern::Object {
+                properties: properties
+                    .into_iter()
+                    .map(|property| match property {
+                        Property::Init { key, value, .. } => {
+                            Some((key, Self::expression_to_pattern(value)?))
+                        }
+                        Property::Method { .. } => None,
+                    })
+                    .collect::<Option<Vec<_>>>()?,
+                rest: None,
+            }),
+            _ => None,
+        }
+    }
+
+    /// 解析箭头函数 `=>` 及其之后的函数体
+    ///
+    /// ArrowFunction ::
+    ///     ArrowParameters `=>` ConciseBody
+    ///
+    /// ConciseBody ::
+    ///     ExpressionBody
+    ///     `{` FunctionBody `}`
+    ///
+    /// # Arguments
+    /// `params` - 已解析的参数列表
+    /// `rest` - 已解析的剩余参数
+    /// # Returns
+    /// 返回箭头函数节点
+    fn parse_arrow_function(
+        &mut self,
+        params: Vec<Pattern>,
+        rest: Option<Box<Pattern>>,
+    ) -> ParseResult<Expr> {
+        self.cursor.expect(&Token::ArrowFunction)?;
+
+        let body = match self.cursor.peek() {
+            Token::Operator('{') => ArrowBody::Block(self.parse_function_body()?),
+            _ => ArrowBody::Expr(Box::new(self.parse_assignment_expression()?)),
+        };
+
+        Ok(Expr::Arrow { params, rest, body })
+    }
+
+    /// 解析 ArrayLiteral
+    ///
+    /// ArrayLiteral ::
+    ///     `[` ElementList [`,`] `]`
+    ///
+    /// # Returns
+    /// 返回数组字面量节点
+    fn parse_array_literal(&mut self) -> ParseResult<Expr> {
+        self.cursor.expect(&Token::Operator('['))?;
+
+        let mut elements = Vec::new();
+        while !self.cursor.eat(&Token::Operator(']'))? {
+            elements.push(self.parse_assignment_expression_cover()?);
+
+            if !matches!(self.cursor.peek(), Token::Operator(']'))
+                && !self.cursor.eat(&Token::Operator(','))?
+            {
+                return Err(self.unexpected(&[",", "]"]));
+            }
         }
+
+        Ok(Expr::Array(elements))
     }
 
-    fn parse_value(&mut self) -> Result<JSValue, parse_error::ParseError> {
-        match self.lexer.current() {
-            Token::Str(val) => Ok(JSValue::Str(val.clone())),
-            _ => Ok(JSValue::Null),
+    /// 解析 ObjectLiteral
+    ///
+    /// ObjectLiteral ::
+    ///     `{` `}`
+    ///     `{` PropertyDefinitionList [`,`] `}`
+    ///
+    /// PropertyDefinition ::
+    ///     IdentifierReference
+    ///     CoverInitializedName
+    ///     PropertyName `:` AssignmentExpression
+    ///     MethodDefinition
+    ///
+    /// CoverInitializedName ::
+    ///     IdentifierReference Initializer
+    ///
+    /// # Returns
+    /// 返回对象字面量节点
+    fn parse_object_literal(&mut self) -> ParseResult<Expr> {
+        self.cursor.expect(&Token::Operator('{'))?;
+
+        let mut properties = Vec::new();
+        while !self.cursor.eat(&Token::Operator('}'))? {
+            properties.push(self.parse_property_definition()?);
+
+            if !matches!(self.cursor.peek(), Token::Operator('}')) {
+                self.cursor.expect(&Token::Operator(','))?;
+            }
         }
+
+        Ok(Expr::Object(properties))
     }
+
+    /// 解析对象字面量中的 PropertyDefinition
+    ///
+    /// # Returns
+    /// 返回属性节点
+    fn parse_property_definition(&mut self) -> ParseResult<Property> {
+        let mut kind = MethodKind::Method;
+        if self.is_contextual("get") && !self.is_member_name_end()? {
+            self.cursor.bump()?;
+            kind = MethodKind::Getter;
+        } else if self.is_contextual("set") && !self.is_member_name_end()? {
+            self.cursor.bump()?;
+            kind = MethodKind::Setter;
+        }
+
+        // 对象字面量的属性名不能是 PrivateIdentifier
+        if matches!(self.cursor.peek(), Token::PrivateIdentifier(..)) {
+            return Err(self.unexpected(&[]));
+        }
+        let shorthand = matches!(self.cursor.peek(), Token::IdentifierName(..));
+        let key = self.parse_property_key()?;
+
+        if matches!(self.cursor.peek(), Token::Operator('(')) {
+            return Ok(Property::Method {
+                key,
+                kind,
+                value: self.with_super(true, false, |p| p.parse_method_function())?,
+            });
+        }
+        if kind != MethodKind::Method {
+            return Err(self.unexpected(&["("]));
+        }
+
+        if self.cursor.eat(&Token::Operator(':'))? {
+            return Ok(Property::Init {
+                key,
+                value: self.parse_assignment_expression_cover()?,
+                shorthand: false,
+            });
+        }
+        let PropertyKey::Identifier(name) = key else {
+            return Err(self.unexpected(&[":"]));
+        };
+        if !shorthand {
+            return Err(self.unexpected(&[":"]));
+        }
+
+        let mut value = Expr::Identifier(name.clone());
+        if matches!(self.cursor.peek(), Token::Operator('=')) {
+            // CoverInitializedName，仅在对象字面量被重新解释为绑定模式时合法
+            let err = self.unexpected(&[":"]);
+            self.cursor.bump()?;
+            value = Expr::Assign {
+                op: None,
+                target: Box::new(value),
+                value: Box::new(self.parse_assignment_expression()?),
+            };
+            self.cover_initializer.get_or_insert(err);
+        }
+
+        Ok(Property::Init {
+            key: PropertyKey::Identifier(name),
+            value,
+            shorthand: true,
+        })
+    }
+
+    /// 解析以 `import` 开头的表达式
+    ///
+    /// ImportMeta ::
+    ///     `import` `.` `meta`
+    ///
+    /// ImportCall ::
+    ///     `import` `(` AssignmentExpression `)`
+    ///
+    /// `import.meta` 仅允许出现在 Module 中
+    ///
+    /// # Returns
+    /// 返回 MetaProperty 或 ImportCall 表达式节点
+    fn parse_import_expression(&mut self) -> ParseResult<Expr> {
+        self.cursor.expect(&Token::Import)?;
+
+        match self.cursor.peek() {
+            Token::Operator('.') => {
+                self.cursor.bump()?;
+                if !matches!(self.cursor.peek(), Token::IdentifierName(name) if name == "meta") {
+                    return Err(self.unexpected(&["meta"]));
+                }
+                if self.source_type != SourceType::Module {
+                    return Err(self.unexpected(&[]));
+                }
+                self.cursor.bump()?;
+
+                Ok(Expr::MetaProperty {
+                    meta: "import".to_string(),
+                    property: "meta".to_string(),
+                })
+            }
+            Token::Operator('(') => {
+                self.cursor.bump()?;
+                let specifier = self.parse_assignment_expression()?;
+                self.cursor.expect(&Token::Operator(')'))?;
+
+                Ok(Expr::ImportCall(Box::new(specifier)))
+            }
+            _ => Err(self.unexpected(&[".", "("])),
+        }
+    }
+
+    /// 解析类声明或类表达式
+    ///
+    /// ClassDeclaration ::
+    ///     `class` BindingIdentifier ClassTail
+    ///
+    /// ClassExpression ::
+    ///     `class` [BindingIdentifier] ClassTail
+    ///
+    /// ClassTail ::
+    ///     [ClassHeritage] `{` [ClassBody] `}`
+    ///
+    /// ClassHeritage ::
+    ///     `extends` LeftHandSideExpression
+    ///
+    /// # Arguments
+    /// `is_declaration` - 是否为类声明，类声明必须具有类名
+    /// # Returns
+    /// 返回类节点
+    fn parse_class(&mut self, is_declaration: bool) -> ParseResult<Class> {
+        self.cursor.expect(&Token::Class)?;
+
+        let name = match self.cursor.peek() {
+            Token::IdentifierName(..) => Some(self.expect_identifier()?),
+            _ if is_declaration => return Err(self.unexpected(&["identifier"])),
+            _ => None,
+        };
+        let super_class = if self.cursor.eat(&Token::Extends)? {
+            Some(Box::new(self.parse_left_hand_side_expression()?))
+        } else {
+            None
+        };
+
+        self.cursor.expect(&Token::Operator('{'))?;
+        let mut body = Vec::new();
+        while !self.cursor.eat(&Token::Operator('}'))? {
+            if self.cursor.eat(&Token::Operator(';'))? {
+                continue;
+            }
+            body.push(self.parse_class_element(super_class.is_some())?);
+        }
+
+        Ok(Class {
+            name,
+            super_class,
+            body,
+        })
+    }
+
+    /// 解析 ClassElement
+    ///
+    /// ClassElement ::
+    ///     MethodDefinition
+    ///     `static` MethodDefinition
+    ///     FieldDefinition `;`
+    ///     `static` FieldDefinition `;`
+    ///
+    /// MethodDefinition ::
+    ///     ClassElementName `(` UniqueFormalParameters `)` `{` FunctionBody `}`
+    ///     `get` ClassElementName `(` `)` `{` FunctionBody `}`
+    ///     `set` ClassElementName `(` PropertySetParameterList `)` `{` FunctionBody `}`
+    ///
+    /// 方法与字段初始值中允许 `super.prop`，派生类的构造函数中还允许 `super(args)`
+    ///
+    /// FieldDefinition ::
+    ///     ClassElementName [Initializer]
+    ///
+    /// `static`、`get`、`set` 之后若紧跟 `(`、`=`、`;`、`}`，则其本身即为成员名
+    ///
+    /// # Arguments
+    /// `is_derived` - 所在的类是否具有 `extends` 子句
+    /// # Returns
+    /// 返回类成员节点
+    fn parse_class_element(&mut self, is_derived: bool) -> ParseResult<ClassMember> {
+        let is_static = self.is_contextual("static") && !self.is_member_name_end()?;
+        if is_static {
+            self.cursor.bump()?;
+        }
+
+        let mut kind = MethodKind::Method;
+        if self.is_contextual("get") && !self.is_member_name_end()? {
+            self.cursor.bump()?;
+            kind = MethodKind::Getter;
+        } else if self.is_contextual("set") && !self.is_member_name_end()? {
+            self.cursor.bump()?;
+            kind = MethodKind::Setter;
+        }
+
+        let key = self.parse_property_key()?;
+
+        if matches!(self.cursor.peek(), Token::Operator('(')) {
+            if kind == MethodKind::Method
+                && !is_static
+                && matches!(&key, PropertyKey::Identifier(name) if name == "constructor")
+            {
+                kind = MethodKind::Constructor;
+            }
+            let super_call = is_derived && kind == MethodKind::Constructor;
+            let value = self.with_super(true, super_call, |p| p.parse_method_function())?;
+
+            return Ok(ClassMember::Method {
+                key,
+                kind,
+                is_static,
+                value,
+            });
+        }
+        if kind != MethodKind::Method {
+            return Err(self.unexpected(&["("]));
+        }
+
+        let value = if self.cursor.eat(&Token::Operator('='))? {
+            Some(self.with_super(true, false, |p| p.parse_assignment_expression())?)
+        } else {
+            None
+        };
+        self.consume_semicolon()?;
+
+        Ok(ClassMember::Field {
+            key,
+            is_static,
+            value,
+        })
+    }
+
+    /// 游标之后的 Token 是否表明游标指向的 Token 本身即为成员名
+    ///
+    /// 用于区分 `static x` 与名为 `static` 的成员，`get x()` 与名为 `get` 的方法，
+    /// 以及对象字面量中的 `{ get: 1 }` 与 `{ get }`
+    fn is_member_name_end(&mut self) -> ParseResult<bool> {
+        Ok(matches!(
+            self.cursor.peek_next()?,
+            Token::Operator('(' | '=' | ';' | '}' | ':' | ',')
+        ))
+    }
+
+    /// 解析属性名
+    ///
+    /// PropertyName ::
+    ///     LiteralPropertyName
+    ///     ComputedPropertyName
+    ///
+    /// LiteralPropertyName ::
+    ///     IdentifierName
+    ///     StringLiteral
+    ///     NumericLiteral
+    ///
+    /// ComputedPropertyName ::
+    ///     `[` AssignmentExpression `]`
+    ///
+    /// ClassElementName ::
+    ///     PropertyName
+    ///     PrivateIdentifier
+    ///
+    /// # Returns
+    /// 返回属性名节点
+    fn parse_property_key(&mut self) -> ParseResult<PropertyKey> {
+        let key = match self.cursor.peek() {
+            Token::PrivateIdentifier(name) => PropertyKey::Private(name.clone()),
+            Token::Str(val) => PropertyKey::Literal(JSValue::Str(val.clone())),
+            Token::Number(val) => PropertyKey::Literal(self.numeric_literal(val)?),
+            Token::Operator('[') => {
+                self.cursor.bump()?;
+                let expr = self.parse_assignment_expression()?;
+                self.cursor.expect(&Token::Operator(']'))?;
+
+                return Ok(PropertyKey::Computed(Box::new(expr)));
+            }
+            _ => return Ok(PropertyKey::Identifier(self.expect_identifier_name()?)),
+        };
+        self.cursor.bump()?;
+
+        Ok(key)
+    }
+
+    /// 解析方法的参数列表与函数体
+    ///
+    /// # Returns
+    /// 返回匿名函数节点
+    fn parse_method_function(&mut self) -> ParseResult<Function> {
+        let (params, rest) = self.parse_formal_parameters()?;
+        let body = self.parse_function_body()?;
+
+        Ok(Function {
+            name: None,
+            params,
+            rest,
+            body,
+        })
+    }
+
+    /// 解析函数声明或函数表达式
+    ///
+    /// FunctionDeclaration ::
+    ///     `function` BindingIdentifier `(` FormalParameters `)` `{` FunctionBody `}`
+    ///
+    /// FunctionExpression ::
+    ///     `function` [BindingIdentifier] `(` FormalParameters `)` `{` FunctionBody `}`
+    ///
+    /// # Arguments
+    /// `is_declaration` - 是否为函数声明，函数声明必须具有函数名
+    /// # Returns
+    /// 返回函数节点
+    fn parse_function(&mut self, is_declaration: bool) -> ParseResult<Function> {
+        self.cursor.expect(&Token::Function)?;
+
+        let name = match self.cursor.peek() {
+            Token::IdentifierName(..) => Some(self.expect_identifier()?),
+            _ if is_declaration => return Err(self.unexpected(&["identifier"])),
+            _ => None,
+        };
+        let (params, rest, body) = self.with_super(false, false, |p| {
+            let (params, rest) = p.parse_formal_parameters()?;
+            Ok((params, rest, p.parse_function_body()?))
+        })?;
+
+        Ok(Function {
+            name,
+            params,
+            rest,
+            body,
+        })
+    }
+
+    /// 解析函数参数列表
+    ///
+    /// FormalParameters ::
+    ///     `(` `)`
+    ///     `(` FunctionRestParameter `)`
+    ///     `(` FormalParameterList `)`
+    ///     `(` FormalParameterList `,` `)`
+    ///     `(` FormalParameterList `,` FunctionRestParameter `)`
+    ///
+    /// FormalParameter ::
+    ///     BindingElement
+    ///
+    /// FunctionRestParameter ::
+    ///     `...` BindingIdentifier
+    ///     `...` BindingPattern
+    ///
+    /// # Returns
+    /// 返回参数列表与剩余参数
+    fn parse_formal_parameters(&mut self) -> ParseResult<(Vec<Pattern>, Option<Box<Pattern>>)> {
+        self.cursor.expect(&Token::Operator('('))?;
+
+        let mut params = Vec::new();
+        let mut rest = None;
+        while !self.cursor.eat(&Token::Operator(')'))? {
+            if self.cursor.eat(&Token::Spread)? {
+                rest = Some(Box::new(self.parse_binding_target()?));
+                self.cursor.expect(&Token::Operator(')'))?;
+                break;
+            }
+            params.push(self.parse_binding_element()?);
+
+            if !matches!(self.cursor.peek(), Token::Operator(')')) {
+                self.cursor.expect(&Token::Operator(','))?;
+            }
+        }
+
+        Ok((params, rest))
+    }
+
+    /// 解析 BindingElement
+    ///
+    /// BindingElement ::
+    ///     BindingIdentifier [Initializer]
+    ///     BindingPattern [Initializer]
+    ///
+    /// # Returns
+    /// 返回绑定模式节点
+    fn parse_binding_element(&mut self) -> ParseResult<Pattern> {
+        let target = self.parse_binding_target()?;
+        self.parse_binding_initializer(target)
+    }
+
+    /// 若存在 Initializer，则将绑定模式包装为带默认值的绑定
+    ///
+    /// # Arguments
+    /// `target` - 绑定模式
+    /// # Returns
+    /// 返回绑定模式节点
+    fn parse_binding_initializer(&mut self, target: Pattern) -> ParseResult<Pattern> {
+        if !self.cursor.eat(&Token::Operator('='))? {
+            return Ok(target);
+        }
+
+        Ok(Pattern::Assign {
+            target: Box::new(target),
+            value: Box::new(self.parse_assignment_expression()?),
+        })
+    }
+
+    /// 解析不带 Initializer 的绑定目标
+    ///
+    /// BindingPattern ::
+    ///     ObjectBindingPattern
+    ///     ArrayBindingPattern
+    ///
+    /// # Returns
+    /// 返回绑定模式节点
+    fn parse_binding_target(&mut self) -> ParseResult<Pattern> {
+        match self.cursor.peek() {
+            Token::Operator('{') => self.parse_object_binding_pattern(),
+            Token::Operator('[') => self.parse_array_binding_pattern(),
+            _ => Ok(Pattern::Identifier(self.expect_identifier()?)),
+        }
+    }
+
+    /// 解析 ObjectBindingPattern
+    ///
+    /// ObjectBindingPattern ::
+    ///     `{` `}`
+    ///     `{` BindingRestProperty `}`
+    ///     `{` BindingPropertyList [`,`] `}`
+    ///     `{` BindingPropertyList `,` BindingRestProperty `}`
+    ///
+    /// BindingProperty ::
+    ///     SingleNameBinding
+    ///     PropertyName `:` BindingElement
+    ///
+    /// BindingRestProperty ::
+    ///     `...` BindingIdentifier
+    ///
+    /// # Returns
+    /// 返回对象解构节点
+    fn parse_object_binding_pattern(&mut self) -> ParseResult<Pattern> {
+        self.cursor.expect(&Token::Operator('{'))?;
+
+        let mut properties = Vec::new();
+        let mut rest = None;
+        while !self.cursor.eat(&Token::Operator('}'))? {
+            if self.cursor.eat(&Token::Spread)? {
+                rest = Some(Box::new(Pattern::Identifier(self.expect_identifier()?)));
+                self.cursor.expect(&Token::Operator('}'))?;
+                break;
+            }
+
+            let shorthand = matches!(self.cursor.peek(), Token::IdentifierName(..));
+            let key = self.parse_property_key()?;
+            let value = if self.cursor.eat(&Token::Operator(':'))? {
+                self.parse_binding_element()?
+            } else {
+                // SingleNameBinding，属性名即为绑定的标识符
+                match &key {
+                    PropertyKey::Identifier(name) if shorthand => {
+                        self.parse_binding_initializer(Pattern::Identifier(name.clone()))?
+                    }
+                    _ => return Err(self.unexpected(&[":"])),
+                }
+            };
+            properties.push((key, value));
+
+            if !matches!(self.cursor.peek(), Token::Operator('}')) {
+                self.cursor.expect(&Token::Operator(','))?;
+            }
+        }
+
+        Ok(Pattern::Object { properties, rest })
+    }
+
+    /// 解析 ArrayBindingPattern
+    ///
+    /// ArrayBindingPattern ::
+    ///     `[` [Elision] [BindingRestElement] `]`
+    ///     `[` BindingElementList `]`
+    ///     `[` BindingElementList `,` [Elision] [BindingRestElement] `]`
+    ///
+    /// BindingRestElement ::
+    ///     `...` BindingIdentifier
+    ///     `...` BindingPattern
+    ///
+    /// # Returns
+    /// 返回数组解构节点
+    fn parse_array_binding_pattern(&mut self) -> ParseResult<Pattern> {
+        self.cursor.expect(&Token::Operator('['))?;
+
+        let mut elements = Vec::new();
+        let mut rest = None;
+        while !self.cursor.eat(&Token::Operator(']'))? {
+            if self.cursor.eat(&Token::Operator(','))? {
+                elements.push(None);
+                continue;
+            }
+            if self.cursor.eat(&Token::Spread)? {
+                rest = Some(Box::new(self.parse_binding_target()?));
+                self.cursor.expect(&Token::Operator(']'))?;
+                break;
+            }
+            elements.push(Some(self.parse_binding_element()?));
+
+            if !matches!(self.cursor.peek(), Token::Operator(']')) {
+                self.cursor.expect(&Token::Operator(','))?;
+            }
+        }
+
+        Ok(Pattern::Array { elements, rest })
+    }
+
+    /// 解析函数体
+    ///
+    /// FunctionBody ::
+    ///     `{` [FunctionStatementList] `}`
+    ///
+    /// 函数体是自包含的结构，其内部总是允许 `in` 运算符，
+    /// 即使箭头函数出现在 for 语句的初始化部分中。函数体内允许 `return` 语句
+    ///
+    /// # Returns
+    /// 返回函数体内的语句列表
+    fn parse_function_body(&mut self) -> ParseResult<Vec<Stmt>> {
+        self.cursor.expect(&Token::Operator('{'))?;
+
+        let allow_return = std::mem::replace(&mut self.allow_return, true);
+        let body = self.with_allow_in(true, |p| {
+            let mut body = Vec::new();
+            while !p.cursor.eat(&Token::Operator('}'))? {
+                body.push(p.parse_statement_list_item()?);
+            }
+
+            Ok(body)
+        });
+        self.allow_return = allow_return;
+
+        body
+    }
+}
+
+/// 解析一段 ECMAScript 源码
+///
+/// 目前支持的语句包括变量声明、函数与类声明、表达式语句、语句块、空语句、
+/// `if`、`while`、`do-while`、`for`（含 for-in/for-of）、`return`、`debugger`，
+/// 以及 Module 中的 `import`/`export` 声明；`switch`、`try`、`throw`、`break`/`continue`、
+/// 标签语句、`with` 等尚不支持，遇到时返回语法错误。
+/// 表达式中尚不支持 `++`/`--`、`?.`、模板字符串中的替换、`yield`/`await` 与 `new.target`
+///
+/// # Arguments
+/// `source` - ECMAScript 源码
+/// `source_type` - 源码类型
+/// # Returns
+/// 返回语法树的根节点
+pub(crate) fn parse(source: &str, source_type: SourceType) -> ParseResult<Program> {
+    let mut reader = InlineSourceReader::new(source);
+
+    Parser::with_source_type(&mut reader, source_type)?.parse_program()
+}
+
+/// 判断两个二元运算符是否为未加括号时不能直接混用的 `??` 与 `||`、`&&`
+const fn mixes_coalesce(op: BinaryOp, operand_op: BinaryOp) -> bool {
+    matches!(
+        (op, operand_op),
+        (
+            BinaryOp::Coalesce,
+            BinaryOp::LogicalOr | BinaryOp::LogicalAnd
+        ) | (
+            BinaryOp::LogicalOr | BinaryOp::LogicalAnd,
+            BinaryOp::Coalesce
+        )
+    )
 }
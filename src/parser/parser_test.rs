@@ -0,0 +1,1597 @@
+use crate::{
+    lexer::{InlineSourceReader, Token},
+    vals::JSValue,
+};
+
+use super::{
+    ast::{
+        ArrowBody, BinaryOp, Class, ClassMember, ExportSpecifier, Expr, ForInit, Function,
+        ImportSpecifier, MethodKind, Pattern, Program, Property, PropertyKey, Stmt, UnaryOp,
+        VarDeclarator, VarKind,
+    },
+    parse_error::ParseError,
+    parser::{parse, Parser, SourceType},
+};
+
+#[test]
+fn test_Parser_parse_import_meta() {
+    let mut src = InlineSourceReader::new(r#"import.meta.url"#);
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Module).unwrap();
+
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::Member {
+            object: Box::new(Expr::MetaProperty {
+                meta: "import".to_string(),
+                property: "meta".to_string(),
+            }),
+            property: Box::new(Expr::Identifier("url".to_string())),
+            computed: false,
+        }
+    );
+
+    let mut src = InlineSourceReader::new(r#"import.meta"#);
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Script).unwrap();
+    assert!(parser.parse_expression().is_err());
+
+    let mut src = InlineSourceReader::new(r#"import.foo"#);
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Module).unwrap();
+    assert!(parser.parse_expression().is_err());
+}
+
+#[test]
+fn test_Parser_parse_import_call() {
+    let mut src = InlineSourceReader::new(r#"import("./mod.js")"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::ImportCall(Box::new(Expr::Literal(JSValue::Str(
+            "./mod.js".to_string()
+        ))))
+    );
+
+    let mut src = InlineSourceReader::new(r#"import(name).then(cb)"#);
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Module).unwrap();
+    assert!(matches!(
+        parser.parse_expression().unwrap(),
+        Expr::Call { callee, arguments } if arguments.len() == 1 && matches!(
+            callee.as_ref(),
+            Expr::Member { object, .. } if matches!(object.as_ref(), Expr::ImportCall(..))
+        )
+    ));
+
+    let mut src = InlineSourceReader::new(r#"import"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert!(parser.parse_expression().is_err());
+}
+
+#[test]
+fn test_Parser_parse_export_named() {
+    let mut src = InlineSourceReader::new(
+        r#"export const x = 1;
+        export { a, b as default }"#,
+    );
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Module).unwrap();
+
+    assert_eq!(
+        parser.parse_module_item().unwrap(),
+        Stmt::ExportDecl(Box::new(Stmt::VarDecl {
+            kind: VarKind::Const,
+            declarations: vec![VarDeclarator {
+                name: "x".to_string(),
+                init: Some(Expr::Literal(JSValue::Int(1))),
+            }],
+        }))
+    );
+    assert_eq!(
+        parser.parse_module_item().unwrap(),
+        Stmt::ExportNamed {
+            specifiers: vec![
+                ExportSpecifier {
+                    local: "a".to_string(),
+                    exported: "a".to_string(),
+                },
+                ExportSpecifier {
+                    local: "b".to_string(),
+                    exported: "default".to_string(),
+                },
+            ],
+            source: None,
+        }
+    );
+}
+
+#[test]
+fn test_Parser_parse_export_default() {
+    let mut src = InlineSourceReader::new(r#"export default foo.bar"#);
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Module).unwrap();
+
+    assert!(matches!(
+        parser.parse_module_item().unwrap(),
+        Stmt::ExportDefault(Expr::Member { .. })
+    ));
+
+    let mut src = InlineSourceReader::new(r#"export default foo"#);
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Script).unwrap();
+    assert!(parser.parse_module_item().is_err());
+
+    // `default` 之后的函数与类按声明解析，其后是另一条语句
+    let program = parse("export default function f() {} (1);", SourceType::Module).unwrap();
+    assert!(matches!(
+        &program.body[..],
+        [
+            Stmt::ExportDefault(Expr::Function(function)),
+            Stmt::Expr(Expr::Literal(JSValue::Int(1))),
+        ] if function.name.as_deref() == Some("f")
+    ));
+
+    let program = parse("export default function () {}\n/x/g", SourceType::Module).unwrap();
+    assert!(matches!(
+        &program.body[..],
+        [
+            Stmt::ExportDefault(Expr::Function(function)),
+            Stmt::Expr(Expr::Regex { .. }),
+        ] if function.name.is_none()
+    ));
+
+    let program = parse("export default class {}\n[a];", SourceType::Module).unwrap();
+    assert!(matches!(
+        &program.body[..],
+        [
+            Stmt::ExportDefault(Expr::Class(class)),
+            Stmt::Expr(Expr::Array(..)),
+        ] if class.name.is_none()
+    ));
+
+    let program = parse("export default (function () {})(1);", SourceType::Module).unwrap();
+    assert!(matches!(
+        &program.body[..],
+        [Stmt::ExportDefault(Expr::Call { .. })]
+    ));
+}
+
+#[test]
+fn test_Parser_parse_export_from() {
+    let mut src = InlineSourceReader::new(
+        r#"export { a as b } from "mod";
+        export * from "mod";
+        export * as ns from "mod";"#,
+    );
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Module).unwrap();
+
+    assert_eq!(
+        parser.parse_module_item().unwrap(),
+        Stmt::ExportNamed {
+            specifiers: vec![ExportSpecifier {
+                local: "a".to_string(),
+                exported: "b".to_string(),
+            }],
+            source: Some("mod".to_string()),
+        }
+    );
+    assert_eq!(
+        parser.parse_module_item().unwrap(),
+        Stmt::ExportAll {
+            exported: None,
+            source: "mod".to_string(),
+        }
+    );
+    assert_eq!(
+        parser.parse_module_item().unwrap(),
+        Stmt::ExportAll {
+            exported: Some("ns".to_string()),
+            source: "mod".to_string(),
+        }
+    );
+
+    let mut src = InlineSourceReader::new(r#"export * from mod"#);
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Module).unwrap();
+    assert!(parser.parse_module_item().is_err());
+}
+
+#[test]
+fn test_Parser_parse_import_declaration() {
+    let mut src = InlineSourceReader::new(
+        r#"import def from "mod";
+        import { a, b as c } from "mod";
+        import * as ns from "mod";
+        import def2, { d } from "mod";
+        import "mod";
+        import("mod");"#,
+    );
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Module).unwrap();
+
+    assert_eq!(
+        parser.parse_module_item().unwrap(),
+        Stmt::Import {
+            default: Some("def".to_string()),
+            namespace: None,
+            specifiers: vec![],
+            source: "mod".to_string(),
+        }
+    );
+    assert_eq!(
+        parser.parse_module_item().unwrap(),
+        Stmt::Import {
+            default: None,
+            namespace: None,
+            specifiers: vec![
+                ImportSpecifier {
+                    imported: "a".to_string(),
+                    local: "a".to_string(),
+                },
+                ImportSpecifier {
+                    imported: "b".to_string(),
+                    local: "c".to_string(),
+                },
+            ],
+            source: "mod".to_string(),
+        }
+    );
+    assert_eq!(
+        parser.parse_module_item().unwrap(),
+        Stmt::Import {
+            default: None,
+            namespace: Some("ns".to_string()),
+            specifiers: vec![],
+            source: "mod".to_string(),
+        }
+    );
+    assert_eq!(
+        parser.parse_module_item().unwrap(),
+        Stmt::Import {
+            default: Some("def2".to_string()),
+            namespace: None,
+            specifiers: vec![ImportSpecifier {
+                imported: "d".to_string(),
+                local: "d".to_string(),
+            }],
+            source: "mod".to_string(),
+        }
+    );
+    assert_eq!(
+        parser.parse_module_item().unwrap(),
+        Stmt::Import {
+            default: None,
+            namespace: None,
+            specifiers: vec![],
+            source: "mod".to_string(),
+        }
+    );
+    assert!(matches!(
+        parser.parse_module_item().unwrap(),
+        Stmt::Expr(Expr::ImportCall(..))
+    ));
+}
+
+#[test]
+fn test_Parser_parse_import_declaration_not_top_level() {
+    let mut src = InlineSourceReader::new(r#"{ import a from "mod"; }"#);
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Module).unwrap();
+    assert!(parser.parse_module_item().is_err());
+
+    let mut src = InlineSourceReader::new(r#"import a from "mod";"#);
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Script).unwrap();
+    assert!(parser.parse_module_item().is_err());
+
+    let mut src = InlineSourceReader::new(r#"import { a b } from "mod";"#);
+    let mut parser = Parser::with_source_type(&mut src, SourceType::Module).unwrap();
+    assert!(parser.parse_module_item().is_err());
+}
+
+#[test]
+fn test_Parser_parse_debugger_statement() {
+    let mut src = InlineSourceReader::new(
+        r#"debugger;
+        debugger
+        foo
+        debugger foo"#,
+    );
+    let mut parser = Parser::new(&mut src).unwrap();
+
+    assert_eq!(parser.parse_statement_list_item().unwrap(), Stmt::Debugger);
+    assert_eq!(parser.parse_statement_list_item().unwrap(), Stmt::Debugger);
+    assert_eq!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::Expr(Expr::Identifier("foo".to_string()))
+    );
+    assert!(parser.parse_statement_list_item().is_err());
+}
+
+#[test]
+fn test_Parser_parse_class_declaration() {
+    let mut src = InlineSourceReader::new(
+        r#"class A extends B {
+            static count = 0;
+            #secret;
+            constructor(a, b) {}
+            get value() { this.#secret }
+            static #reset() {}
+            static() {}
+        }"#,
+    );
+    let mut parser = Parser::new(&mut src).unwrap();
+
+    let empty = Function {
+        name: None,
+        params: vec![],
+        rest: None,
+        body: vec![],
+    };
+    assert_eq!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::Class(Class {
+            name: Some("A".to_string()),
+            super_class: Some(Box::new(Expr::Identifier("B".to_string()))),
+            body: vec![
+                ClassMember::Field {
+                    key: PropertyKey::Identifier("count".to_string()),
+                    is_static: true,
+                    value: Some(Expr::Literal(JSValue::Int(0))),
+                },
+                ClassMember::Field {
+                    key: PropertyKey::Private("#secret".to_string()),
+                    is_static: false,
+                    value: None,
+                },
+                ClassMember::Method {
+                    key: PropertyKey::Identifier("constructor".to_string()),
+                    kind: MethodKind::Constructor,
+                    is_static: false,
+                    value: Function {
+                        name: None,
+                        params: vec![
+                            Pattern::Identifier("a".to_string()),
+                            Pattern::Identifier("b".to_string()),
+                        ],
+                        rest: None,
+                        body: vec![],
+                    },
+                },
+                ClassMember::Method {
+                    key: PropertyKey::Identifier("value".to_string()),
+                    kind: MethodKind::Getter,
+                    is_static: false,
+                    value: Function {
+                        name: None,
+                        params: vec![],
+                        rest: None,
+                        body: vec![Stmt::Expr(Expr::Member {
+                            object: Box::new(Expr::This),
+                            property: Box::new(Expr::PrivateName("#secret".to_string())),
+                            computed: false,
+                        })],
+                    },
+                },
+                ClassMember::Method {
+                    key: PropertyKey::Private("#reset".to_string()),
+                    kind: MethodKind::Method,
+                    is_static: true,
+                    value: empty.clone(),
+                },
+                ClassMember::Method {
+                    key: PropertyKey::Identifier("static".to_string()),
+                    kind: MethodKind::Method,
+                    is_static: false,
+                    value: empty,
+                },
+            ],
+        })
+    );
+
+    let mut src = InlineSourceReader::new(r#"class {}"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert!(parser.parse_statement_list_item().is_err());
+
+    let mut src = InlineSourceReader::new(r#"class A { get x = 1 }"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert!(parser.parse_statement_list_item().is_err());
+}
+
+#[test]
+fn test_Parser_parse_class_expression() {
+    let mut src = InlineSourceReader::new(r#"let A = class {}"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+
+    assert_eq!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::VarDecl {
+            kind: VarKind::Let,
+            declarations: vec![VarDeclarator {
+                name: "A".to_string(),
+                init: Some(Expr::Class(Box::new(Class {
+                    name: None,
+                    super_class: None,
+                    body: vec![],
+                }))),
+            }],
+        }
+    );
+}
+
+#[test]
+fn test_Parser_parse_function_parameters() {
+    let mut src =
+        InlineSourceReader::new(r#"function f({ a = 1, b: { c } = {} }, [d = 2] = []) {}"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+
+    assert_eq!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::Function(Function {
+            name: Some("f".to_string()),
+            params: vec![
+                Pattern::Object {
+                    properties: vec![
+                        (
+                            PropertyKey::Identifier("a".to_string()),
+                            Pattern::Assign {
+                                target: Box::new(Pattern::Identifier("a".to_string())),
+                                value: Box::new(Expr::Literal(JSValue::Int(1))),
+                            },
+                        ),
+                        (
+                            PropertyKey::Identifier("b".to_string()),
+                            Pattern::Assign {
+                                target: Box::new(Pattern::Object {
+                                    properties: vec![(
+                                        PropertyKey::Identifier("c".to_string()),
+                                        Pattern::Identifier("c".to_string()),
+                                    )],
+                                    rest: None,
+                                }),
+                                value: Box::new(Expr::Object(vec![])),
+                            },
+                        ),
+                    ],
+                    rest: None,
+                },
+                Pattern::Assign {
+                    target: Box::new(Pattern::Array {
+                        elements: vec![Some(Pattern::Assign {
+                            target: Box::new(Pattern::Identifier("d".to_string())),
+                            value: Box::new(Expr::Literal(JSValue::Int(2))),
+                        })],
+                        rest: None,
+                    }),
+                    value: Box::new(Expr::Array(vec![])),
+                },
+            ],
+            rest: None,
+            body: vec![],
+        })
+    );
+
+    let mut src = InlineSourceReader::new(r#"function f(a, ...[b, ...c]) {}"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert!(matches!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::Function(Function { params, rest: Some(rest), .. })
+            if params.len() == 1 && matches!(rest.as_ref(), Pattern::Array { rest: Some(..), .. })
+    ));
+
+    for source in [
+        r#"function f(...a, b) {}"#,
+        r#"function f([...a, b]) {}"#,
+        r#"function f({ ...a, b }) {}"#,
+        r#"function f({ if }) {}"#,
+        r#"function () {}"#,
+    ] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert!(parser.parse_statement_list_item().is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_binary_expression() {
+    let mut src = InlineSourceReader::new(r#"a + b * c ** d ** e"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+
+    let ident = |name: &str| Box::new(Expr::Identifier(name.to_string()));
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::Binary {
+            op: BinaryOp::Add,
+            left: ident("a"),
+            right: Box::new(Expr::Binary {
+                op: BinaryOp::Mul,
+                left: ident("b"),
+                right: Box::new(Expr::Binary {
+                    op: BinaryOp::Exp,
+                    left: ident("c"),
+                    right: Box::new(Expr::Binary {
+                        op: BinaryOp::Exp,
+                        left: ident("d"),
+                        right: ident("e"),
+                    }),
+                }),
+            }),
+        }
+    );
+
+    let mut src = InlineSourceReader::new(r#"(a - b) - c"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::Binary {
+            op: BinaryOp::Sub,
+            left: Box::new(Expr::Binary {
+                op: BinaryOp::Sub,
+                left: ident("a"),
+                right: ident("b"),
+            }),
+            right: ident("c"),
+        }
+    );
+}
+
+#[test]
+fn test_Parser_parse_binary_expression_precedence() {
+    let num = |val: i64| Box::new(Expr::Literal(JSValue::Int(val)));
+    let ident = |name: &str| Box::new(Expr::Identifier(name.to_string()));
+    let binary = |op, left, right| Box::new(Expr::Binary { op, left, right });
+
+    for (source, expected) in [
+        (
+            r#"1 + 2 * 3"#,
+            binary(BinaryOp::Add, num(1), binary(BinaryOp::Mul, num(2), num(3))),
+        ),
+        (
+            r#"1 * 2 + 3"#,
+            binary(BinaryOp::Add, binary(BinaryOp::Mul, num(1), num(2)), num(3)),
+        ),
+        (
+            r#"2 ** 3 ** 2"#,
+            binary(BinaryOp::Exp, num(2), binary(BinaryOp::Exp, num(3), num(2))),
+        ),
+        (
+            r#"a / b % c"#,
+            binary(
+                BinaryOp::Mod,
+                binary(BinaryOp::Div, ident("a"), ident("b")),
+                ident("c"),
+            ),
+        ),
+        (
+            r#"a << 1 < b >>> 2"#,
+            binary(
+                BinaryOp::Less,
+                binary(BinaryOp::Shl, ident("a"), num(1)),
+                binary(BinaryOp::UShr, ident("b"), num(2)),
+            ),
+        ),
+        (
+            r#"a | b ^ c & d"#,
+            binary(
+                BinaryOp::BitOr,
+                ident("a"),
+                binary(
+                    BinaryOp::BitXor,
+                    ident("b"),
+                    binary(BinaryOp::BitAnd, ident("c"), ident("d")),
+                ),
+            ),
+        ),
+        (
+            r#"a === b & c"#,
+            binary(
+                BinaryOp::BitAnd,
+                binary(BinaryOp::StrictEqual, ident("a"), ident("b")),
+                ident("c"),
+            ),
+        ),
+        (
+            r#"a || b && c"#,
+            binary(
+                BinaryOp::LogicalOr,
+                ident("a"),
+                binary(BinaryOp::LogicalAnd, ident("b"), ident("c")),
+            ),
+        ),
+        (
+            r#"a ?? b ?? c"#,
+            binary(
+                BinaryOp::Coalesce,
+                binary(BinaryOp::Coalesce, ident("a"), ident("b")),
+                ident("c"),
+            ),
+        ),
+    ] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert_eq!(parser.parse_expression().unwrap(), *expected, "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_coalesce_mixed_with_logical() {
+    for source in [
+        r#"a || b ?? c"#,
+        r#"a ?? b || c"#,
+        r#"a && b ?? c"#,
+        r#"a ?? b && c"#,
+        r#"a ?? b | c && d"#,
+    ] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        let err = parser.parse_expression().unwrap_err();
+        assert!(err.span().is_some(), "{}", source);
+    }
+
+    for source in [
+        r#"(a || b) ?? c"#,
+        r#"a ?? (b && c)"#,
+        r#"(a ?? b) || c"#,
+        r#"a ?? b | c"#,
+    ] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert!(parser.parse_expression().is_ok(), "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_unary_expression() {
+    let mut src = InlineSourceReader::new(r#"-a * !b + typeof -c"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+
+    let ident = |name: &str| Box::new(Expr::Identifier(name.to_string()));
+    let unary = |op, operand| Box::new(Expr::Unary { op, operand });
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::Binary {
+            op: BinaryOp::Add,
+            left: Box::new(Expr::Binary {
+                op: BinaryOp::Mul,
+                left: unary(UnaryOp::Neg, ident("a")),
+                right: unary(UnaryOp::Not, ident("b")),
+            }),
+            right: unary(UnaryOp::TypeOf, unary(UnaryOp::Neg, ident("c"))),
+        }
+    );
+
+    let mut src = InlineSourceReader::new(r#"(-a) ** b"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::Binary {
+            op: BinaryOp::Exp,
+            left: unary(UnaryOp::Neg, ident("a")),
+            right: ident("b"),
+        }
+    );
+
+    for source in [r#"-a ** b"#, r#"void a ** b"#, r#"!"#] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert!(parser.parse_expression().is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_expression_no_in() {
+    let mut src = InlineSourceReader::new(r#"a in b"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_expression_no_in().unwrap(),
+        Expr::Identifier("a".to_string())
+    );
+    assert_eq!(parser.cursor.peek(), &Token::In);
+
+    let mut src = InlineSourceReader::new(r#"for (a in b) {}"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::ForIn {
+            left: ForInit::Expr(Expr::Identifier("a".to_string())),
+            right: Expr::Identifier("b".to_string()),
+            body: Box::new(Stmt::Block(vec![])),
+        }
+    );
+
+    let mut src = InlineSourceReader::new(r#"for (a + b; ;) {}"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::For {
+            init: Some(ForInit::Expr(Expr::Binary {
+                op: BinaryOp::Add,
+                left: Box::new(Expr::Identifier("a".to_string())),
+                right: Box::new(Expr::Identifier("b".to_string())),
+            })),
+            test: None,
+            update: None,
+            body: Box::new(Stmt::Block(vec![])),
+        }
+    );
+
+    let mut src = InlineSourceReader::new(r#"for ((a in b); ;) {}"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert!(matches!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::For {
+            init: Some(ForInit::Expr(Expr::Binary {
+                op: BinaryOp::In,
+                ..
+            })),
+            ..
+        }
+    ));
+
+    let mut src = InlineSourceReader::new(r#"for (let x = f(a in b); x < 3; x) ;"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert!(matches!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::For {
+            init: Some(ForInit::VarDecl {
+                kind: VarKind::Let,
+                ..
+            }),
+            test: Some(..),
+            update: Some(..),
+            ..
+        }
+    ));
+
+    // 箭头函数的函数体内允许 `in`
+    let mut src = InlineSourceReader::new(r#"for (var f = x => { a in b; }; ;) {}"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert!(matches!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::For {
+            init: Some(ForInit::VarDecl { .. }),
+            test: None,
+            ..
+        }
+    ));
+
+    let mut src = InlineSourceReader::new(r#"for (const x of xs) {}"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert!(matches!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::ForOf {
+            left: ForInit::VarDecl {
+                kind: VarKind::Const,
+                ..
+            },
+            ..
+        }
+    ));
+
+    for source in [r#"for (a + b in c) {}"#, r#"for (const x; ;) {}"#] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert!(parser.parse_statement_list_item().is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_object_literal() {
+    let mut src = InlineSourceReader::new(
+        r#"({ x, [k]: 1, "s": 2, m(a) {}, get p() {}, set p(v) {}, get: 3 })"#,
+    );
+    let mut parser = Parser::new(&mut src).unwrap();
+
+    let empty = |params: Vec<Pattern>| Function {
+        name: None,
+        params,
+        rest: None,
+        body: vec![],
+    };
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::Object(vec![
+            Property::Init {
+                key: PropertyKey::Identifier("x".to_string()),
+                value: Expr::Identifier("x".to_string()),
+                shorthand: true,
+            },
+            Property::Init {
+                key: PropertyKey::Computed(Box::new(Expr::Identifier("k".to_string()))),
+                value: Expr::Literal(JSValue::Int(1)),
+                shorthand: false,
+            },
+            Property::Init {
+                key: PropertyKey::Literal(JSValue::Str("s".to_string())),
+                value: Expr::Literal(JSValue::Int(2)),
+                shorthand: false,
+            },
+            Property::Method {
+                key: PropertyKey::Identifier("m".to_string()),
+                kind: MethodKind::Method,
+                value: empty(vec![Pattern::Identifier("a".to_string())]),
+            },
+            Property::Method {
+                key: PropertyKey::Identifier("p".to_string()),
+                kind: MethodKind::Getter,
+                value: empty(vec![]),
+            },
+            Property::Method {
+                key: PropertyKey::Identifier("p".to_string()),
+                kind: MethodKind::Setter,
+                value: empty(vec![Pattern::Identifier("v".to_string())]),
+            },
+            Property::Init {
+                key: PropertyKey::Identifier("get".to_string()),
+                value: Expr::Literal(JSValue::Int(3)),
+                shorthand: false,
+            },
+        ])
+    );
+
+    for source in [
+        r#"({ if })"#,
+        r#"({ "s" })"#,
+        r#"({ #x: 1 })"#,
+        r#"({ get p: 1 })"#,
+    ] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert!(parser.parse_expression().is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_parenthesized_and_arrow() {
+    let ident = |name: &str| Expr::Identifier(name.to_string());
+
+    let mut src = InlineSourceReader::new(r#"(a) => a"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::Arrow {
+            params: vec![Pattern::Identifier("a".to_string())],
+            rest: None,
+            body: ArrowBody::Expr(Box::new(ident("a"))),
+        }
+    );
+
+    let mut src = InlineSourceReader::new(r#"(a, b)"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::Sequence(vec![ident("a"), ident("b")])
+    );
+
+    let mut src = InlineSourceReader::new(r#"x => {}"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::Arrow {
+            params: vec![Pattern::Identifier("x".to_string())],
+            rest: None,
+            body: ArrowBody::Block(vec![]),
+        }
+    );
+
+    let mut src = InlineSourceReader::new(r#"([a], { b }, ...c) => a + b"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::Arrow {
+            params: vec![
+                Pattern::Array {
+                    elements: vec![Some(Pattern::Identifier("a".to_string()))],
+                    rest: None,
+                },
+                Pattern::Object {
+                    properties: vec![(
+                        PropertyKey::Identifier("b".to_string()),
+                        Pattern::Identifier("b".to_string()),
+                    )],
+                    rest: None,
+                },
+            ],
+            rest: Some(Box::new(Pattern::Identifier("c".to_string()))),
+            body: ArrowBody::Expr(Box::new(Expr::Binary {
+                op: BinaryOp::Add,
+                left: Box::new(ident("a")),
+                right: Box::new(ident("b")),
+            })),
+        }
+    );
+
+    let mut src = InlineSourceReader::new(r#"() => 1"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert!(matches!(
+        parser.parse_expression().unwrap(),
+        Expr::Arrow { params, rest: None, .. } if params.is_empty()
+    ));
+
+    for source in [
+        r#"(1) => x"#,
+        r#"(a + b) => x"#,
+        r#"({ m() {} }) => x"#,
+        r#"()"#,
+        r#"(a, )"#,
+        r#"(...a)"#,
+        r#"(...a, b) => x"#,
+    ] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert!(parser.parse_expression().is_err(), "{}", source);
+    }
+
+    // 箭头函数只能作为完整的 AssignmentExpression 出现
+    for source in [r#"1 + a => a;"#, r#"() => {}(1);"#, r#"!(a) => a;"#] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert!(parser.parse_program().is_err(), "{}", source);
+    }
+
+    let mut src = InlineSourceReader::new(r#"f(a => a, 1 + ((b) => b))"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert!(matches!(
+        parser.parse_expression().unwrap(),
+        Expr::Call { arguments, .. } if matches!(arguments[0], Expr::Arrow { .. })
+            && matches!(&arguments[1], Expr::Binary { right, .. } if matches!(**right, Expr::Arrow { .. }))
+    ));
+
+    // `=>` 之前不允许换行
+    let mut src = InlineSourceReader::new("(a)\n=> a");
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(parser.parse_expression().unwrap(), ident("a"));
+    assert_eq!(parser.cursor.peek(), &Token::ArrowFunction);
+}
+
+#[test]
+fn test_Parser_parse_arrow_default_parameters() {
+    let ident = |name: &str| Pattern::Identifier(name.to_string());
+    let default = |target: Pattern, value: i64| Pattern::Assign {
+        target: Box::new(target),
+        value: Box::new(Expr::Literal(JSValue::Int(value))),
+    };
+    let params = |source: &str| {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        match parser.parse_expression().unwrap() {
+            Expr::Arrow { params, .. } => params,
+            expr => panic!("{}: {:?}", source, expr),
+        }
+    };
+
+    assert_eq!(params(r#"(a = 1) => a"#), vec![default(ident("a"), 1)]);
+    assert_eq!(
+        params(r#"({a = 1}) => a"#),
+        vec![Pattern::Object {
+            properties: vec![(
+                PropertyKey::Identifier("a".to_string()),
+                default(ident("a"), 1),
+            )],
+            rest: None,
+        }]
+    );
+    assert_eq!(
+        params(r#"([a, b = 2]) => a"#),
+        vec![Pattern::Array {
+            elements: vec![Some(ident("a")), Some(default(ident("b"), 2))],
+            rest: None,
+        }]
+    );
+    assert_eq!(
+        params(r#"({ a: [b = 1] = [] }, c) => c"#),
+        vec![
+            Pattern::Object {
+                properties: vec![(
+                    PropertyKey::Identifier("a".to_string()),
+                    Pattern::Assign {
+                        target: Box::new(Pattern::Array {
+                            elements: vec![Some(default(ident("b"), 1))],
+                            rest: None,
+                        }),
+                        value: Box::new(Expr::Array(vec![])),
+                    },
+                )],
+                rest: None,
+            },
+            ident("c"),
+        ]
+    );
+
+    // CoverInitializedName 只能出现在绑定模式中
+    for source in [
+        r#"({ a = 1 })"#,
+        r#"({ a = 1 }) + 1"#,
+        r#"[{ a = 1 }]"#,
+        r#"f({ a = 1 })"#,
+        r#"(a += 1) => a"#,
+        r#"(a.b = 1) => a"#,
+    ] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert!(parser.parse_expression().is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_assignment_expression() {
+    let ident = |name: &str| Expr::Identifier(name.to_string());
+    let parse_expression = |source: &str| {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        parser.parse_expression()
+    };
+
+    assert_eq!(
+        parse_expression(r#"a = b = 1"#).unwrap(),
+        Expr::Assign {
+            op: None,
+            target: Box::new(ident("a")),
+            value: Box::new(Expr::Assign {
+                op: None,
+                target: Box::new(ident("b")),
+                value: Box::new(Expr::Literal(JSValue::Int(1))),
+            }),
+        }
+    );
+    assert_eq!(
+        parse_expression(r#"a.b += c ?? d"#).unwrap(),
+        Expr::Assign {
+            op: Some(BinaryOp::Add),
+            target: Box::new(Expr::Member {
+                object: Box::new(ident("a")),
+                property: Box::new(ident("b")),
+                computed: false,
+            }),
+            value: Box::new(Expr::Binary {
+                op: BinaryOp::Coalesce,
+                left: Box::new(ident("c")),
+                right: Box::new(ident("d")),
+            }),
+        }
+    );
+    assert!(matches!(
+        parse_expression(r#"x ??= y => y"#).unwrap(),
+        Expr::Assign { op: Some(BinaryOp::Coalesce), value, .. } if matches!(*value, Expr::Arrow { .. })
+    ));
+    assert!(matches!(
+        parse_expression(r#"(a) = 1"#).unwrap(),
+        Expr::Assign { op: None, target, .. } if *target == ident("a")
+    ));
+
+    // 解构赋值
+    assert_eq!(
+        parse_expression(r#"[a, { b = 1, c: d.e }] = f"#).unwrap(),
+        Expr::Assign {
+            op: None,
+            target: Box::new(Expr::Array(vec![
+                ident("a"),
+                Expr::Object(vec![
+                    Property::Init {
+                        key: PropertyKey::Identifier("b".to_string()),
+                        value: Expr::Assign {
+                            op: None,
+                            target: Box::new(ident("b")),
+                            value: Box::new(Expr::Literal(JSValue::Int(1))),
+                        },
+                        shorthand: true,
+                    },
+                    Property::Init {
+                        key: PropertyKey::Identifier("c".to_string()),
+                        value: Expr::Member {
+                            object: Box::new(ident("d")),
+                            property: Box::new(ident("e")),
+                            computed: false,
+                        },
+                        shorthand: false,
+                    },
+                ]),
+            ])),
+            value: Box::new(ident("f")),
+        }
+    );
+
+    for source in [
+        r#"1 = a"#,
+        r#"a + b = c"#,
+        r#"f() = 1"#,
+        r#"[a] += 1"#,
+        r#"([a]) = 1"#,
+        r#"[1] = a"#,
+        r#"({ m() {} } = a)"#,
+    ] {
+        assert!(parse_expression(source).is_err(), "{}", source);
+    }
+
+    let program = parse("x = 1;", SourceType::Script).unwrap();
+    assert!(matches!(
+        &program.body[..],
+        [Stmt::Expr(Expr::Assign { op: None, .. })]
+    ));
+}
+
+#[test]
+fn test_Parser_parse_super() {
+    let mut src = InlineSourceReader::new(
+        r#"class A extends B {
+            constructor() { super(1); }
+            m() { super.foo(); }
+            n() { () => super[k]; }
+        }"#,
+    );
+    let mut parser = Parser::new(&mut src).unwrap();
+    let class = match parser.parse_statement_list_item().unwrap() {
+        Stmt::Class(class) => class,
+        stmt => panic!("unexpected statement: {:?}", stmt),
+    };
+
+    let body = |member: &ClassMember| match member {
+        ClassMember::Method { value, .. } => value.body.clone(),
+        _ => panic!("unexpected member: {:?}", member),
+    };
+    assert_eq!(
+        body(&class.body[0]),
+        vec![Stmt::Expr(Expr::Call {
+            callee: Box::new(Expr::Super),
+            arguments: vec![Expr::Literal(JSValue::Int(1))],
+        })]
+    );
+    assert_eq!(
+        body(&class.body[1]),
+        vec![Stmt::Expr(Expr::Call {
+            callee: Box::new(Expr::Member {
+                object: Box::new(Expr::Super),
+                property: Box::new(Expr::Identifier("foo".to_string())),
+                computed: false,
+            }),
+            arguments: vec![],
+        })]
+    );
+
+    let mut src = InlineSourceReader::new(r#"({ m() { super.x } })"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert!(parser.parse_expression().is_ok());
+
+    for source in [
+        r#"super"#,
+        r#"super.foo"#,
+        r#"super(1)"#,
+        r#"class A { m() { super; } }"#,
+        r#"class A { constructor() { super(); } }"#,
+        r#"class A extends B { m() { super(); } }"#,
+        r#"class A extends B { m() { function f() { super.x; } } }"#,
+        r#"({ m() { super(); } })"#,
+    ] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert!(parser.parse_statement_list_item().is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_this() {
+    let mut src = InlineSourceReader::new(r#"this.x"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_expression().unwrap(),
+        Expr::Member {
+            object: Box::new(Expr::This),
+            property: Box::new(Expr::Identifier("x".to_string())),
+            computed: false,
+        }
+    );
+
+    let mut src = InlineSourceReader::new(r#"this"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(parser.parse_expression().unwrap(), Expr::This);
+}
+
+#[test]
+fn test_Parser_parse_keyword_literal() {
+    for (source, expected) in [
+        (r#"true"#, JSValue::Bool(true)),
+        (r#"false"#, JSValue::Bool(false)),
+        (r#"null"#, JSValue::Null),
+    ] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert_eq!(
+            parser.parse_expression().unwrap(),
+            Expr::Literal(expected),
+            "{}",
+            source
+        );
+    }
+
+    let program = parse(r#"true; null ?? false;"#, SourceType::Script).unwrap();
+    assert_eq!(
+        program.body,
+        vec![
+            Stmt::Expr(Expr::Literal(JSValue::Bool(true))),
+            Stmt::Expr(Expr::Binary {
+                op: BinaryOp::Coalesce,
+                left: Box::new(Expr::Literal(JSValue::Null)),
+                right: Box::new(Expr::Literal(JSValue::Bool(false))),
+            }),
+        ]
+    );
+}
+
+#[test]
+fn test_Parser_parse_while_statement() {
+    let ident = |name: &str| Expr::Identifier(name.to_string());
+
+    let mut src = InlineSourceReader::new(r#"while (a < b) f();"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::While {
+            test: Expr::Binary {
+                op: BinaryOp::Less,
+                left: Box::new(ident("a")),
+                right: Box::new(ident("b")),
+            },
+            body: Box::new(Stmt::Expr(Expr::Call {
+                callee: Box::new(ident("f")),
+                arguments: vec![],
+            })),
+        }
+    );
+
+    let mut src = InlineSourceReader::new(r#"do {} while (a) b"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::DoWhile {
+            body: Box::new(Stmt::Block(vec![])),
+            test: ident("a"),
+        }
+    );
+    assert_eq!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::Expr(ident("b"))
+    );
+
+    let mut src = InlineSourceReader::new(r#"do ; while (a);"#);
+    let mut parser = Parser::new(&mut src).unwrap();
+    assert_eq!(
+        parser.parse_statement_list_item().unwrap(),
+        Stmt::DoWhile {
+            body: Box::new(Stmt::Empty),
+            test: ident("a"),
+        }
+    );
+    assert!(parser.cursor.is_eof());
+
+    for source in [r#"while a {}"#, r#"do {} (a)"#, r#"while () {}"#] {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        assert!(parser.parse_statement_list_item().is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_if_statement() {
+    let ident = |name: &str| Expr::Identifier(name.to_string());
+
+    let program = parse(r#"if (a) b; else if (c) {} else d;"#, SourceType::Script).unwrap();
+    assert_eq!(
+        program.body,
+        vec![Stmt::If {
+            test: ident("a"),
+            consequent: Box::new(Stmt::Expr(ident("b"))),
+            alternate: Some(Box::new(Stmt::If {
+                test: ident("c"),
+                consequent: Box::new(Stmt::Block(vec![])),
+                alternate: Some(Box::new(Stmt::Expr(ident("d")))),
+            })),
+        }]
+    );
+
+    // `else` 与最近的 `if` 结合
+    let program = parse(r#"if (a) if (b) c; else d;"#, SourceType::Script).unwrap();
+    assert!(matches!(
+        &program.body[..],
+        [Stmt::If { alternate: None, consequent, .. }]
+            if matches!(**consequent, Stmt::If { alternate: Some(..), .. })
+    ));
+
+    for source in [r#"if a b;"#, r#"if (a) else b;"#, r#"if (a) let x;"#] {
+        assert!(parse(source, SourceType::Script).is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_return_statement() {
+    let program = parse(r#"function f() { return 1; }"#, SourceType::Script).unwrap();
+    assert!(matches!(
+        &program.body[..],
+        [Stmt::Function(function)]
+            if function.body == vec![Stmt::Return(Some(Expr::Literal(JSValue::Int(1))))]
+    ));
+
+    // `return` 与返回值之间不允许换行
+    let program = parse("x => { return\n a }", SourceType::Script).unwrap();
+    assert!(matches!(
+        &program.body[..],
+        [Stmt::Expr(Expr::Arrow { body: ArrowBody::Block(body), .. })]
+            if *body == vec![Stmt::Return(None), Stmt::Expr(Expr::Identifier("a".to_string()))]
+    ));
+
+    let program = parse(r#"({ m() { if (a) return } })"#, SourceType::Script).unwrap();
+    assert_eq!(program.body.len(), 1);
+
+    for source in [r#"return 1;"#, r#"{ return; }"#, r#"while (a) return;"#] {
+        assert!(parse(source, SourceType::Script).is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_conditional_expression() {
+    let ident = |name: &str| Expr::Identifier(name.to_string());
+    let parse_expression = |source: &str| {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        parser.parse_expression()
+    };
+
+    // 条件表达式为右结合，分支中可以出现赋值表达式
+    assert_eq!(
+        parse_expression(r#"a || b ? c = 1 : d ? e : f"#).unwrap(),
+        Expr::Conditional {
+            test: Box::new(Expr::Binary {
+                op: BinaryOp::LogicalOr,
+                left: Box::new(ident("a")),
+                right: Box::new(ident("b")),
+            }),
+            consequent: Box::new(Expr::Assign {
+                op: None,
+                target: Box::new(ident("c")),
+                value: Box::new(Expr::Literal(JSValue::Int(1))),
+            }),
+            alternate: Box::new(Expr::Conditional {
+                test: Box::new(ident("d")),
+                consequent: Box::new(ident("e")),
+                alternate: Box::new(ident("f")),
+            }),
+        }
+    );
+
+    // `?` 与 `:` 之间总是允许 `in`
+    let program = parse(r#"for (x = a ? b in c : d; ;) {}"#, SourceType::Script).unwrap();
+    assert!(matches!(
+        &program.body[..],
+        [Stmt::For {
+            init: Some(ForInit::Expr(..)),
+            ..
+        }]
+    ));
+
+    for source in [r#"a ? b"#, r#"(a ? b : c) = 1"#, r#"({ a = 1 }) ? b : c"#] {
+        assert!(parse_expression(source).is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_Parser_parse_new_expression() {
+    let ident = |name: &str| Expr::Identifier(name.to_string());
+    let parse_expression = |source: &str| {
+        let mut src = InlineSourceReader::new(source);
+        let mut parser = Parser::new(&mut src).unwrap();
+        parser.parse_expression()
+    };
+
+    assert_eq!(
+        parse_expression(r#"new Foo(a)"#).unwrap(),
+        Expr::New {
+            callee: Box::new(ident("Foo")),
+            arguments: vec![ident("a")],
+        }
+    );
+    assert_eq!(
+        parse_expression(r#"new Foo"#).unwrap(),
+        Expr::New {
+            callee: Box::new(ident("Foo")),
+            arguments: vec![],
+        }
+    );
+
+    // 第一个参数列表属于 `new`，其后的参数列表为函数调用
+    assert_eq!(
+        parse_expression(r#"new a.b(c)(d)"#).unwrap(),
+        Expr::Call {
+            callee: Box::new(Expr::New {
+                callee: Box::new(Expr::Member {
+                    object: Box::new(ident("a")),
+                    property: Box::new(ident("b")),
+                    computed: false,
+                }),
+                arguments: vec![ident("c")],
+            }),
+            arguments: vec![ident("d")],
+        }
+    );
+    assert_eq!(
+        parse_expression(r#"new new A()"#).unwrap(),
+        Expr::New {
+            callee: Box::new(Expr::New {
+                callee: Box::new(ident("A")),
+                arguments: vec![],
+            }),
+            arguments: vec![],
+        }
+    );
+    assert!(matches!(
+        parse_expression(r#"new (f())"#).unwrap(),
+        Expr::New { callee, arguments } if matches!(*callee, Expr::Call { .. }) && arguments.is_empty()
+    ));
+
+    let program = parse(r#"new Foo();"#, SourceType::Script).unwrap();
+    assert!(matches!(&program.body[..], [Stmt::Expr(Expr::New { .. })]));
+
+    for source in [r#"new"#, r#"new ()"#, r#"new.target"#] {
+        assert!(parse_expression(source).is_err(), "{}", source);
+    }
+}
+
+#[test]
+fn test_parse_program() {
+    let program = parse(
+        "'use strict'\n\"use asm\";\nimport x from 'x';\nlet a = x;\n'not a directive';\nwhile (a) {}\n",
+        SourceType::Module,
+    )
+    .unwrap();
+
+    assert_eq!(program.source_type, SourceType::Module);
+    assert_eq!(program.body.len(), 6);
+    assert_eq!(program.body[0], Stmt::Directive("use strict".to_string()));
+    assert_eq!(program.body[1], Stmt::Directive("use asm".to_string()));
+    assert_eq!(
+        program.body[4],
+        Stmt::Expr(Expr::Literal(JSValue::Str("not a directive".to_string())))
+    );
+
+    assert_eq!(
+        parse("('use strict');", SourceType::Script).unwrap(),
+        Program {
+            body: vec![Stmt::Expr(Expr::Literal(JSValue::Str(
+                "use strict".to_string()
+            )))],
+            source_type: SourceType::Script,
+        }
+    );
+    assert!(parse("import x from 'x';", SourceType::Script).is_err());
+
+    // 指令按源码原文判定，含转义序列的字符串不构成 "use strict" 指令
+    let program = parse(
+        "'use\\x20strict';\n\"use \\u0073trict\";\n'use strict';\n'a'",
+        SourceType::Script,
+    )
+    .unwrap();
+    assert_eq!(
+        program.body,
+        vec![
+            Stmt::Expr(Expr::Literal(JSValue::Str("use strict".to_string()))),
+            Stmt::Expr(Expr::Literal(JSValue::Str("use strict".to_string()))),
+            Stmt::Directive("use strict".to_string()),
+            Stmt::Directive("a".to_string()),
+        ]
+    );
+}
+
+/// 将源码解析为 JSValue
+fn parse_value(source: &str) -> Result<JSValue, ParseError> {
+    let mut src = InlineSourceReader::new(source);
+    Parser::new(&mut src).unwrap().parse_value()
+}
+
+#[test]
+fn test_Parser_parse_value_array() {
+    assert_eq!(
+        parse_value(r#"[1, "a", [true, null], ]"#).unwrap(),
+        JSValue::Array(vec![
+            JSValue::Int(1),
+            JSValue::Str("a".to_string()),
+            JSValue::Array(vec![JSValue::Bool(true), JSValue::Null]),
+        ])
+    );
+    assert_eq!(
+        parse_value("[, 1, , 2,]").unwrap(),
+        JSValue::Array(vec![
+            JSValue::Undefined,
+            JSValue::Int(1),
+            JSValue::Undefined,
+            JSValue::Int(2),
+        ])
+    );
+    assert_eq!(parse_value("[]").unwrap(), JSValue::Array(vec![]));
+    assert_eq!(
+        parse_value("[,]").unwrap(),
+        JSValue::Array(vec![JSValue::Undefined])
+    );
+
+    assert!(parse_value("[1, 2").is_err());
+    assert!(parse_value("[1 2]").is_err());
+    assert!(parse_value("[").is_err());
+    assert!(parse_value("[a]").is_err());
+}
+
+#[test]
+fn test_Parser_parse_value_object() {
+    assert_eq!(
+        parse_value(r#"{ a: 1, "b c": [null], 2: { if: false }, a: "x", }"#).unwrap(),
+        JSValue::Object(vec![
+            ("a".to_string(), JSValue::Str("x".to_string())),
+            ("b c".to_string(), JSValue::Array(vec![JSValue::Null])),
+            (
+                "2".to_string(),
+                JSValue::Object(vec![("if".to_string(), JSValue::Bool(false))])
+            ),
+        ])
+    );
+    assert_eq!(parse_value("{}").unwrap(), JSValue::Object(vec![]));
+    assert_eq!(
+        parse_value("{ 1.50: 0 }").unwrap(),
+        JSValue::Object(vec![("1.5".to_string(), JSValue::Int(0))])
+    );
+
+    assert!(parse_value("{ a 1 }").is_err());
+    assert!(parse_value("{ a: 1").is_err());
+    assert!(parse_value("{ a: 1 b: 2 }").is_err());
+    assert!(parse_value("{ ,}").is_err());
+}
+
+#[test]
+fn test_Parser_parse_value_number() {
+    assert_eq!(parse_value("123").unwrap(), JSValue::Int(123));
+    assert_eq!(parse_value("1.5").unwrap(), JSValue::Float(1.5));
+    assert_eq!(parse_value("0xff").unwrap(), JSValue::Int(255));
+    assert_eq!(parse_value("-5").unwrap(), JSValue::Int(-5));
+    assert_eq!(parse_value("+5").unwrap(), JSValue::Int(5));
+    assert_eq!(parse_value("-1.5").unwrap(), JSValue::Float(-1.5));
+    assert_eq!(parse_value("-10n").unwrap(), JSValue::BigInt(-10));
+    assert_eq!(
+        parse_value("[-1, +2]").unwrap(),
+        JSValue::Array(vec![JSValue::Int(-1), JSValue::Int(2)])
+    );
+
+    match parse_value("-0").unwrap() {
+        JSValue::Float(val) => assert!(val == 0.0 && val.is_sign_negative()),
+        val => panic!("unexpected value: {:?}", val),
+    }
+
+    assert!(parse_value("-").is_err());
+    assert!(parse_value("-\"a\"").is_err());
+    assert!(parse_value("+1n").is_err());
+
+    // BigInt 以 i128 表示，超出范围的字面量视为错误
+    let max = i128::MAX.to_string();
+    assert_eq!(
+        parse_value(&format!("{}n", max)).unwrap(),
+        JSValue::BigInt(i128::MAX)
+    );
+    for source in [
+        "170141183460469231731687303715884105728n",
+        "123456789012345678901234567890123456789012345n",
+        "[0xffffffffffffffffffffffffffffffffn]",
+        "{ 123456789012345678901234567890123456789012345n: 1 }",
+    ] {
+        assert!(parse_value(source).is_err(), "{}", source);
+    }
+    assert!(parse(
+        "f(123456789012345678901234567890123456789012345n);",
+        SourceType::Script
+    )
+    .is_err());
+}
+
+#[test]
+fn test_Parser_parse_value_keyword() {
+    assert_eq!(
+        parse_value("[true, false, null, undefined]").unwrap(),
+        JSValue::Array(vec![
+            JSValue::Bool(true),
+            JSValue::Bool(false),
+            JSValue::Null,
+            JSValue::Undefined,
+        ])
+    );
+    assert_eq!(
+        parse_value("{ undefined: undefined }").unwrap(),
+        JSValue::Object(vec![("undefined".to_string(), JSValue::Undefined)])
+    );
+
+    assert!(parse_value("NaN").is_err());
+    assert!(parse_value("this").is_err());
+}
@@ -0,0 +1,289 @@
+use super::ast::{
+    ArrowBody, Class, ClassMember, Expr, ForInit, Function, Pattern, Program, Property,
+    PropertyKey, Stmt, VarDeclarator,
+};
+
+/// 语法树访问器
+///
+/// 各方法的默认实现调用对应的 `walk_*` 函数遍历子节点。
+/// 实现者只需重写关心的节点，并在需要继续遍历时调用对应的 `walk_*` 函数
+pub(crate) trait Visitor {
+    /// 访问语法树的根节点
+    fn visit_program(&mut self, program: &Program) {
+        walk_program(self, program);
+    }
+
+    /// 访问语句节点
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        walk_stmt(self, stmt);
+    }
+
+    /// 访问表达式节点
+    fn visit_expr(&mut self, expr: &Expr) {
+        walk_expr(self, expr);
+    }
+
+    /// 访问绑定模式
+    fn visit_pattern(&mut self, pattern: &Pattern) {
+        walk_pattern(self, pattern);
+    }
+
+    /// 访问属性名
+    fn visit_property_key(&mut self, key: &PropertyKey) {
+        walk_property_key(self, key);
+    }
+
+    /// 访问函数
+    fn visit_function(&mut self, function: &Function) {
+        walk_function(self, function);
+    }
+
+    /// 访问类
+    fn visit_class(&mut self, class: &Class) {
+        walk_class(self, class);
+    }
+}
+
+/// 遍历语法树根节点下的所有语句
+pub(crate) fn walk_program<V: Visitor + ?Sized>(visitor: &mut V, program: &Program) {
+    for stmt in &program.body {
+        visitor.visit_stmt(stmt);
+    }
+}
+
+/// 遍历语句节点的子节点
+pub(crate) fn walk_stmt<V: Visitor + ?Sized>(visitor: &mut V, stmt: &Stmt) {
+    match stmt {
+        Stmt::Expr(expr) | Stmt::ExportDefault(expr) => visitor.visit_expr(expr),
+        Stmt::Block(body) => {
+            for stmt in body {
+                visitor.visit_stmt(stmt);
+            }
+        }
+        Stmt::Function(function) => visitor.visit_function(function),
+        Stmt::Class(class) => visitor.visit_class(class),
+        Stmt::VarDecl { declarations, .. } => walk_declarations(visitor, declarations),
+        Stmt::While { test, body } | Stmt::DoWhile { body, test } => {
+            visitor.visit_expr(test);
+            visitor.visit_stmt(body);
+        }
+        Stmt::For {
+            init,
+            test,
+            update,
+            body,
+        } => {
+            if let Some(init) = init {
+                walk_for_init(visitor, init);
+            }
+            if let Some(test) = test {
+                visitor.visit_expr(test);
+            }
+            if let Some(update) = update {
+                visitor.visit_expr(update);
+            }
+            visitor.visit_stmt(body);
+        }
+        Stmt::ForIn { left, right, body } | Stmt::ForOf { left, right, body } => {
+            walk_for_init(visitor, left);
+            visitor.visit_expr(right);
+            visitor.visit_stmt(body);
+        }
+        Stmt::If {
+            test,
+            consequent,
+            alternate,
+        } => {
+            visitor.visit_expr(test);
+            visitor.visit_stmt(consequent);
+            if let Some(alternate) = alternate {
+                visitor.visit_stmt(alternate);
+            }
+        }
+        Stmt::Return(argument) => {
+            if let Some(argument) = argument {
+                visitor.visit_expr(argument);
+            }
+        }
+        Stmt::ExportDecl(decl) => visitor.visit_stmt(decl),
+        Stmt::Directive(_)
+        | Stmt::Debugger
+        | Stmt::Empty
+        | Stmt::Import { .. }
+        | Stmt::ExportNamed { .. }
+        | Stmt::ExportAll { .. } => {}
+    }
+}
+
+/// 遍历表达式节点的子节点
+pub(crate) fn walk_expr<V: Visitor + ?Sized>(visitor: &mut V, expr: &Expr) {
+    match expr {
+        Expr::Member {
+            object,
+            property,
+            computed,
+        } => {
+            visitor.visit_expr(object);
+            // `object.property` 中的 property 是属性名而非标识符引用，不作为子表达式访问
+            if *computed {
+                visitor.visit_expr(property);
+            }
+        }
+        Expr::Call { callee, arguments } | Expr::New { callee, arguments } => {
+            visitor.visit_expr(callee);
+            for argument in arguments {
+                visitor.visit_expr(argument);
+            }
+        }
+        Expr::ImportCall(specifier) => visitor.visit_expr(specifier),
+        Expr::Unary { operand, .. } => visitor.visit_expr(operand),
+        Expr::Binary { left, right, .. } => {
+            visitor.visit_expr(left);
+            visitor.visit_expr(right);
+        }
+        Expr::Conditional {
+            test,
+            consequent,
+            alternate,
+        } => {
+            visitor.visit_expr(test);
+            visitor.visit_expr(consequent);
+            visitor.visit_expr(alternate);
+        }
+        Expr::Assign { target, value, .. } => {
+            visitor.visit_expr(target);
+            visitor.visit_expr(value);
+        }
+        Expr::Sequence(exprs) | Expr::Array(exprs) => {
+            for expr in exprs {
+                visitor.visit_expr(expr);
+            }
+        }
+        Expr::Arrow { params, rest, body } => {
+            walk_params(visitor, params, rest);
+            match body {
+                ArrowBody::Expr(expr) => visitor.visit_expr(expr),
+                ArrowBody::Block(body) => {
+                    for stmt in body {
+                        visitor.visit_stmt(stmt);
+                    }
+                }
+            }
+        }
+        Expr::Object(properties) => {
+            for property in properties {
+                match property {
+                    Property::Init { key, value, .. } => {
+                        visitor.visit_property_key(key);
+                        visitor.visit_expr(value);
+                    }
+                    Property::Method { key, value, .. } => {
+                        visitor.visit_property_key(key);
+                        visitor.visit_function(value);
+                    }
+                }
+            }
+        }
+        Expr::Function(function) => visitor.visit_function(function),
+        Expr::Class(class) => visitor.visit_class(class),
+        Expr::Literal(_)
+        | Expr::Identifier(_)
+        | Expr::This
+        | Expr::Super
+        | Expr::PrivateName(_)
+        | Expr::Regex { .. }
+        | Expr::MetaProperty { .. } => {}
+    }
+}
+
+/// 遍历绑定模式的子节点
+pub(crate) fn walk_pattern<V: Visitor + ?Sized>(visitor: &mut V, pattern: &Pattern) {
+    match pattern {
+        Pattern::Identifier(_) => {}
+        Pattern::Object { properties, rest } => {
+            for (key, value) in properties {
+                visitor.visit_property_key(key);
+                visitor.visit_pattern(value);
+            }
+            if let Some(rest) = rest {
+                visitor.visit_pattern(rest);
+            }
+        }
+        Pattern::Array { elements, rest } => {
+            for element in elements.iter().flatten() {
+                visitor.visit_pattern(element);
+            }
+            if let Some(rest) = rest {
+                visitor.visit_pattern(rest);
+            }
+        }
+        Pattern::Assign { target, value } => {
+            visitor.visit_pattern(target);
+            visitor.visit_expr(value);
+        }
+    }
+}
+
+/// 遍历属性名的子节点，仅计算属性名含有子表达式
+pub(crate) fn walk_property_key<V: Visitor + ?Sized>(visitor: &mut V, key: &PropertyKey) {
+    if let PropertyKey::Computed(expr) = key {
+        visitor.visit_expr(expr);
+    }
+}
+
+/// 遍历函数的参数与函数体
+pub(crate) fn walk_function<V: Visitor + ?Sized>(visitor: &mut V, function: &Function) {
+    walk_params(visitor, &function.params, &function.rest);
+    for stmt in &function.body {
+        visitor.visit_stmt(stmt);
+    }
+}
+
+/// 遍历类的父类与类成员
+pub(crate) fn walk_class<V: Visitor + ?Sized>(visitor: &mut V, class: &Class) {
+    if let Some(super_class) = &class.super_class {
+        visitor.visit_expr(super_class);
+    }
+    for member in &class.body {
+        match member {
+            ClassMember::Method { key, value, .. } => {
+                visitor.visit_property_key(key);
+                visitor.visit_function(value);
+            }
+            ClassMember::Field { key, value, .. } => {
+                visitor.visit_property_key(key);
+                if let Some(value) = value {
+                    visitor.visit_expr(value);
+                }
+            }
+        }
+    }
+}
+
+fn walk_params<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    params: &[Pattern],
+    rest: &Option<Box<Pattern>>,
+) {
+    for param in params {
+        visitor.visit_pattern(param);
+    }
+    if let Some(rest) = rest {
+        visitor.visit_pattern(rest);
+    }
+}
+
+fn walk_declarations<V: Visitor + ?Sized>(visitor: &mut V, declarations: &[VarDeclarator]) {
+    for declarator in declarations {
+        if let Some(init) = &declarator.init {
+            visitor.visit_expr(init);
+        }
+    }
+}
+
+fn walk_for_init<V: Visitor + ?Sized>(visitor: &mut V, init: &ForInit) {
+    match init {
+        ForInit::VarDecl { declarations, .. } => walk_declarations(visitor, declarations),
+        ForInit::Expr(expr) => visitor.visit_expr(expr),
+    }
+}
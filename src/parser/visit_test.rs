@@ -0,0 +1,48 @@
+use super::{
+    ast::{Expr, Pattern},
+    parser::{parse, SourceType},
+    visit::{walk_expr, walk_pattern, Visitor},
+};
+
+#[derive(Default)]
+struct IdentifierCollector {
+    names: Vec<String>,
+}
+
+impl Visitor for IdentifierCollector {
+    fn visit_expr(&mut self, expr: &Expr) {
+        if let Expr::Identifier(name) = expr {
+            self.names.push(name.clone());
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_pattern(&mut self, pattern: &Pattern) {
+        if let Pattern::Identifier(name) = pattern {
+            self.names.push(name.clone());
+        }
+        walk_pattern(self, pattern);
+    }
+}
+
+#[test]
+fn test_visitor_collect_identifiers() {
+    let program = parse(
+        r#"
+        let a = b + c;
+        function f(d, { e = g }) { h(i.j); }
+        class K extends L { m() { n; } [o] = p; }
+        for (const q of r) { while (s) t => u; }
+        "#,
+        SourceType::Script,
+    )
+    .unwrap();
+
+    let mut collector = IdentifierCollector::default();
+    collector.visit_program(&program);
+
+    assert_eq!(
+        collector.names,
+        ["b", "c", "d", "e", "g", "h", "i", "L", "n", "o", "p", "r", "s", "t", "u"]
+    );
+}
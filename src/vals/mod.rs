@@ -1,8 +1,19 @@
 mod str_converter;
 mod values;
+#[cfg(feature = "serde")]
+mod values_serde;
 
 pub(crate) use str_converter::strconv;
-pub(crate) use values::JSValue;
+pub use values::JSValue;
+pub(crate) use values::MAX_SAFE_INTEGER;
 
 #[cfg(test)]
 mod str_converter_test;
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod values_test;
+
+#[cfg(all(test, feature = "serde"))]
+#[allow(non_snake_case)]
+mod values_serde_test;
@@ -1,5 +1,5 @@
 pub(crate) mod strconv {
-    use crate::vals::JSValue;
+    use crate::vals::{JSValue, MAX_SAFE_INTEGER};
 
     enum ToNumberTarget {
         Binary,
@@ -8,6 +8,7 @@ pub(crate) mod strconv {
         Hex,
     }
 
+    #[allow(clippy::enum_variant_names)]
     enum ToNumberDecimalState {
         IntPart,
         FracPart,
@@ -15,6 +16,60 @@ pub(crate) mod strconv {
         ExpPart,
     }
 
+    /// 将二进制、八进制或十六进制的数字部分转化为数字
+    ///
+    /// 累加过程检查溢出：结果不超过 `Number.MAX_SAFE_INTEGER` 时使用 Int 表示，
+    /// 否则与 JS 一致使用 f64 表示。进制均为 2 的幂，超出 i64 范围时按位记录最高的 64 个有效位，
+    /// 其余有效位只记录位数及是否非零，保证结果只舍入一次。
+    /// 没有任何数字时（如 `0x`）无法转换
+    ///
+    /// # Arguments
+    /// `digits` - 去除进制前缀后的各个数字
+    /// `radix` - 进制
+    /// # Returns
+    /// 返回文本转化成的数字，用 JSValue 表示
+    fn to_number_with_radix(digits: impl Iterator<Item = char>, radix: u32) -> JSValue {
+        let mut result = Some(0i64);
+
+        // 最高的 64 个有效位，mantissa_bits 为已记录的位数
+        let mut mantissa = 0u64;
+        let mut mantissa_bits = 0u32;
+        // 未记录在 mantissa 中的低位的位数，以及其中是否存在非零位
+        let mut exponent = 0i32;
+        let mut sticky = false;
+        let mut has_digit = false;
+        for chr in digits {
+            has_digit = true;
+            let digit = match chr.to_digit(radix) {
+                Some(n) => n,
+                _ => return JSValue::Float(f64::NAN),
+            };
+
+            result = result
+                .and_then(|val| val.checked_mul(radix as i64))
+                .and_then(|val| val.checked_add(digit as i64));
+
+            for shift in (0..radix.trailing_zeros()).rev() {
+                let bit = (digit >> shift) & 1;
+                if mantissa_bits == u64::BITS {
+                    exponent = exponent.saturating_add(1);
+                    sticky |= bit != 0;
+                } else if mantissa_bits != 0 || bit != 0 {
+                    mantissa = mantissa << 1 | bit as u64;
+                    mantissa_bits += 1;
+                }
+            }
+        }
+
+        match result {
+            _ if !has_digit => JSValue::Float(f64::NAN),
+            Some(val) if val <= MAX_SAFE_INTEGER => JSValue::Int(val),
+            Some(val) => JSValue::Float(val as f64),
+            // mantissa 的最低位远低于 f64 的精度，将其置位即可使被截断的低位参与舍入
+            None => JSValue::Float((mantissa | sticky as u64) as f64 * 2f64.powi(exponent)),
+        }
+    }
+
     /// 根据明确的转化目标进行转化
     ///
     /// # Arguments
@@ -24,152 +79,237 @@ pub(crate) mod strconv {
     /// 返回文本转化成的数字，用 JSValue 表示
     fn to_number_with_target(s: &str, target: ToNumberTarget) -> JSValue {
         match target {
-            ToNumberTarget::Binary => {
-                let mut chars = s.chars().skip(2);
-                let mut result = 0i64;
-                while let Some(chr) = chars.next() {
-                    result = match chr {
-                        '0'..='1' => match chr.to_digit(2) {
-                            Some(n) => (result << 1) | (n as i64),
-                            _ => return JSValue::Float(f64::NAN),
-                        },
-                        _ => return JSValue::Float(f64::NAN),
-                    }
-                }
-                JSValue::Int(result)
-            }
-            ToNumberTarget::Oct => {
-                let mut chars = s.chars().skip(1).peekable();
-                if matches!(chars.peek(), Some('o' | 'O')) {
-                    chars.next();
-                }
-
-                let mut result = 0i64;
-                while let Some(chr) = chars.next() {
-                    result = match chr {
-                        '0'..='7' => match chr.to_digit(8) {
-                            Some(n) => (result << 3) | (n as i64),
-                            _ => return JSValue::Float(f64::NAN),
-                        },
-                        _ => return JSValue::Float(f64::NAN),
-                    };
-                }
-                JSValue::Int(result)
-            }
-            ToNumberTarget::Hex => {
-                let mut chars = s.chars().skip(2);
-                let mut result = 0i64;
-                while let Some(chr) = chars.next() {
-                    result = match chr {
-                        '0'..='9' | 'a'..='f' | 'A'..='F' => match chr.to_digit(16) {
-                            Some(n) => (result << 4) | (n as i64),
-                            _ => return JSValue::Float(f64::NAN),
-                        },
-                        _ => return JSValue::Float(f64::NAN),
-                    }
-                }
-                JSValue::Int(result)
-            }
+            ToNumberTarget::Binary => to_number_with_radix(s.chars().skip(2), 2),
+            ToNumberTarget::Oct => match s.as_bytes() {
+                [b'0', b'o' | b'O', ..] => to_number_with_radix(s.chars().skip(2), 8),
+                // 旧式八进制开头的 `0` 同样是数字，如 `0` 与 `017`
+                _ => to_number_with_radix(s.chars(), 8),
+            },
+            ToNumberTarget::Hex => to_number_with_radix(s.chars().skip(2), 16),
             ToNumberTarget::Decimal => {
-                let mut chars = s.chars();
                 let mut state = ToNumberDecimalState::IntPart;
 
-                let mut intval = 0i64;
-                let mut fracval = 0f64;
-                let mut fracbase = 1f64;
+                // 整数部分溢出 i64 时 intval 为 None
+                let mut intval = Some(0i64);
                 let mut expval = 0i64;
 
+                let mut has_digit = false;
+                let mut has_exp_digit = false;
                 let mut is_float = false;
                 let mut has_exp = false;
                 let mut negative_exp = false;
 
-                while let Some(chr) = chars.next() {
-                    match chr {
-                        '0'..='9' if matches!(state, ToNumberDecimalState::IntPart) => {
-                            match chr.to_digit(10) {
-                                Some(n) => intval = intval * 10 + (n as i64),
-                                _ => return JSValue::Float(f64::NAN),
-                            }
-                        }
-                        '0'..='9' if matches!(state, ToNumberDecimalState::FracPart) => {
-                            match chr.to_digit(10) {
-                                Some(n) => {
-                                    fracbase *= 0.1;
-                                    fracval += fracbase * (n as f64);
-                                }
-                                _ => return JSValue::Float(f64::NAN),
-                            }
+                // 先校验文本并计算整数部分，浮点数的最终转换交由标准库完成以保证正确舍入
+                for chr in s.chars() {
+                    match (chr.to_digit(10), &state) {
+                        (Some(n), ToNumberDecimalState::IntPart) => {
+                            has_digit = true;
+                            intval = intval
+                                .and_then(|val| val.checked_mul(10))
+                                .and_then(|val| val.checked_add(n as i64));
                         }
-                        '0'..='9'
-                            if matches!(
-                                state,
-                                ToNumberDecimalState::ExpInitPart | ToNumberDecimalState::ExpPart
-                            ) =>
-                        {
+                        (Some(_), ToNumberDecimalState::FracPart) => has_digit = true,
+                        (
+                            Some(n),
+                            ToNumberDecimalState::ExpInitPart | ToNumberDecimalState::ExpPart,
+                        ) => {
                             state = ToNumberDecimalState::ExpPart;
-                            match chr.to_digit(10) {
-                                Some(n) => expval = expval * 10 + (n as i64),
-                                _ => return JSValue::Float(f64::NAN),
-                            }
+                            has_exp_digit = true;
+                            expval = expval.saturating_mul(10).saturating_add(n as i64);
                         }
-                        '.' if matches!(state, ToNumberDecimalState::IntPart) => {
+                        (None, ToNumberDecimalState::IntPart) if chr == '.' => {
                             is_float = true;
-                            fracval = intval as f64;
                             state = ToNumberDecimalState::FracPart;
                         }
-                        'e' | 'E'
-                            if matches!(
-                                state,
-                                ToNumberDecimalState::IntPart | ToNumberDecimalState::FracPart
-                            ) =>
+                        (None, ToNumberDecimalState::IntPart | ToNumberDecimalState::FracPart)
+                            if matches!(chr, 'e' | 'E') =>
                         {
                             has_exp = true;
-                            state = ToNumberDecimalState::ExpInitPart
-                        }
-                        '+' if matches!(state, ToNumberDecimalState::ExpInitPart) => {
-                            state = ToNumberDecimalState::ExpPart;
-                            negative_exp = false;
+                            state = ToNumberDecimalState::ExpInitPart;
                         }
-                        '-' if matches!(state, ToNumberDecimalState::ExpInitPart) => {
+                        (None, ToNumberDecimalState::ExpInitPart) if matches!(chr, '+' | '-') => {
+                            negative_exp = chr == '-';
                             state = ToNumberDecimalState::ExpPart;
-                            negative_exp = true;
                         }
                         _ => return JSValue::Float(f64::NAN),
                     }
                 }
 
-                if negative_exp {
-                    expval = -expval;
-                    if !is_float {
-                        is_float = true;
-                        fracval = intval as f64;
-                    }
+                if !has_digit || (has_exp && !has_exp_digit) {
+                    return JSValue::Float(f64::NAN);
                 }
 
-                if is_float {
-                    if has_exp {
-                        JSValue::Float(fracval.powf(expval as f64))
-                    } else {
-                        JSValue::Float(fracval)
-                    }
-                } else {
-                    if has_exp {
-                        JSValue::Int(intval.pow(expval as u32))
-                    } else {
-                        JSValue::Int(intval)
+                // 整数结果不超出安全整数范围时使用 Int 表示，指数部分表示乘以 10 的 expval 次方
+                if !is_float {
+                    let scaled = match intval {
+                        Some(0) => Some(0),
+                        Some(val) if !has_exp || expval == 0 => Some(val),
+                        Some(val) if !negative_exp => u32::try_from(expval)
+                            .ok()
+                            .and_then(|exp| 10i64.checked_pow(exp))
+                            .and_then(|base| val.checked_mul(base)),
+                        _ => None,
+                    };
+                    if let Some(val) = scaled.filter(|val| *val <= MAX_SAFE_INTEGER) {
+                        return JSValue::Int(val);
                     }
                 }
+
+                s.parse::<f64>()
+                    .map_or(JSValue::Float(f64::NAN), JSValue::Float)
             }
         }
     }
 
-    /// 将字符串转换为数字，用 JSValue 表示
+    /// StringToNumber 抽象操作，将字符串转换为数字，用 JSValue 表示
+    ///
+    /// 首尾的空白与换行会被忽略，空字符串（或仅含空白的字符串）转换为 0。
+    /// 接受 `Infinity`、`+Infinity`、`-Infinity` 与 `NaN`；
+    /// 十进制数字允许带有 `+` 或 `-` 符号，`-0` 转换为 `Float(-0.0)`，
+    /// 带进制前缀的数字带有符号时与 JS 一致转换为 NaN，如 `-0x10`。
+    /// 与数字字面量不同，以 `0` 开头的数字按十进制转换，如 `"010"` 转换为 10
     ///
     /// # Arguments
     /// `s` - 待转换为数字的字符串
     /// # Returns
-    /// 返回 JSValue 表示的数字
+    /// 返回 JSValue 表示的数字，无法转换时为 NaN
     pub(crate) fn to_number(s: &str) -> JSValue {
+        let s = s.trim_matches(is_str_whitespace);
+        let (negative, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let signed = unsigned.len() != s.len();
+        let val = match unsigned {
+            "Infinity" => JSValue::Float(f64::INFINITY),
+            "NaN" => JSValue::Float(f64::NAN),
+            "" if !signed => JSValue::Int(0),
+            // 符号之后不能为空、不能再出现符号，也不能是带进制前缀的数字
+            _ if signed
+                && matches!(
+                    unsigned.as_bytes(),
+                    [] | [b'0', b'b' | b'B' | b'o' | b'O' | b'x' | b'X', ..] | [b'+' | b'-', ..]
+                ) =>
+            {
+                JSValue::Float(f64::NAN)
+            }
+            _ => to_unsigned_number(unsigned, false),
+        };
+
+        match val {
+            JSValue::Int(0) if negative => JSValue::Float(-0.0),
+            JSValue::Int(val) if negative => JSValue::Int(-val),
+            JSValue::Float(val) if negative => JSValue::Float(-val),
+            val => val,
+        }
+    }
+
+    /// 判断字符是否为 StrWhiteSpaceChar，即 WhiteSpace 或 LineTerminator
+    fn is_str_whitespace(chr: char) -> bool {
+        match chr {
+            '\u{0009}' | '\u{000b}' | '\u{000c}' | '\u{feff}' => true,
+            '\u{000a}' | '\u{000d}' | '\u{2028}' | '\u{2029}' => true,
+            '\u{0085}' => false,
+            _ => chr.is_whitespace(),
+        }
+    }
+
+    /// 将数字字面量转换为数字，用 JSValue 表示
+    ///
+    /// 以 `n` 结尾的 BigInt 字面量转换为 BigInt，其余按数字字面量转换。
+    /// 与 to_number 不同，该函数用于源码中的字面量，允许数字分隔符 `_`，
+    /// 并按旧式八进制处理以 `0` 开头的数字，如 `0777`；
+    /// 字符串到数字的转换不接受 `n` 后缀与数字分隔符
+    ///
+    /// # Arguments
+    /// `s` - 数字字面量的文本
+    /// # Returns
+    /// 返回 JSValue 表示的数字，无法转换时为 NaN；超出 i128 范围的 BigInt 同样为 NaN
+    pub(crate) fn to_numeric(s: &str) -> JSValue {
+        // 字面量中的 NumericLiteralSeparator 已由词法分析校验，此处直接去除
+        let s = &s.replace('_', "");
+        match s.strip_suffix('n') {
+            Some(digits) => to_bigint(digits),
+            None => to_unsigned_number(s, true),
+        }
+    }
+
+    /// 将去除 `n` 后缀的 BigInt 字面量转换为 BigInt
+    ///
+    /// 十进制部分不允许出现小数、指数以及除 `0` 本身以外的前导零，如 `1.5n`、`1e3n`、`01n`；
+    /// 超出 i128 范围时同样视为无法转换
+    ///
+    /// # Arguments
+    /// `s` - 去除 `n` 后缀的文本
+    /// # Returns
+    /// 返回 BigInt，无法转换时为 NaN
+    fn to_bigint(s: &str) -> JSValue {
+        let (digits, radix) = match s.as_bytes() {
+            [b'0', b'b' | b'B', ..] => (&s[2..], 2),
+            [b'0', b'o' | b'O', ..] => (&s[2..], 8),
+            [b'0', b'x' | b'X', ..] => (&s[2..], 16),
+            [b'0', _, ..] => return JSValue::Float(f64::NAN),
+            _ => (s, 10),
+        };
+
+        parse_bigint_digits(digits, radix).map_or(JSValue::Float(f64::NAN), JSValue::BigInt)
+    }
+
+    /// StringToBigInt 抽象操作，将字符串转换为 BigInt
+    ///
+    /// 首尾的空白与换行会被忽略，空字符串转换为 0n。十进制数字允许带有 `+` 或 `-` 符号与前导零，
+    /// 带进制前缀的数字不允许带有符号；不接受 `n` 后缀、小数与指数
+    ///
+    /// # Arguments
+    /// `s` - 待转换为 BigInt 的字符串
+    /// # Returns
+    /// 返回 BigInt 的值，无法转换或超出 i128 范围时返回 None
+    pub(crate) fn string_to_bigint(s: &str) -> Option<i128> {
+        let s = s.trim_matches(is_str_whitespace);
+        let (negative, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let signed = unsigned.len() != s.len();
+
+        match unsigned.as_bytes() {
+            [] if !signed => Some(0),
+            [b'0', b'b' | b'B', ..] if !signed => parse_bigint_digits(&unsigned[2..], 2),
+            [b'0', b'o' | b'O', ..] if !signed => parse_bigint_digits(&unsigned[2..], 8),
+            [b'0', b'x' | b'X', ..] if !signed => parse_bigint_digits(&unsigned[2..], 16),
+            _ => parse_bigint_digits(unsigned, 10).map(|val| if negative { -val } else { val }),
+        }
+    }
+
+    /// 按指定进制将数字部分转换为 i128
+    ///
+    /// # Arguments
+    /// `digits` - 去除进制前缀后的数字部分
+    /// `radix` - 进制
+    /// # Returns
+    /// 返回转换结果，数字部分为空、含有非法字符或溢出时返回 None
+    fn parse_bigint_digits(digits: &str, radix: u32) -> Option<i128> {
+        if digits.is_empty() {
+            return None;
+        }
+
+        digits.chars().try_fold(0i128, |val, chr| {
+            chr.to_digit(radix)
+                .and_then(|digit| val.checked_mul(radix as i128)?.checked_add(digit as i128))
+        })
+    }
+
+    /// 将不带符号的字符串转换为数字
+    ///
+    /// # Arguments
+    /// `s` - 待转换为数字的字符串
+    /// `legacy_octal` - 是否按旧式八进制处理以 `0` 开头的数字，仅用于数字字面量
+    /// # Returns
+    /// 返回 JSValue 表示的数字
+    fn to_unsigned_number(s: &str, legacy_octal: bool) -> JSValue {
         let mut chars = s.chars();
 
         match chars.next() {
@@ -178,13 +318,9 @@ pub(crate) mod strconv {
                 Some('o' | 'O') => to_number_with_target(s, ToNumberTarget::Oct),
                 Some('x' | 'X') => to_number_with_target(s, ToNumberTarget::Hex),
                 _ => {
-                    let mut is_oct = true;
-                    while let Some(chr) = chars.next() {
-                        if !matches!(chr, '0'..='7') {
-                            is_oct = false;
-                            break;
-                        }
-                    }
+                    // 前导 `0` 之后的全部字符均为八进制数字时按旧式八进制处理，如 `0777`；
+                    // 否则按十进制处理，如 `0778`、`089`、`0.5`
+                    let is_oct = legacy_octal && s[1..].chars().all(|chr| matches!(chr, '0'..='7'));
 
                     to_number_with_target(
                         s,
@@ -199,4 +335,61 @@ pub(crate) mod strconv {
             _ => to_number_with_target(s, ToNumberTarget::Decimal),
         }
     }
+
+    /// 将数字转换为字符串，对应 `Number.prototype.toString()`
+    ///
+    /// Float 使用能够还原出原值的最短十进制表示：整数值不带 `.0` 后缀，
+    /// 十进制指数不小于 21 或小于 -6 时使用指数形式，如 `1e+21`、`1.5e-7`
+    ///
+    /// # Arguments
+    /// `value` - JSValue 表示的数字
+    /// # Returns
+    /// 返回数字对应的字符串，非数字返回空字符串。`-0` 与 `+0` 均转换为 `"0"`，
+    /// BigInt 不带 `n` 后缀
+    pub(crate) fn to_string(value: &JSValue) -> String {
+        match value {
+            JSValue::Int(val) => val.to_string(),
+            JSValue::BigInt(val) => val.to_string(),
+            JSValue::Float(val) => float_to_string(*val),
+            _ => String::new(),
+        }
+    }
+
+    /// 按照 Number::toString 的规则将 f64 转换为字符串
+    fn float_to_string(val: f64) -> String {
+        if val.is_nan() {
+            return "NaN".to_string();
+        }
+        if val == 0.0 {
+            return "0".to_string();
+        }
+        if val.is_infinite() {
+            return if val > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+        }
+        if val < 0.0 {
+            return format!("-{}", float_to_string(-val));
+        }
+
+        // `{:e}` 给出最短的可还原表示，如 `1.2345e6`，
+        // 拆分为有效数字 digits 与指数 n，使得 val = 0.digits × 10^n
+        let repr = format!("{:e}", val);
+        let (mantissa, exp) = repr.split_once('e').unwrap_or((&repr, "0"));
+        let digits = mantissa.replace('.', "");
+        let k = digits.len() as i32;
+        let n = exp.parse::<i32>().unwrap_or(0) + 1;
+
+        if k <= n && n <= 21 {
+            format!("{}{}", digits, "0".repeat((n - k) as usize))
+        } else if 0 < n && n <= 21 {
+            format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+        } else if -6 < n && n <= 0 {
+            format!("0.{}{}", "0".repeat(-n as usize), digits)
+        } else {
+            let sign = if n - 1 < 0 { '-' } else { '+' };
+            match digits.split_at(1) {
+                (first, "") => format!("{}e{}{}", first, sign, (n - 1).abs()),
+                (first, rest) => format!("{}.{}e{}{}", first, rest, sign, (n - 1).abs()),
+            }
+        }
+    }
 }
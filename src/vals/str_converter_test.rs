@@ -1,4 +1,4 @@
-use crate::vals::strconv::to_number;
+use crate::vals::strconv::{string_to_bigint, to_number, to_numeric, to_string};
 
 use super::JSValue;
 
@@ -10,9 +10,10 @@ fn test_strconv_to_integer() {
     };
 
     verify("123", 123);
-    verify("34e12", 34i64.pow(12));
-    verify("34e+12", 34i64.pow(12));
-    verify("0123", 0o123);
+    verify("34e12", 34 * 10i64.pow(12));
+    verify("34e+12", 34 * 10i64.pow(12));
+    verify("0e400", 0);
+    verify("0123", 123);
     verify("01238", 1238);
     verify("0b101", 0b101);
     verify("0o567", 0o567);
@@ -28,6 +29,269 @@ fn test_strconv_to_float() {
     verify("123.", 123f64);
     verify("123.456", 123.456f64);
     verify(".456", 0.456f64);
-    verify("123.456e2", 123.456f64.powf(2.0));
-    verify(".456E-3", 0.456f64.powf(-3.0));
+    verify("123.456e2", 12345.6f64);
+    verify(".456E-3", 0.000456f64);
+    verify("5e-1", 0.5f64);
+    verify("1e16", 1e16f64);
+    verify("9e18", 9e18f64);
+    verify("1e19", 1e19f64);
+    verify("1e400", f64::INFINITY);
+}
+
+#[test]
+fn test_strconv_signed_zero() {
+    assert!(matches!(to_number("-0"), JSValue::Float(a) if a == 0.0 && a.is_sign_negative()));
+    assert!(matches!(to_number("-0.0"), JSValue::Float(a) if a == 0.0 && a.is_sign_negative()));
+    assert!(matches!(to_number("+0"), JSValue::Int(0)));
+    assert!(matches!(to_number("-12"), JSValue::Int(-12)));
+    assert!(matches!(to_number("-1.5"), JSValue::Float(a) if a == -1.5));
+    assert!(matches!(to_number("-0x10"), JSValue::Float(a) if a.is_nan()));
+
+    assert_eq!(to_string(&JSValue::Float(-0.0)), "0");
+    assert_eq!(to_string(&JSValue::Float(0.0)), "0");
+    assert_eq!(to_string(&JSValue::Float(-1.5)), "-1.5");
+}
+
+#[test]
+fn test_strconv_radix_overflow() {
+    assert!(matches!(
+        to_number("0x1fffffffffffff"),
+        JSValue::Int(0x1fffffffffffff)
+    ));
+    assert!(matches!(
+        to_number("0x20000000000001"),
+        JSValue::Float(a) if a == 9007199254740992f64
+    ));
+    assert!(matches!(
+        to_number("0xffffffffffffffff"),
+        JSValue::Float(a) if a == 18446744073709551615f64
+    ));
+    assert!(matches!(
+        to_number("0x1ffffffffffffffff"),
+        JSValue::Float(a) if a == 36893488147419103231f64
+    ));
+    assert!(matches!(
+        to_number("0b1111111111111111111111111111111111111111111111111111111111111111"),
+        JSValue::Float(a) if a == 18446744073709551615f64
+    ));
+    assert!(matches!(
+        to_number("0o7777777777777777777777"),
+        JSValue::Float(a) if a == 73786976294838206463f64
+    ));
+    assert!(matches!(to_number("0x1g"), JSValue::Float(a) if a.is_nan()));
+}
+
+#[test]
+fn test_strconv_radix_overflow_rounding() {
+    // 2^64 + 2^11 + 1 略大于两个相邻 f64 的中点，应向上舍入为 2^64 + 2^12
+    assert!(matches!(
+        to_number("0x10000000000000801"),
+        JSValue::Float(a) if a == 18446744073709555712f64
+    ));
+    assert!(matches!(
+        to_number(&format!("0b1{}100000000001", "0".repeat(52))),
+        JSValue::Float(a) if a == 18446744073709555712f64
+    ));
+    // 恰好位于中点时舍入到偶数
+    assert!(matches!(
+        to_number("0x10000000000000800"),
+        JSValue::Float(a) if a == 18446744073709551616f64
+    ));
+    assert!(matches!(
+        to_number("0x10000000000001800"),
+        JSValue::Float(a) if a == 18446744073709559808f64
+    ));
+    assert!(matches!(
+        to_number(&format!("0x1{}", "0".repeat(256))),
+        JSValue::Float(a) if a == f64::INFINITY
+    ));
+}
+
+#[test]
+fn test_strconv_to_bigint() {
+    assert_eq!(to_numeric("0n"), JSValue::BigInt(0));
+    assert_eq!(to_numeric("123n"), JSValue::BigInt(123));
+    assert_eq!(to_numeric("0x1fn"), JSValue::BigInt(0x1f));
+    assert_eq!(to_numeric("0o17n"), JSValue::BigInt(0o17));
+    assert_eq!(to_numeric("0b101n"), JSValue::BigInt(0b101));
+    assert_eq!(
+        to_numeric("9007199254740993n"),
+        JSValue::BigInt(9007199254740993)
+    );
+    assert_eq!(to_numeric("123"), JSValue::Int(123));
+
+    for s in ["1.5n", "1e3n", "01n", "0xn", "n", "0x1gn"] {
+        assert!(to_numeric(s).is_nan(), "{s}");
+    }
+    assert!(to_numeric("170141183460469231731687303715884105728n").is_nan());
+    assert!(to_number("1n").is_nan());
+
+    assert_eq!(to_string(&JSValue::BigInt(-42)), "-42");
+}
+
+#[test]
+fn test_strconv_decimal_overflow() {
+    assert!(matches!(
+        to_number("9007199254740991"),
+        JSValue::Int(9007199254740991)
+    ));
+    assert!(matches!(
+        to_number("9007199254740993"),
+        JSValue::Float(a) if a == 9007199254740992f64
+    ));
+    assert!(matches!(
+        to_number("9223372036854775808"),
+        JSValue::Float(a) if a == 9223372036854775808f64
+    ));
+    assert!(matches!(
+        to_number("99999999999999999999"),
+        JSValue::Float(a) if a == 1e20
+    ));
+    assert!(matches!(
+        to_number("99999999999999999999.5"),
+        JSValue::Float(a) if a == 1e20
+    ));
+    assert!(matches!(
+        to_number("-9223372036854775809"),
+        JSValue::Float(a) if a == -9223372036854775808f64
+    ));
+    assert!(matches!(
+        to_number("18446744073709551616e2"),
+        JSValue::Float(a) if a == 1844674407370955161600f64
+    ));
+}
+
+#[test]
+fn test_strconv_to_string() {
+    let verify = |val: f64, expected: &str| {
+        assert_eq!(to_string(&JSValue::Float(val)), expected);
+    };
+
+    verify(1.0, "1");
+    verify(-42.0, "-42");
+    verify(0.1, "0.1");
+    verify(0.1 + 0.2, "0.30000000000000004");
+    verify(123.456, "123.456");
+    verify(1e21, "1e+21");
+    verify(1e20, "100000000000000000000");
+    verify(1.5e21, "1.5e+21");
+    verify(0.000001, "0.000001");
+    verify(1e-7, "1e-7");
+    verify(-1.25e-7, "-1.25e-7");
+    verify(f64::MAX, "1.7976931348623157e+308");
+    verify(5e-324, "5e-324");
+    verify(f64::NAN, "NaN");
+    verify(f64::INFINITY, "Infinity");
+    verify(f64::NEG_INFINITY, "-Infinity");
+
+    assert_eq!(to_string(&JSValue::Int(-7)), "-7");
+    assert_eq!(to_string(&JSValue::Str("1".to_string())), "");
+}
+
+#[test]
+fn test_strconv_legacy_octal() {
+    assert!(matches!(to_numeric("0777"), JSValue::Int(0o777)));
+    assert!(matches!(to_numeric("0778"), JSValue::Int(778)));
+    assert!(matches!(to_numeric("089"), JSValue::Int(89)));
+    assert!(matches!(to_numeric("0912"), JSValue::Int(912)));
+    assert!(matches!(to_numeric("0"), JSValue::Int(0)));
+    assert!(matches!(to_numeric("00"), JSValue::Int(0)));
+    assert!(matches!(to_numeric("07.5"), JSValue::Float(a) if a == 7.5));
+}
+
+#[test]
+fn test_strconv_to_number_leading_zero() {
+    // 字符串到数字的转换不使用旧式八进制
+    assert!(matches!(to_number("010"), JSValue::Int(10)));
+    assert!(matches!(to_number("08"), JSValue::Int(8)));
+    assert!(matches!(to_number("0777"), JSValue::Int(777)));
+    assert!(matches!(to_number("-010"), JSValue::Int(-10)));
+    assert!(matches!(to_number(" 0o10 "), JSValue::Int(8)));
+    assert!(JSValue::Str("010".to_string()).loose_eq(&JSValue::Int(10)));
+}
+
+#[test]
+fn test_strconv_to_number_trim() {
+    let is_nan = |s: &str| matches!(to_number(s), JSValue::Float(a) if a.is_nan());
+
+    assert!(matches!(to_number(" 5 "), JSValue::Int(5)));
+    assert!(matches!(to_number("\n\t-5\u{feff}"), JSValue::Int(-5)));
+    assert!(matches!(to_number(""), JSValue::Int(0)));
+    assert!(matches!(to_number(" \r\n "), JSValue::Int(0)));
+    assert!(matches!(to_number("  0x10 "), JSValue::Int(16)));
+    assert!(matches!(to_number("+Infinity"), JSValue::Float(a) if a == f64::INFINITY));
+    assert!(matches!(to_number(" Infinity"), JSValue::Float(a) if a == f64::INFINITY));
+    assert!(matches!(to_number("-Infinity"), JSValue::Float(a) if a == f64::NEG_INFINITY));
+
+    // 带进制前缀的数字不允许带有符号
+    assert!(is_nan("  -0x10 "));
+    assert!(is_nan("+0b1"));
+    // 进制前缀之后至少需要一位数字
+    assert!(is_nan("0x"));
+    assert!(is_nan(" 0b "));
+    assert!(is_nan("0o"));
+    assert!(is_nan("0X"));
+    assert!(is_nan("NaN"));
+    assert!(is_nan("-NaN"));
+    assert!(is_nan("-"));
+    assert!(is_nan("+-1"));
+    assert!(is_nan("1 2"));
+    assert!(is_nan("infinity"));
+}
+
+#[test]
+fn test_strconv_decimal_rounding() {
+    for s in [
+        "0.1",
+        "0.2",
+        "0.3",
+        "123.456",
+        "3.141592653589793",
+        "0.000001",
+        "1e-7",
+        "1.5e300",
+        "2.2250738585072014e-308",
+        "4.9e-324",
+        "1.7976931348623157e308",
+        "9007199254740993.5",
+        "12345678901234567890.123",
+        "7e-1",
+        ".1e1",
+    ] {
+        let expected: f64 = s.parse().unwrap();
+        match to_number(s) {
+            JSValue::Float(val) => assert_eq!(val.to_bits(), expected.to_bits(), "{}", s),
+            val => panic!("unexpected value for {}: {:?}", s, val),
+        }
+    }
+
+    let is_nan = |s: &str| matches!(to_number(s), JSValue::Float(a) if a.is_nan());
+    assert!(is_nan("."));
+    assert!(is_nan("e5"));
+    assert!(is_nan("1e"));
+    assert!(is_nan("1e+"));
+    assert!(is_nan("1.2.3"));
+    assert!(is_nan("1_000"));
+
+    assert!(matches!(to_number("1e-0"), JSValue::Int(1)));
+    assert!(matches!(to_numeric("1_000"), JSValue::Int(1000)));
+    assert!(matches!(to_numeric("1_000.5"), JSValue::Float(a) if a == 1000.5));
+    assert!(matches!(to_numeric("0xf_f"), JSValue::Int(255)));
+    assert!(matches!(to_numeric("1_0n"), JSValue::BigInt(10)));
+}
+
+#[test]
+fn test_strconv_string_to_bigint() {
+    assert_eq!(string_to_bigint(" 123 "), Some(123));
+    assert_eq!(string_to_bigint("-0123"), Some(-123));
+    assert_eq!(string_to_bigint("+7"), Some(7));
+    assert_eq!(string_to_bigint("0x1F"), Some(31));
+    assert_eq!(string_to_bigint("0b101"), Some(5));
+    assert_eq!(string_to_bigint(""), Some(0));
+    assert_eq!(string_to_bigint("-0x1"), None);
+    assert_eq!(string_to_bigint("1n"), None);
+    assert_eq!(string_to_bigint("1.5"), None);
+    assert_eq!(string_to_bigint("1e3"), None);
+    assert_eq!(string_to_bigint("-"), None);
+    assert_eq!(string_to_bigint("0x"), None);
 }
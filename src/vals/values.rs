@@ -1,7 +1,379 @@
-#[derive(Debug)]
-pub(crate) enum JSValue {
+use std::fmt;
+
+use super::strconv;
+
+/// EMCAScript 的值
+#[derive(Debug, Clone, PartialEq)]
+pub enum JSValue {
     Int(i64),
     Float(f64),
+    /// BigInt，目前以 i128 表示，超出其范围的值无法表示
+    BigInt(i128),
     Str(String),
+    Bool(bool),
     Null,
+    Undefined,
+    Array(Vec<JSValue>),
+    Object(Vec<(String, JSValue)>),
+}
+
+/// ToPrimitive 的类型提示
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Hint {
+    Default,
+    Number,
+    String,
+}
+
+/// 数字的规范化策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NumberPolicy {
+    /// 安全整数范围（`±(2^53 - 1)`）内的整数使用 Int 表示，其余数字使用 Float 表示。
+    /// 超出安全整数范围的 Int 会被舍入为对应的 f64
+    SafeInt,
+
+    /// 全部数字均使用 Float 表示，与 JS 仅有 f64 一种数字类型保持一致
+    Float,
+}
+
+/// 安全整数的最大值，对应 `Number.MAX_SAFE_INTEGER`
+pub(crate) const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+impl JSValue {
+    /// 判断当前值是否为原始值
+    #[inline(always)]
+    pub(crate) const fn is_primitive(&self) -> bool {
+        !matches!(self, JSValue::Array(..) | JSValue::Object(..))
+    }
+
+    /// 对应 `typeof` 运算符
+    ///
+    /// 与 JS 一致，`typeof null` 为 `"object"`
+    ///
+    /// # Returns
+    /// 返回值的类型名称
+    pub(crate) const fn type_of(&self) -> &'static str {
+        match self {
+            JSValue::Int(..) | JSValue::Float(..) => "number",
+            JSValue::BigInt(..) => "bigint",
+            JSValue::Str(..) => "string",
+            JSValue::Bool(..) => "boolean",
+            JSValue::Undefined => "undefined",
+            JSValue::Null | JSValue::Array(..) | JSValue::Object(..) => "object",
+        }
+    }
+
+    /// 对应 `Number.isNaN`，非数字不做类型转换，直接返回 false
+    pub(crate) fn is_nan(&self) -> bool {
+        matches!(self, JSValue::Float(val) if val.is_nan())
+    }
+
+    /// 对应 `Number.isFinite`，非数字不做类型转换，直接返回 false
+    pub(crate) fn is_finite(&self) -> bool {
+        match self {
+            JSValue::Int(..) => true,
+            JSValue::Float(val) => val.is_finite(),
+            _ => false,
+        }
+    }
+
+    /// 对应 `Number.isInteger`，非数字不做类型转换，直接返回 false
+    ///
+    /// 小数部分为零的 Float（包括 `-0.0`）同样视为整数
+    pub(crate) fn is_integer(&self) -> bool {
+        match self {
+            JSValue::Int(..) => true,
+            JSValue::Float(val) => val.is_finite() && val.fract() == 0.0,
+            _ => false,
+        }
+    }
+
+    /// 获取数字在 JS 中对应的 f64 值，非数字返回 None
+    ///
+    /// Int 是对整数的优化表示。按照严格的 JS 语义，超出 `2^53` 的整数
+    /// 应视为其舍入后的 f64，如 `9007199254740993` 对应 `9007199254740992`
+    pub(crate) fn as_js_number(&self) -> Option<f64> {
+        match self {
+            JSValue::Int(val) => Some(*val as f64),
+            JSValue::Float(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    /// 按照指定策略规范化数字的表示方式，非数字原样返回
+    ///
+    /// # Arguments
+    /// `policy` - 数字的规范化策略
+    /// # Returns
+    /// 返回规范化后的值
+    pub(crate) fn normalize(&self, policy: NumberPolicy) -> JSValue {
+        let val = match self.as_js_number() {
+            Some(val) => val,
+            None => return self.clone(),
+        };
+
+        match policy {
+            NumberPolicy::Float => JSValue::Float(val),
+            NumberPolicy::SafeInt
+                if val.fract() == 0.0
+                    && val.abs() <= MAX_SAFE_INTEGER as f64
+                    && !(val == 0.0 && val.is_sign_negative()) =>
+            {
+                JSValue::Int(val as i64)
+            }
+            NumberPolicy::SafeInt => JSValue::Float(val),
+        }
+    }
+
+    /// 严格相等比较，对应 `===` 运算符
+    ///
+    /// 不进行类型转换，不同类型的值均不相等。Int 与 Float 同属 JS 的 Number 类型，
+    /// 数字之间按数值比较，因此 `1 === 1.0`、`NaN !== NaN` 且 `-0 === +0`；
+    /// 数组与对象仅在引用同一个值时相等
+    ///
+    /// # Arguments
+    /// `other` - 待比较的值
+    /// # Returns
+    /// 返回两个值是否严格相等
+    pub(crate) fn strict_eq(&self, other: &JSValue) -> bool {
+        match (self, other) {
+            (JSValue::Int(a), JSValue::Int(b)) => a == b,
+            (JSValue::Int(a), JSValue::Float(b)) | (JSValue::Float(b), JSValue::Int(a)) => {
+                *a as f64 == *b
+            }
+            (JSValue::Float(a), JSValue::Float(b)) => a == b,
+            (JSValue::BigInt(a), JSValue::BigInt(b)) => a == b,
+            (JSValue::Str(a), JSValue::Str(b)) => a == b,
+            (JSValue::Bool(a), JSValue::Bool(b)) => a == b,
+            (JSValue::Null, JSValue::Null) | (JSValue::Undefined, JSValue::Undefined) => true,
+            (JSValue::Array(..), JSValue::Array(..))
+            | (JSValue::Object(..), JSValue::Object(..)) => std::ptr::eq(self, other),
+            _ => false,
+        }
+    }
+
+    /// 宽松相等比较，对应 `==` 运算符
+    ///
+    /// 同类型的值按严格相等比较；`null` 与 `undefined` 彼此相等，且不等于其他任何值；
+    /// 数字与字符串比较时字符串经过 ToNumber 转换，BigInt 与字符串比较时字符串经过 StringToBigInt 转换；
+    /// 布尔值先转换为数字；数组与对象同原始值比较时先经过 ToPrimitive 转换。`NaN` 不等于任何值
+    ///
+    /// # Arguments
+    /// `other` - 待比较的值
+    /// # Returns
+    /// 返回两个值是否宽松相等
+    pub(crate) fn loose_eq(&self, other: &JSValue) -> bool {
+        match (self, other) {
+            (JSValue::Null | JSValue::Undefined, JSValue::Null | JSValue::Undefined) => true,
+            (JSValue::Null | JSValue::Undefined, _) | (_, JSValue::Null | JSValue::Undefined) => {
+                false
+            }
+            (JSValue::Int(..) | JSValue::Float(..), JSValue::Str(..)) => {
+                self.strict_eq(&other.to_number())
+            }
+            (JSValue::Str(..), JSValue::Int(..) | JSValue::Float(..)) => {
+                self.to_number().strict_eq(other)
+            }
+            (JSValue::BigInt(a), JSValue::Str(b)) | (JSValue::Str(b), JSValue::BigInt(a)) => {
+                strconv::string_to_bigint(b) == Some(*a)
+            }
+            (JSValue::Bool(..), _) => self.to_number().loose_eq(other),
+            (_, JSValue::Bool(..)) => self.loose_eq(&other.to_number()),
+            (
+                JSValue::Array(..) | JSValue::Object(..),
+                JSValue::Array(..) | JSValue::Object(..),
+            ) => self.strict_eq(other),
+            (JSValue::Array(..) | JSValue::Object(..), _) => {
+                self.to_primitive(Hint::Default).loose_eq(other)
+            }
+            (_, JSValue::Array(..) | JSValue::Object(..)) => {
+                self.loose_eq(&other.to_primitive(Hint::Default))
+            }
+            (JSValue::BigInt(a), JSValue::Int(..) | JSValue::Float(..)) => other
+                .as_js_number()
+                .is_some_and(|b| bigint_equals_number(*a, b)),
+            (JSValue::Int(..) | JSValue::Float(..), JSValue::BigInt(b)) => self
+                .as_js_number()
+                .is_some_and(|a| bigint_equals_number(*b, a)),
+            _ => self.strict_eq(other),
+        }
+    }
+
+    /// ToBoolean 抽象操作
+    ///
+    /// `null`、`undefined`、`false`、`0`、`-0`、`NaN`、`0n` 与空字符串为 false，
+    /// 其余值（包括空数组与空对象）均为 true
+    ///
+    /// # Returns
+    /// 返回值的真假性
+    pub(crate) fn to_boolean(&self) -> bool {
+        match self {
+            JSValue::Null | JSValue::Undefined => false,
+            JSValue::Bool(val) => *val,
+            JSValue::Int(val) => *val != 0,
+            JSValue::Float(val) => !(*val == 0.0 || val.is_nan()),
+            JSValue::BigInt(val) => *val != 0,
+            JSValue::Str(val) => !val.is_empty(),
+            JSValue::Array(..) | JSValue::Object(..) => true,
+        }
+    }
+
+    /// ToNumber 抽象操作
+    ///
+    /// 字符串忽略首尾空白后按数字字面量的规则转换，无法转换时为 NaN，空字符串为 0；
+    /// `true` 转换为 1，`false` 与 `null` 转换为 0，`undefined` 转换为 NaN；
+    /// 数组与对象先经过 ToPrimitive 转换为原始值。
+    /// BigInt 在 JS 中会抛出 TypeError，由于尚无异常机制，此处转换为 NaN
+    ///
+    /// # Returns
+    /// 返回 Int 或 Float 表示的数字
+    pub(crate) fn to_number(&self) -> JSValue {
+        match self {
+            JSValue::Int(..) | JSValue::Float(..) => self.clone(),
+            JSValue::BigInt(..) => JSValue::Float(f64::NAN),
+            JSValue::Str(val) => strconv::to_number(val),
+            JSValue::Bool(val) => JSValue::Int(*val as i64),
+            JSValue::Null => JSValue::Int(0),
+            JSValue::Undefined => JSValue::Float(f64::NAN),
+            JSValue::Array(..) | JSValue::Object(..) => self.to_primitive(Hint::Number).to_number(),
+        }
+    }
+
+    /// 减法运算，对应 `-` 运算符
+    ///
+    /// # Arguments
+    /// `other` - 右操作数
+    /// # Returns
+    /// 返回运算结果，运算规则见 arithmetic
+    pub(crate) fn sub(&self, other: &JSValue) -> JSValue {
+        self.arithmetic(other, i64::checked_sub, |a, b| a - b)
+    }
+
+    /// 乘法运算，对应 `*` 运算符
+    ///
+    /// # Arguments
+    /// `other` - 右操作数
+    /// # Returns
+    /// 返回运算结果，运算规则见 arithmetic
+    pub(crate) fn mul(&self, other: &JSValue) -> JSValue {
+        self.arithmetic(
+            other,
+            |a, b| match a.checked_mul(b) {
+                // 结果为零且操作数异号时应为 `-0`，Int 无法表示
+                Some(0) if (a < 0) != (b < 0) => None,
+                result => result,
+            },
+            |a, b| a * b,
+        )
+    }
+
+    /// 除法运算，对应 `/` 运算符
+    ///
+    /// 除数为零时按 IEEE 754 规则得到 `Infinity`、`-Infinity` 或 `NaN`
+    ///
+    /// # Arguments
+    /// `other` - 右操作数
+    /// # Returns
+    /// 返回运算结果，运算规则见 arithmetic
+    pub(crate) fn div(&self, other: &JSValue) -> JSValue {
+        self.arithmetic(
+            other,
+            |a, b| match (a.checked_rem(b), a.checked_div(b)) {
+                // 被除数为零且除数为负数时结果为 `-0`，Int 无法表示
+                (Some(0), Some(0)) if b < 0 => None,
+                (Some(0), result) => result,
+                _ => None,
+            },
+            |a, b| a / b,
+        )
+    }
+
+    /// 数值运算的公共部分
+    ///
+    /// 两个操作数均先经过 ToNumber 转换，不进行字符串拼接。
+    /// 当两个操作数均为 Int 且 `int_op` 能给出精确结果时，结果保持为 Int；
+    /// 否则（溢出、除不尽、结果为 `-0` 等）按 f64 计算，结果为 Float
+    ///
+    /// # Arguments
+    /// `other` - 右操作数
+    /// `int_op` - 整数运算，无法得到精确的 Int 结果时返回 None
+    /// `float_op` - 浮点数运算
+    /// # Returns
+    /// 返回运算结果
+    fn arithmetic(
+        &self,
+        other: &JSValue,
+        int_op: impl Fn(i64, i64) -> Option<i64>,
+        float_op: impl Fn(f64, f64) -> f64,
+    ) -> JSValue {
+        let (left, right) = (self.to_number(), other.to_number());
+        if let (JSValue::Int(a), JSValue::Int(b)) = (&left, &right) {
+            if let Some(result) = int_op(*a, *b) {
+                return JSValue::Int(result);
+            }
+        }
+
+        match (left.as_js_number(), right.as_js_number()) {
+            (Some(a), Some(b)) => JSValue::Float(float_op(a, b)),
+            _ => JSValue::Float(f64::NAN),
+        }
+    }
+
+    /// ToPrimitive 抽象操作
+    ///
+    /// 原始值直接返回自身；数组转换为以 `,` 连接各元素的字符串，
+    /// 对象转换为 `"[object Object]"`。由于尚不支持自定义 `valueOf`/`toString`，
+    /// 转换结果与 `hint` 无关
+    ///
+    /// # Arguments
+    /// `hint` - 类型提示
+    /// # Returns
+    /// 返回转换后的原始值
+    pub(crate) fn to_primitive(&self, _hint: Hint) -> JSValue {
+        match self {
+            JSValue::Array(..) | JSValue::Object(..) => JSValue::Str(self.object_to_string()),
+            _ => self.clone(),
+        }
+    }
+
+    /// 将数组或对象转换为字符串，对应 `Array.prototype.toString` 与
+    /// `Object.prototype.toString`
+    fn object_to_string(&self) -> String {
+        match self {
+            JSValue::Array(elements) => elements
+                .iter()
+                .map(|element| match element {
+                    JSValue::Null | JSValue::Undefined => String::new(),
+                    _ => element.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(","),
+            _ => "[object Object]".to_string(),
+        }
+    }
+}
+
+/// 判断 BigInt 与数字的数值是否相等，数字为 `NaN`、无穷大或带有小数部分时不相等
+fn bigint_equals_number(bigint: i128, number: f64) -> bool {
+    let bound = 2f64.powi(127);
+    number.fract() == 0.0 && number >= -bound && number < bound && number as i128 == bigint
+}
+
+/// 按照 ToString 抽象操作渲染值
+///
+/// `null`、`undefined` 与布尔值渲染为对应的关键字，数字与 BigInt 按 strconv::to_string 渲染，
+/// 字符串原样输出，数组与对象先经过 ToPrimitive 转换
+impl fmt::Display for JSValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JSValue::Null => f.write_str("null"),
+            JSValue::Undefined => f.write_str("undefined"),
+            JSValue::Bool(val) => write!(f, "{}", val),
+            JSValue::Int(..) | JSValue::Float(..) | JSValue::BigInt(..) => {
+                f.write_str(&strconv::to_string(self))
+            }
+            JSValue::Str(val) => f.write_str(val),
+            JSValue::Array(..) | JSValue::Object(..) => f.write_str(&self.object_to_string()),
+        }
+    }
 }
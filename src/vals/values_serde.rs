@@ -0,0 +1,131 @@
+use std::fmt;
+
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::values::{JSValue, MAX_SAFE_INTEGER};
+
+/// 按 JSON 的数据模型序列化
+///
+/// `Null` 与 `Undefined` 均序列化为 null，BigInt 序列化为 128 位整数，
+/// 对象的属性按保存的顺序序列化
+impl Serialize for JSValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            JSValue::Int(val) => serializer.serialize_i64(*val),
+            JSValue::Float(val) => serializer.serialize_f64(*val),
+            JSValue::BigInt(val) => serializer.serialize_i128(*val),
+            JSValue::Str(val) => serializer.serialize_str(val),
+            JSValue::Bool(val) => serializer.serialize_bool(*val),
+            JSValue::Null | JSValue::Undefined => serializer.serialize_unit(),
+            JSValue::Array(elements) => {
+                let mut seq = serializer.serialize_seq(Some(elements.len()))?;
+                for element in elements {
+                    seq.serialize_element(element)?;
+                }
+                seq.end()
+            }
+            JSValue::Object(properties) => {
+                let mut map = serializer.serialize_map(Some(properties.len()))?;
+                for (key, value) in properties {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+/// 按 JSON 的数据模型反序列化
+///
+/// 与 JSON 的数字一致，不超出安全整数范围的整数转换为 Int，其余整数转换为 Float；
+/// null 转换为 `Null`，重复的属性名保留最后一次出现的值
+impl<'de> Deserialize<'de> for JSValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(JSValueVisitor)
+    }
+}
+
+struct JSValueVisitor;
+
+impl<'de> Visitor<'de> for JSValueVisitor {
+    type Value = JSValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_bool<E: de::Error>(self, val: bool) -> Result<JSValue, E> {
+        Ok(JSValue::Bool(val))
+    }
+
+    fn visit_i64<E: de::Error>(self, val: i64) -> Result<JSValue, E> {
+        self.visit_i128(val as i128)
+    }
+
+    fn visit_i128<E: de::Error>(self, val: i128) -> Result<JSValue, E> {
+        Ok(match i64::try_from(val) {
+            Ok(val) if val.abs() <= MAX_SAFE_INTEGER => JSValue::Int(val),
+            _ => JSValue::Float(val as f64),
+        })
+    }
+
+    fn visit_u64<E: de::Error>(self, val: u64) -> Result<JSValue, E> {
+        self.visit_i128(val as i128)
+    }
+
+    fn visit_u128<E: de::Error>(self, val: u128) -> Result<JSValue, E> {
+        match i128::try_from(val) {
+            Ok(val) => self.visit_i128(val),
+            Err(_) => Ok(JSValue::Float(val as f64)),
+        }
+    }
+
+    fn visit_f64<E: de::Error>(self, val: f64) -> Result<JSValue, E> {
+        Ok(JSValue::Float(val))
+    }
+
+    fn visit_str<E: de::Error>(self, val: &str) -> Result<JSValue, E> {
+        Ok(JSValue::Str(val.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, val: String) -> Result<JSValue, E> {
+        Ok(JSValue::Str(val))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<JSValue, E> {
+        Ok(JSValue::Null)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<JSValue, E> {
+        Ok(JSValue::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<JSValue, D::Error> {
+        JSValue::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<JSValue, A::Error> {
+        let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+
+        Ok(JSValue::Array(elements))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JSValue, A::Error> {
+        let mut properties: Vec<(String, JSValue)> = Vec::new();
+        while let Some((key, value)) = map.next_entry::<String, JSValue>()? {
+            match properties.iter_mut().find(|(name, _)| *name == key) {
+                Some(property) => property.1 = value,
+                None => properties.push((key, value)),
+            }
+        }
+
+        Ok(JSValue::Object(properties))
+    }
+}
@@ -0,0 +1,84 @@
+use super::values::JSValue;
+
+#[test]
+fn test_JSValue_serialize() {
+    let val = JSValue::Object(vec![
+        ("a".to_string(), JSValue::Int(1)),
+        ("b".to_string(), JSValue::Float(1.5)),
+        ("c".to_string(), JSValue::Str("x\"y".to_string())),
+        (
+            "d".to_string(),
+            JSValue::Array(vec![JSValue::Bool(true), JSValue::Null, JSValue::Undefined]),
+        ),
+        ("e".to_string(), JSValue::BigInt(1 << 70)),
+    ]);
+    assert_eq!(
+        serde_json::to_string(&val).unwrap(),
+        r#"{"a":1,"b":1.5,"c":"x\"y","d":[true,null,null],"e":1180591620717411303424}"#
+    );
+}
+
+#[test]
+fn test_JSValue_deserialize() {
+    let val: JSValue = serde_json::from_str(
+        r#"{"a": [1, -2, 2.5, 18446744073709551615], "b": null, "a": "x", "c": {}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        val,
+        JSValue::Object(vec![
+            ("a".to_string(), JSValue::Str("x".to_string())),
+            ("b".to_string(), JSValue::Null),
+            ("c".to_string(), JSValue::Object(vec![])),
+        ])
+    );
+
+    let val: JSValue = serde_json::from_str("[18446744073709551615]").unwrap();
+    assert_eq!(val, JSValue::Array(vec![JSValue::Float(u64::MAX as f64)]));
+}
+
+#[test]
+fn test_JSValue_deserialize_unsafe_integer() {
+    let safe: JSValue = serde_json::from_str("9007199254740991").unwrap();
+    assert_eq!(safe, JSValue::Int(9007199254740991));
+    let safe: JSValue = serde_json::from_str("-9007199254740991").unwrap();
+    assert_eq!(safe, JSValue::Int(-9007199254740991));
+
+    for (json, expected) in [
+        ("9007199254740993", 9007199254740993u64 as f64),
+        ("-9007199254740993", -(9007199254740993u64 as f64)),
+        ("18446744073709551615", u64::MAX as f64),
+    ] {
+        let val: JSValue = serde_json::from_str(json).unwrap();
+        assert_eq!(val, JSValue::Float(expected), "{}", json);
+
+        // 再次序列化与反序列化得到同一个值
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(serde_json::from_str::<JSValue>(&json).unwrap(), val);
+    }
+}
+
+#[test]
+fn test_JSValue_serde_round_trip() {
+    let val = JSValue::Array(vec![
+        JSValue::Int(-3),
+        JSValue::Float(0.25),
+        JSValue::Str("中文".to_string()),
+        JSValue::Bool(false),
+        JSValue::Null,
+        JSValue::Object(vec![
+            ("a".to_string(), JSValue::Array(vec![])),
+            ("z".to_string(), JSValue::Int(1)),
+        ]),
+    ]);
+
+    let json = serde_json::to_value(&val).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([-3, 0.25, "中文", false, null, {"a": [], "z": 1}])
+    );
+    assert_eq!(serde_json::from_value::<JSValue>(json).unwrap(), val);
+
+    let text = serde_json::to_string(&val).unwrap();
+    assert_eq!(serde_json::from_str::<JSValue>(&text).unwrap(), val);
+}
@@ -0,0 +1,321 @@
+use super::values::{Hint, JSValue, NumberPolicy, MAX_SAFE_INTEGER};
+
+#[test]
+fn test_JSValue_to_primitive() {
+    let verify = |val: JSValue, exp: JSValue| {
+        println!("verify: {:?} {:?}", val, exp);
+        assert_eq!(val.to_primitive(Hint::Default), exp);
+        assert_eq!(val.to_primitive(Hint::Number), exp);
+        assert_eq!(val.to_primitive(Hint::String), exp);
+    };
+
+    verify(JSValue::Int(1), JSValue::Int(1));
+    verify(JSValue::Str("a".to_string()), JSValue::Str("a".to_string()));
+    verify(JSValue::Null, JSValue::Null);
+    verify(JSValue::Array(vec![]), JSValue::Str("".to_string()));
+    verify(
+        JSValue::Array(vec![
+            JSValue::Int(1),
+            JSValue::Str("a".to_string()),
+            JSValue::Null,
+            JSValue::Array(vec![JSValue::Int(2), JSValue::Int(3)]),
+        ]),
+        JSValue::Str("1,a,,2,3".to_string()),
+    );
+    verify(
+        JSValue::Object(vec![("a".to_string(), JSValue::Int(1))]),
+        JSValue::Str("[object Object]".to_string()),
+    );
+    verify(
+        JSValue::Array(vec![JSValue::Object(vec![])]),
+        JSValue::Str("[object Object]".to_string()),
+    );
+}
+
+#[test]
+fn test_JSValue_number_predicates() {
+    let verify = |val: JSValue, nan: bool, finite: bool, integer: bool| {
+        println!("verify: {:?}", val);
+        assert_eq!(val.is_nan(), nan);
+        assert_eq!(val.is_finite(), finite);
+        assert_eq!(val.is_integer(), integer);
+    };
+
+    verify(JSValue::Float(f64::NAN), true, false, false);
+    verify(JSValue::Float(1.5), false, true, false);
+    verify(JSValue::Float(2.0), false, true, true);
+    verify(JSValue::Float(-0.0), false, true, true);
+    verify(JSValue::Float(f64::INFINITY), false, false, false);
+    verify(JSValue::Int(3), false, true, true);
+    verify(JSValue::Str("3".to_string()), false, false, false);
+    verify(JSValue::Str("NaN".to_string()), false, false, false);
+    verify(JSValue::Null, false, false, false);
+}
+
+#[test]
+fn test_JSValue_strict_eq() {
+    assert!(JSValue::Float(-0.0).strict_eq(&JSValue::Float(0.0)));
+    assert!(JSValue::Float(-0.0).strict_eq(&JSValue::Int(0)));
+    assert!(JSValue::Int(1).strict_eq(&JSValue::Float(1.0)));
+    assert!(!JSValue::Float(f64::NAN).strict_eq(&JSValue::Float(f64::NAN)));
+    assert!(!JSValue::Int(1).strict_eq(&JSValue::Str("1".to_string())));
+    assert!(JSValue::Null.strict_eq(&JSValue::Null));
+
+    let array = JSValue::Array(vec![]);
+    assert!(array.strict_eq(&array));
+    assert!(!array.strict_eq(&JSValue::Array(vec![])));
+
+    // 与宽松相等不同，严格相等不进行类型转换
+    let cases = [
+        (JSValue::Int(1), JSValue::Str("1".to_string())),
+        (JSValue::Int(0), JSValue::Bool(false)),
+        (JSValue::Null, JSValue::Undefined),
+        (JSValue::BigInt(1), JSValue::Int(1)),
+        (JSValue::Array(vec![]), JSValue::Str("".to_string())),
+    ];
+    for (a, b) in cases {
+        assert!(a.loose_eq(&b), "{:?} == {:?}", a, b);
+        assert!(!a.strict_eq(&b), "{:?} === {:?}", a, b);
+        assert!(!b.strict_eq(&a), "{:?} === {:?}", b, a);
+    }
+    assert!(JSValue::BigInt(2).strict_eq(&JSValue::BigInt(2)));
+    assert!(JSValue::Undefined.strict_eq(&JSValue::Undefined));
+    assert!(JSValue::Bool(true).strict_eq(&JSValue::Bool(true)));
+    assert!(!JSValue::Bool(true).strict_eq(&JSValue::Bool(false)));
+}
+
+#[test]
+fn test_JSValue_normalize() {
+    let two_53 = 1i64 << 53;
+
+    assert_eq!(JSValue::Int(two_53 + 1).as_js_number(), Some(two_53 as f64));
+    assert_eq!(JSValue::Float(1.5).as_js_number(), Some(1.5));
+    assert_eq!(JSValue::Null.as_js_number(), None);
+
+    let verify = |val: JSValue, exp: JSValue| {
+        println!("verify: {:?} {:?}", val, exp);
+        assert_eq!(val.normalize(NumberPolicy::SafeInt), exp);
+    };
+    verify(
+        JSValue::Int(MAX_SAFE_INTEGER),
+        JSValue::Int(MAX_SAFE_INTEGER),
+    );
+    verify(
+        JSValue::Float(MAX_SAFE_INTEGER as f64),
+        JSValue::Int(MAX_SAFE_INTEGER),
+    );
+    verify(
+        JSValue::Int(-MAX_SAFE_INTEGER),
+        JSValue::Int(-MAX_SAFE_INTEGER),
+    );
+    verify(JSValue::Int(two_53), JSValue::Float(two_53 as f64));
+    verify(JSValue::Int(two_53 + 1), JSValue::Float(two_53 as f64));
+    verify(JSValue::Float(2.0), JSValue::Int(2));
+    verify(JSValue::Float(2.5), JSValue::Float(2.5));
+    verify(JSValue::Str("1".to_string()), JSValue::Str("1".to_string()));
+    assert!(matches!(
+        JSValue::Float(-0.0).normalize(NumberPolicy::SafeInt),
+        JSValue::Float(val) if val.is_sign_negative()
+    ));
+
+    assert_eq!(
+        JSValue::Int(two_53 + 1).normalize(NumberPolicy::Float),
+        JSValue::Float(two_53 as f64)
+    );
+    assert_eq!(
+        JSValue::Int(1).normalize(NumberPolicy::Float),
+        JSValue::Float(1.0)
+    );
+}
+
+#[test]
+fn test_JSValue_arithmetic() {
+    let str = |val: &str| JSValue::Str(val.to_string());
+
+    assert_eq!(JSValue::Int(6).div(&JSValue::Int(2)), JSValue::Int(3));
+    assert_eq!(JSValue::Int(7).div(&JSValue::Int(2)), JSValue::Float(3.5));
+    assert_eq!(
+        JSValue::Int(1).div(&JSValue::Int(0)),
+        JSValue::Float(f64::INFINITY)
+    );
+    assert_eq!(
+        JSValue::Int(-1).div(&JSValue::Int(0)),
+        JSValue::Float(f64::NEG_INFINITY)
+    );
+    assert!(JSValue::Int(0).div(&JSValue::Int(0)).is_nan());
+    assert!(matches!(
+        JSValue::Int(0).div(&JSValue::Int(-5)),
+        JSValue::Float(val) if val == 0.0 && val.is_sign_negative()
+    ));
+
+    assert_eq!(str("3").mul(&JSValue::Int(2)), JSValue::Int(6));
+    assert_eq!(
+        JSValue::Float(1.5).mul(&JSValue::Int(2)),
+        JSValue::Float(3.0)
+    );
+    assert!(matches!(
+        JSValue::Int(0).mul(&JSValue::Int(-5)),
+        JSValue::Float(val) if val == 0.0 && val.is_sign_negative()
+    ));
+    assert_eq!(
+        JSValue::Int(i64::MAX).mul(&JSValue::Int(2)),
+        JSValue::Float(i64::MAX as f64 * 2.0)
+    );
+
+    assert_eq!(str("5").sub(&str("2")), JSValue::Int(3));
+    assert_eq!(JSValue::Null.sub(&JSValue::Int(1)), JSValue::Int(-1));
+    assert!(str("a").sub(&JSValue::Int(1)).is_nan());
+    assert_eq!(
+        JSValue::Array(vec![JSValue::Int(4)]).sub(&JSValue::Int(1)),
+        JSValue::Int(3)
+    );
+}
+
+#[test]
+fn test_JSValue_to_boolean() {
+    let table = [
+        (JSValue::Null, false),
+        (JSValue::Undefined, false),
+        (JSValue::Bool(true), true),
+        (JSValue::Bool(false), false),
+        (JSValue::Int(0), false),
+        (JSValue::Int(-1), true),
+        (JSValue::Float(0.0), false),
+        (JSValue::Float(-0.0), false),
+        (JSValue::Float(f64::NAN), false),
+        (JSValue::Float(0.5), true),
+        (JSValue::Float(f64::NEG_INFINITY), true),
+        (JSValue::BigInt(0), false),
+        (JSValue::BigInt(2), true),
+        (JSValue::Str(String::new()), false),
+        (JSValue::Str("0".to_string()), true),
+        (JSValue::Str(" ".to_string()), true),
+        (JSValue::Array(vec![]), true),
+        (JSValue::Object(vec![]), true),
+    ];
+
+    for (val, exp) in table {
+        println!("verify: {:?} {:?}", val, exp);
+        assert_eq!(val.to_boolean(), exp);
+    }
+}
+
+#[test]
+fn test_JSValue_to_number() {
+    let verify = |val: JSValue, exp: JSValue| {
+        println!("verify: {:?} {:?}", val, exp);
+        let result = val.to_number();
+        assert!(result.strict_eq(&exp) || (result.is_nan() && exp.is_nan()));
+    };
+
+    verify(JSValue::Bool(true), JSValue::Int(1));
+    verify(JSValue::Bool(false), JSValue::Int(0));
+    verify(JSValue::Null, JSValue::Int(0));
+    verify(JSValue::Undefined, JSValue::Float(f64::NAN));
+    verify(JSValue::Float(1.5), JSValue::Float(1.5));
+    verify(JSValue::Str("  42  ".to_string()), JSValue::Int(42));
+    verify(
+        JSValue::Str("\t\n-1.5\u{2028}".to_string()),
+        JSValue::Float(-1.5),
+    );
+    verify(JSValue::Str("".to_string()), JSValue::Int(0));
+    verify(JSValue::Str(" \n ".to_string()), JSValue::Int(0));
+    verify(JSValue::Str("abc".to_string()), JSValue::Float(f64::NAN));
+    verify(JSValue::Str("4 2".to_string()), JSValue::Float(f64::NAN));
+    verify(
+        JSValue::Str(" -Infinity".to_string()),
+        JSValue::Float(f64::NEG_INFINITY),
+    );
+    verify(JSValue::Str("0x1f".to_string()), JSValue::Int(0x1f));
+    assert!(matches!(
+        JSValue::Str("".to_string()).to_number(),
+        JSValue::Int(0)
+    ));
+}
+
+#[test]
+fn test_JSValue_display() {
+    let verify = |val: JSValue, exp: &str| {
+        assert_eq!(val.to_string(), exp);
+    };
+
+    verify(JSValue::Null, "null");
+    verify(JSValue::Undefined, "undefined");
+    verify(JSValue::Bool(true), "true");
+    verify(JSValue::Bool(false), "false");
+    verify(JSValue::Int(-42), "-42");
+    verify(JSValue::Float(1.0), "1");
+    verify(JSValue::Float(1.5), "1.5");
+    verify(JSValue::Float(-0.0), "0");
+    verify(JSValue::Float(f64::NAN), "NaN");
+    verify(JSValue::Float(f64::NEG_INFINITY), "-Infinity");
+    verify(JSValue::BigInt(10), "10");
+    verify(JSValue::Str("a \"b\"".to_string()), "a \"b\"");
+    verify(
+        JSValue::Array(vec![
+            JSValue::Int(1),
+            JSValue::Null,
+            JSValue::Bool(true),
+            JSValue::Array(vec![JSValue::Str("x".to_string())]),
+        ]),
+        "1,,true,x",
+    );
+    verify(JSValue::Object(vec![]), "[object Object]");
+}
+
+#[test]
+fn test_JSValue_loose_eq() {
+    let str = |val: &str| JSValue::Str(val.to_string());
+    let verify = |a: JSValue, b: JSValue, exp: bool| {
+        assert_eq!(a.loose_eq(&b), exp, "{:?} == {:?}", a, b);
+        assert_eq!(b.loose_eq(&a), exp, "{:?} == {:?}", b, a);
+    };
+
+    verify(JSValue::Int(1), str("1"), true);
+    verify(JSValue::Float(1.5), str(" 1.5 "), true);
+    verify(JSValue::Int(1), str("1x"), false);
+    verify(JSValue::Int(0), str(""), true);
+    verify(JSValue::Null, JSValue::Undefined, true);
+    verify(JSValue::Null, JSValue::Null, true);
+    verify(JSValue::Null, JSValue::Int(0), false);
+    verify(JSValue::Undefined, JSValue::Bool(false), false);
+    verify(JSValue::Null, str(""), false);
+    verify(JSValue::Float(f64::NAN), JSValue::Float(f64::NAN), false);
+    verify(JSValue::Float(f64::NAN), str("NaN"), false);
+    verify(JSValue::Int(0), JSValue::Bool(false), true);
+    verify(JSValue::Int(1), JSValue::Bool(true), true);
+    verify(JSValue::Int(2), JSValue::Bool(true), false);
+    verify(str("1"), JSValue::Bool(true), true);
+    verify(str("true"), JSValue::Bool(true), false);
+    verify(JSValue::Int(0), JSValue::Float(-0.0), true);
+    verify(JSValue::BigInt(1), JSValue::Int(1), true);
+    verify(JSValue::BigInt(1), JSValue::Float(1.5), false);
+    verify(JSValue::BigInt(16), str("0x10"), true);
+    verify(JSValue::BigInt(-5), str("-5"), true);
+    verify(JSValue::BigInt(1), str("1.0"), false);
+    verify(JSValue::BigInt(0), JSValue::Bool(false), true);
+    verify(
+        JSValue::Array(vec![JSValue::Int(1), JSValue::Int(2)]),
+        str("1,2"),
+        true,
+    );
+    verify(JSValue::Array(vec![]), JSValue::Int(0), true);
+    verify(JSValue::Array(vec![]), JSValue::Bool(false), true);
+    verify(JSValue::Object(vec![]), str("[object Object]"), true);
+    verify(JSValue::Array(vec![]), JSValue::Array(vec![]), false);
+    verify(str("a"), str("a"), true);
+    verify(str("a"), str("b"), false);
+}
+
+#[test]
+fn test_JSValue_type_of() {
+    assert_eq!(JSValue::Int(1).type_of(), "number");
+    assert_eq!(JSValue::Float(f64::NAN).type_of(), "number");
+    assert_eq!(JSValue::BigInt(1).type_of(), "bigint");
+    assert_eq!(JSValue::Str(String::new()).type_of(), "string");
+    assert_eq!(JSValue::Bool(false).type_of(), "boolean");
+    assert_eq!(JSValue::Undefined.type_of(), "undefined");
+    assert_eq!(JSValue::Null.type_of(), "object");
+    assert_eq!(JSValue::Array(vec![]).type_of(), "object");
+    assert_eq!(JSValue::Object(vec![]).type_of(), "object");
+}
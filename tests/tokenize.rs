@@ -0,0 +1,23 @@
+use qjs_rs::{tokenize, LexerErrorKind, Token};
+
+#[test]
+fn test_tokenize() {
+    let tokens = tokenize("const answer = 42; // done\nanswer").unwrap();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Const,
+            Token::IdentifierName("answer".to_string()),
+            Token::Operator('='),
+            Token::Number("42".to_string()),
+            Token::Operator(';'),
+            Token::LineComment(" done".to_string()),
+            Token::IdentifierName("answer".to_string()),
+            Token::EOF,
+        ]
+    );
+
+    let err = tokenize("let s = 'abc").unwrap_err();
+    assert_eq!(err.kind(), LexerErrorKind::UnterminatedString);
+    assert_eq!(err.line(), 1);
+}